@@ -14,12 +14,12 @@ use gpui_tesserae::{
         select::{Select, SelectItemsMap, SelectState},
     },
     extensions::mouse_handleable::MouseHandleable,
+    open_tesserae_window,
     primitives::{
         input::InputState,
         selectable_text::{SelectableText, SelectableTextState},
     },
     theme::{Theme, ThemeExt},
-    views::Root,
 };
 
 struct Main {
@@ -35,8 +35,6 @@ actions!(window, [TabNext, TabPrev]);
 
 impl Render for Main {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
-        gpui_tesserae::init_for_window(window, cx);
-
         let theme = cx.get_theme();
 
         div()
@@ -136,7 +134,8 @@ fn main() {
 
             let bounds = Bounds::centered(None, size(px(620.), px(800.)), cx);
 
-            cx.open_window(
+            open_tesserae_window(
+                cx,
                 WindowOptions {
                     window_bounds: Some(WindowBounds::Windowed(bounds)),
                     titlebar: Some(TitlebarOptions {
@@ -146,7 +145,7 @@ fn main() {
                     }),
                     ..Default::default()
                 },
-                |window, cx| {
+                |_window, cx| {
                     let items = cx.new(|_cx| SelectItemsMap::<SharedString, SharedString>::new());
                     let selected = cx.new(|_cx| None::<SharedString>);
                     let highlighted = cx.new(|_cx| None::<SharedString>);
@@ -182,7 +181,7 @@ Lorem ipsum dolor sit amet, consectetur adipiscing elit. Sed do eiusmod tempor i
                         selectable_text_state,
                     });
 
-                    cx.new(|cx| Root::new(main, window, cx))
+                    main
                 },
             )
             .unwrap();
@@ -44,6 +44,14 @@ pub enum TesseraeIconKind {
     /// Downward arrow for dropdowns and expand indicators.
     #[assoc(path = "icons/arrow_down.svg".into())]
     ArrowDown,
+
+    /// Cross for closing and dismissing.
+    #[assoc(path = "icons/close.svg".into())]
+    Close,
+
+    /// Open circle for loading indicators.
+    #[assoc(path = "icons/spinner.svg".into())]
+    Spinner,
 }
 
 impl Into<SharedString> for TesseraeIconKind {
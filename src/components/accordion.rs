@@ -0,0 +1,833 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    AnyElement, App, CursorStyle, Edges, ElementId, FocusHandle, InteractiveElement, IntoElement,
+    KeyBinding, Length, ParentElement, Pixels, RenderOnce, SharedString,
+    StatefulInteractiveElement, Styled, Window, actions, canvas, div, ease_out_quint,
+    prelude::FluentBuilder, px, radians, relative,
+};
+use smallvec::SmallVec;
+
+use crate::{
+    ElementIdExt, TesseraeIconKind,
+    components::Icon,
+    conitional_transition,
+    extensions::mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+    primitives::{FocusRing, min_w0_wrapper},
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{RovingNavigation, chevron_rotation, disabled_transition, navigation_target},
+};
+
+actions!(
+    accordion,
+    [ToggleSection, FocusPrev, FocusNext, FocusFirst, FocusLast]
+);
+
+/// Registers key bindings for toggling sections and moving between accordion headers.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("enter", ToggleSection, Some("Collapsible")),
+        KeyBinding::new("space", ToggleSection, Some("Collapsible")),
+        KeyBinding::new("up", FocusPrev, Some("Accordion")),
+        KeyBinding::new("down", FocusNext, Some("Accordion")),
+        KeyBinding::new("home", FocusFirst, Some("Accordion")),
+        KeyBinding::new("end", FocusLast, Some("Accordion")),
+    ]);
+}
+
+/// Type alias for the collapsible toggle callback.
+pub type OnCollapsibleToggleFn = Rc<dyn Fn(&bool, &mut Window, &mut App)>;
+
+/// Type alias for the accordion open sections change callback.
+pub type OnAccordionChangeFn = Rc<dyn Fn(&[SharedString], &mut Window, &mut App)>;
+
+/// A section whose content expands and collapses below a clickable header.
+#[derive(IntoElement)]
+pub struct Collapsible {
+    id: ElementId,
+    title: Option<SharedString>,
+    icon: Option<SharedString>,
+    open: Option<bool>,
+    disabled: bool,
+    focus_handle: Option<FocusHandle>,
+    on_toggle: Option<OnCollapsibleToggleFn>,
+    children: SmallVec<[AnyElement; 2]>,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+    width: Length,
+}
+
+impl Collapsible {
+    /// Creates a new collapsed section with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            title: None,
+            icon: None,
+            open: None,
+            disabled: false,
+            focus_handle: None,
+            on_toggle: None,
+            children: SmallVec::new(),
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+            width: relative(1.).into(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the header text.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets an icon to display before the header text.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Controls whether the section is expanded. When unset, the section
+    /// tracks its own state, starting collapsed.
+    pub fn open(mut self, open: bool) -> Self {
+        self.open = Some(open);
+        self
+    }
+
+    /// Sets the disabled state, preventing the section from being toggled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the focus handle for keyboard navigation.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
+    /// Sets a callback invoked with the new open state when the header is toggled.
+    pub fn on_toggle(mut self, on_toggle: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_toggle = Some(Rc::new(on_toggle));
+        self
+    }
+}
+
+impl ParentElement for Collapsible {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl MouseBehaviorExt for Collapsible {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for Collapsible {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let padding = cx.get_theme().layout.padding.md;
+        let corner_radius = cx.get_theme().layout.corner_radii.sm;
+        let text_size = cx.get_theme().layout.text.default_font.sizes.body.clone();
+        let (primary_text_color, secondary_text_color) =
+            cx.get_theme().variants.active(cx).colors.text.all();
+
+        let is_open_state =
+            window.use_keyed_state(self.id.with_suffix("state:open"), cx, |_cx, _window| false);
+        let is_open = self.open.unwrap_or(*is_open_state.read(cx));
+
+        let is_hover_state =
+            window.use_keyed_state(self.id.with_suffix("state:hover"), cx, |_cx, _window| false);
+        let is_hover = *is_hover_state.read(cx);
+
+        let content_height_state = window.use_keyed_state(
+            self.id.with_suffix("state:content_height"),
+            cx,
+            |_cx, _window| Pixels::ZERO,
+        );
+        let content_height = *content_height_state.read(cx);
+
+        let focus_handle = self
+            .focus_handle
+            .as_ref()
+            .unwrap_or_else(|| {
+                window
+                    .use_keyed_state(
+                        self.id.with_suffix("state:focus_handle"),
+                        cx,
+                        |_window, cx| cx.focus_handle().tab_stop(true),
+                    )
+                    .read(cx)
+            })
+            .clone();
+        let is_focus = focus_handle.is_focused(window);
+
+        let is_disabled = self.disabled;
+        let disabled_transition = disabled_transition(self.id.clone(), window, cx, is_disabled);
+
+        if is_focus && is_disabled {
+            window.blur();
+        }
+
+        let open_delta = *conitional_transition!(
+            self.id.with_suffix("state:transition:open"),
+            window,
+            cx,
+            Duration::from_millis(285),
+            {
+                is_open => 1.,
+                _ => 0.
+            }
+        )
+        .with_easing(ease_out_quint())
+        .evaluate(window, cx);
+
+        let header_color = *conitional_transition!(
+            self.id.with_suffix("state:transition:header_color"),
+            window,
+            cx,
+            Duration::from_millis(365),
+            {
+                is_hover || is_focus => primary_text_color,
+                _ => secondary_text_color
+            }
+        )
+        .with_easing(ease_out_quint())
+        .evaluate(window, cx);
+
+        let toggle: Rc<dyn Fn(&mut Window, &mut App)> = {
+            let is_controlled = self.open.is_some();
+            let is_open_state = is_open_state.clone();
+            let on_toggle = self.on_toggle.clone();
+
+            Rc::new(move |window, cx| {
+                let open = !is_open;
+
+                if !is_controlled {
+                    is_open_state.update(cx, |this, cx| {
+                        *this = open;
+                        cx.notify();
+                    });
+                }
+
+                if let Some(on_toggle) = on_toggle.as_ref() {
+                    (on_toggle)(&open, window, cx);
+                }
+            })
+        };
+
+        div()
+            .w(self.width)
+            .flex()
+            .flex_col()
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .opacity(*disabled_transition.evaluate(window, cx))
+            .child(
+                div()
+                    .id(self.id.with_suffix("header"))
+                    .key_context("Collapsible")
+                    .relative()
+                    .flex()
+                    .items_center()
+                    .gap(padding * 1.5)
+                    .px(padding)
+                    .py(padding)
+                    .text_size(text_size)
+                    .cursor(if is_disabled {
+                        CursorStyle::OperationNotAllowed
+                    } else {
+                        CursorStyle::PointingHand
+                    })
+                    .child(
+                        FocusRing::new(self.id.with_suffix("focus_ring"), focus_handle.clone())
+                            .rounded(corner_radius),
+                    )
+                    .child(
+                        Icon::new(TesseraeIconKind::ArrowDown)
+                            .size(px(11.))
+                            .color(header_color)
+                            .rotate(radians(chevron_rotation(open_delta))),
+                    )
+                    .when_some(self.icon, |this, icon| {
+                        this.child(Icon::new(icon).size(px(13.)).color(header_color))
+                    })
+                    .when_some(self.title, |this, title| {
+                        this.child(
+                            min_w0_wrapper()
+                                .text_ellipsis()
+                                .text_color(header_color)
+                                .child(title),
+                        )
+                    })
+                    .when(!is_disabled, |this| {
+                        let behavior = self.mouse_behavior;
+                        let toggle_on_action = toggle.clone();
+
+                        this.track_focus(&focus_handle)
+                            .on_hover(move |hover, _window, cx| {
+                                is_hover_state.update(cx, |this, cx| {
+                                    *this = *hover;
+                                    cx.notify();
+                                });
+                            })
+                            .on_click(move |_event, window, cx| {
+                                behavior.apply(window, cx);
+                                (toggle)(window, cx);
+                            })
+                            .on_action(move |_: &ToggleSection, window, cx| {
+                                (toggle_on_action)(window, cx);
+                            })
+                    }),
+            )
+            .when(open_delta != 0., |this| {
+                this.child(
+                    div()
+                        .w_full()
+                        .overflow_hidden()
+                        .opacity(open_delta)
+                        // Once fully open the content sizes itself, so it can keep growing.
+                        .when(open_delta < 1., |this| this.h(content_height * open_delta))
+                        .child(
+                            div()
+                                .relative()
+                                .w_full()
+                                .flex_shrink_0()
+                                .pt(padding)
+                                .child(
+                                    canvas(
+                                        move |bounds, _window, cx| {
+                                            content_height_state.update(cx, |this, cx| {
+                                                if *this != bounds.size.height {
+                                                    *this = bounds.size.height;
+                                                    cx.notify();
+                                                }
+                                            });
+                                        },
+                                        |_, _, _, _| {},
+                                    )
+                                    .absolute()
+                                    .size_full(),
+                                )
+                                .children(self.children),
+                        ),
+                )
+            })
+    }
+}
+
+/// Whether an [`Accordion`] allows one or several sections open at once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AccordionMode {
+    /// Opening a section closes the others.
+    #[default]
+    Single,
+    /// Sections open and close independently.
+    Multiple,
+}
+
+/// A single section within an [`Accordion`].
+pub struct AccordionItem {
+    value: SharedString,
+    title: Option<SharedString>,
+    icon: Option<SharedString>,
+    disabled: bool,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl AccordionItem {
+    /// Creates a new section identified by `value`.
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            title: None,
+            icon: None,
+            disabled: false,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets the header text.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets an icon to display before the header text.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the disabled state, preventing this section from being toggled.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl ParentElement for AccordionItem {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+/// A stack of collapsible sections separated by dividers.
+#[derive(IntoElement)]
+pub struct Accordion {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    items: Vec<AccordionItem>,
+    mode: AccordionMode,
+    open: Option<Vec<SharedString>>,
+    on_change: Option<OnAccordionChangeFn>,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+    width: Length,
+}
+
+impl Accordion {
+    /// Creates a new accordion with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            items: Vec::new(),
+            mode: AccordionMode::default(),
+            open: None,
+            on_change: None,
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+            width: relative(1.).into(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Adds a section.
+    pub fn item(mut self, item: AccordionItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Adds multiple sections.
+    pub fn items(mut self, items: impl IntoIterator<Item = AccordionItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    /// Sets whether one or several sections can be open at once. Defaults to single.
+    pub fn mode(mut self, mode: AccordionMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Controls which sections are open. When unset, the accordion tracks
+    /// its own state, starting with every section collapsed.
+    pub fn open(mut self, open: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.open = Some(open.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets a callback invoked with the values of the open sections after one is toggled.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl MouseBehaviorExt for Accordion {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for Accordion {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let divider_color = layer.next().resolve(cx);
+
+        let open_state =
+            window.use_keyed_state(self.id.with_suffix("state:open"), cx, |_cx, _window| {
+                Vec::<SharedString>::new()
+            });
+        let is_controlled = self.open.is_some();
+        let open: Rc<[SharedString]> = match self.open {
+            Some(open) => open.into(),
+            None => open_state.read(cx).as_slice().into(),
+        };
+
+        let item_ids: Vec<ElementId> = self
+            .items
+            .iter()
+            .map(|item| self.id.with_suffix(format!("item:{}", item.value)))
+            .collect();
+
+        let disabled: Rc<[bool]> = self.items.iter().map(|item| item.disabled).collect();
+
+        let focus_handles: Rc<[FocusHandle]> = item_ids
+            .iter()
+            .map(|item_id| {
+                window
+                    .use_keyed_state(
+                        item_id.with_suffix("state:focus_handle"),
+                        cx,
+                        |_window, cx| cx.focus_handle().tab_stop(true),
+                    )
+                    .read(cx)
+                    .clone()
+            })
+            .collect();
+
+        let toggle: Rc<dyn Fn(&SharedString, &mut Window, &mut App)> = {
+            let open = open.clone();
+            let mode = self.mode;
+            let on_change = self.on_change.clone();
+
+            Rc::new(move |value, window, cx| {
+                let next = toggled_sections(&open, value, mode);
+
+                if !is_controlled {
+                    open_state.update(cx, |this, cx| {
+                        *this = next.clone();
+                        cx.notify();
+                    });
+                }
+
+                if let Some(on_change) = on_change.as_ref() {
+                    (on_change)(&next, window, cx);
+                }
+            })
+        };
+
+        let navigate = {
+            let focus_handles = focus_handles.clone();
+            let disabled = disabled.clone();
+
+            Rc::new(
+                move |navigation: RovingNavigation, window: &mut Window, cx: &mut App| {
+                    let focused = focus_handles
+                        .iter()
+                        .position(|focus_handle| focus_handle.is_focused(window));
+
+                    if let Some(idx) = navigation_target(navigation, focused, &disabled) {
+                        focus_handles[idx].focus(window, cx);
+                    }
+                },
+            )
+        };
+        let (navigate_prev, navigate_next, navigate_first, navigate_last) = (
+            navigate.clone(),
+            navigate.clone(),
+            navigate.clone(),
+            navigate,
+        );
+
+        let item_count = self.items.len();
+
+        div()
+            .id(self.id.clone())
+            .key_context("Accordion")
+            .w(self.width)
+            .flex()
+            .flex_col()
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .on_action(move |_: &FocusPrev, window, cx| {
+                (navigate_prev)(RovingNavigation::Prev, window, cx)
+            })
+            .on_action(move |_: &FocusNext, window, cx| {
+                (navigate_next)(RovingNavigation::Next, window, cx)
+            })
+            .on_action(move |_: &FocusFirst, window, cx| {
+                (navigate_first)(RovingNavigation::First, window, cx)
+            })
+            .on_action(move |_: &FocusLast, window, cx| {
+                (navigate_last)(RovingNavigation::Last, window, cx)
+            })
+            .children(self.items.into_iter().zip(item_ids).enumerate().map(
+                |(idx, (item, item_id))| {
+                    let is_open = open.contains(&item.value);
+                    let toggle = toggle.clone();
+                    let value = item.value;
+
+                    let mut collapsible = Collapsible::new(item_id)
+                        .open(is_open)
+                        .disabled(item.disabled)
+                        .focus_handle(focus_handles[idx].clone())
+                        .when_some(item.title, |this, title| this.title(title))
+                        .when_some(item.icon, |this, icon| this.icon(icon))
+                        .on_toggle(move |_open, window, cx| (toggle)(&value, window, cx))
+                        .children(item.children);
+                    *collapsible.mouse_behavior_mut() = self.mouse_behavior;
+
+                    div()
+                        .w_full()
+                        .when(idx + 1 < item_count, |this| {
+                            this.border_b_1().border_color(divider_color)
+                        })
+                        .child(collapsible)
+                },
+            ))
+    }
+}
+
+/// Returns the open sections after `value` is toggled.
+fn toggled_sections(
+    open: &[SharedString],
+    value: &SharedString,
+    mode: AccordionMode,
+) -> Vec<SharedString> {
+    if open.contains(value) {
+        return open.iter().filter(|open| *open != value).cloned().collect();
+    }
+
+    match mode {
+        AccordionMode::Single => vec![value.clone()],
+        AccordionMode::Multiple => open.iter().chain([value]).cloned().collect(),
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use crate::theme::Theme;
+    use gpui::{AppContext, Render, TestAppContext, VisualTestContext};
+
+    #[gpui::test]
+    fn test_collapsible_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let collapsible = Collapsible::new("test-collapsible")
+                .title("Reasoning")
+                .icon("icons/test.svg")
+                .open(true)
+                .on_toggle(|_open, _window, _cx| {})
+                .child("Thinking about it...");
+
+            assert_eq!(collapsible.title, Some("Reasoning".into()));
+            assert!(
+                collapsible.icon.is_some(),
+                "Collapsible should have an icon"
+            );
+            assert_eq!(collapsible.open, Some(true));
+            assert!(collapsible.on_toggle.is_some());
+            assert_eq!(collapsible.children.len(), 1);
+        });
+    }
+
+    #[gpui::test]
+    fn test_accordion_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let accordion = Accordion::new("test-accordion")
+                .item(AccordionItem::new("a").title("A"))
+                .items([
+                    AccordionItem::new("b").title("B").disabled(true),
+                    AccordionItem::new("c").title("C"),
+                ])
+                .mode(AccordionMode::Multiple)
+                .open(["a", "c"]);
+
+            assert_eq!(accordion.items.len(), 3);
+            assert!(
+                accordion.items[1].disabled,
+                "Second item should be disabled"
+            );
+            assert_eq!(accordion.mode, AccordionMode::Multiple);
+            assert_eq!(
+                accordion.open,
+                Some(vec![SharedString::from("a"), SharedString::from("c")])
+            );
+            assert!(accordion.layer.is_none());
+        });
+    }
+
+    #[test]
+    fn test_toggled_sections() {
+        let open = [SharedString::from("a")];
+
+        assert_eq!(
+            toggled_sections(&open, &"b".into(), AccordionMode::Single),
+            vec![SharedString::from("b")]
+        );
+        assert_eq!(
+            toggled_sections(&open, &"b".into(), AccordionMode::Multiple),
+            vec![SharedString::from("a"), SharedString::from("b")]
+        );
+        assert_eq!(
+            toggled_sections(&open, &"a".into(), AccordionMode::Single),
+            Vec::<SharedString>::new()
+        );
+        assert_eq!(
+            toggled_sections(&open, &"a".into(), AccordionMode::Multiple),
+            Vec::<SharedString>::new()
+        );
+    }
+
+    #[gpui::test]
+    fn test_accordion_renders_in_window(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |window, cx| {
+                let focus_handle = cx.focus_handle();
+                focus_handle.focus(window, cx);
+                cx.new(|_cx| CollapsibleTestView {
+                    focus_handle,
+                    toggles: Vec::new(),
+                })
+            })
+            .unwrap()
+        });
+
+        let _cx = VisualTestContext::from_window(window.into(), cx);
+    }
+
+    #[gpui::test]
+    fn test_collapsible_keyboard_toggle(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|window, cx| {
+            cx.set_theme(Theme::DEFAULT);
+            init(cx);
+
+            let focus_handle = cx.focus_handle();
+            focus_handle.focus(window, cx);
+
+            CollapsibleTestView {
+                focus_handle,
+                toggles: Vec::new(),
+            }
+        });
+
+        cx.simulate_keystrokes("enter");
+        cx.simulate_keystrokes("space");
+        cx.simulate_keystrokes("enter");
+
+        cx.update(|_window, cx| {
+            assert_eq!(view.read(cx).toggles, vec![true, false, true]);
+        });
+    }
+
+    struct CollapsibleTestView {
+        focus_handle: FocusHandle,
+        toggles: Vec<bool>,
+    }
+
+    impl Render for CollapsibleTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            let entity = cx.entity();
+
+            div()
+                .size_full()
+                .child(
+                    Collapsible::new("test-collapsible")
+                        .title("Reasoning")
+                        .focus_handle(self.focus_handle.clone())
+                        .on_toggle(move |open, _window, cx| {
+                            let open = *open;
+                            entity.update(cx, |this, cx| {
+                                this.toggles.push(open);
+                                cx.notify();
+                            });
+                        })
+                        .child("Thinking about it..."),
+                )
+                .child(
+                    Accordion::new("test-accordion")
+                        .item(AccordionItem::new("a").title("A").child("First"))
+                        .item(AccordionItem::new("b").title("B").child("Second")),
+                )
+        }
+    }
+}
@@ -0,0 +1,466 @@
+use gpui::{
+    App, Edges, FontWeight, ImageSource, IntoElement, Length, ObjectFit, ParentElement, Pixels,
+    RenderOnce, SharedString, Styled, StyledImage, Window, div, hsla, img, prelude::FluentBuilder,
+    px, white,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+use smallvec::SmallVec;
+
+use crate::theme::{ThemeExt, ThemeLayerKind, ThemeLayoutSizeKind};
+
+/// The mask applied to an [`Avatar`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AvatarShape {
+    /// A circular mask.
+    #[default]
+    Circle,
+    /// A squircle mask using the size's corner radius.
+    Squircle,
+}
+
+/// A presence indicator shown in the corner of an [`Avatar`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AvatarStatus {
+    /// Available.
+    Online,
+    /// Available but not wanting to be disturbed.
+    Busy,
+    /// Not available.
+    Offline,
+}
+
+/// A user's picture, falling back to colored initials derived from their name.
+#[derive(IntoElement)]
+pub struct Avatar {
+    name: SharedString,
+    image: Option<ImageSource>,
+    size: ThemeLayoutSizeKind,
+    shape: AvatarShape,
+    status: Option<AvatarStatus>,
+    layer: Option<ThemeLayerKind>,
+    margin: Edges<Option<Length>>,
+}
+
+impl Avatar {
+    /// Creates a new avatar for the given name.
+    pub fn new(name: impl Into<SharedString>) -> Self {
+        Self {
+            name: name.into(),
+            image: None,
+            size: ThemeLayoutSizeKind::Lg,
+            shape: AvatarShape::default(),
+            status: None,
+            layer: None,
+            margin: Edges::default(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the image, from an embedded asset path or a local file path.
+    /// The initials are shown while it loads or if it fails to.
+    pub fn image(mut self, image: impl Into<ImageSource>) -> Self {
+        self.image = Some(image.into());
+        self
+    }
+
+    /// Sets the size from the theme's component sizes. Defaults to large.
+    pub fn size(mut self, size: ThemeLayoutSizeKind) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the mask shape.
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Shows a presence status dot.
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Sets the background layer the avatar sits on, used to outline the status dot.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+}
+
+impl RenderOnce for Avatar {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let size = self.size.resolve(cx);
+        let corner_radius = avatar_corner_radius(self.shape, &self.size, cx);
+        let background_color = self
+            .layer
+            .unwrap_or_else(|| ThemeLayerKind::current(cx))
+            .resolve(cx);
+        let colors = &cx.get_theme().variants.active(cx).colors;
+        let status_color = self.status.map(|status| match status {
+            AvatarStatus::Online => colors.accent.constructive,
+            AvatarStatus::Busy => colors.accent.destructive,
+            AvatarStatus::Offline => colors.text.secondary,
+        });
+
+        let initials = initials(&self.name);
+        let initials_element = move || {
+            div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .text_size(size * 0.4)
+                .font_weight(FontWeight::SEMIBOLD)
+                .text_color(white())
+                .child(initials.clone())
+        };
+
+        let status_size = (size * 0.3).max(px(6.));
+
+        div()
+            .relative()
+            .flex_shrink_0()
+            .size(size)
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .child(squircle().absolute_expand().rounded(corner_radius).bg(hsla(
+                name_hue(&self.name),
+                0.45,
+                0.42,
+                1.,
+            )))
+            .map(|this| match self.image {
+                Some(image) => this.child(
+                    img(image)
+                        .absolute()
+                        .size_full()
+                        .rounded(corner_radius)
+                        .object_fit(ObjectFit::Cover)
+                        .with_fallback(move || initials_element().into_any_element()),
+                ),
+                None => this.child(initials_element()),
+            })
+            .when_some(status_color, |this, status_color| {
+                this.child(
+                    div()
+                        .absolute()
+                        .right(px(-1.))
+                        .bottom(px(-1.))
+                        .size(status_size)
+                        .rounded(px(100.))
+                        .border(px(2.))
+                        .border_color(background_color)
+                        .bg(status_color),
+                )
+            })
+    }
+}
+
+/// A row of overlapping avatars, collapsing extras into a "+N" count.
+#[derive(IntoElement)]
+pub struct AvatarGroup {
+    avatars: SmallVec<[Avatar; 4]>,
+    max: usize,
+    size: ThemeLayoutSizeKind,
+    shape: AvatarShape,
+    layer: Option<ThemeLayerKind>,
+    margin: Edges<Option<Length>>,
+}
+
+impl AvatarGroup {
+    /// Creates a new, empty avatar group.
+    pub fn new() -> Self {
+        Self {
+            avatars: SmallVec::new(),
+            max: 4,
+            size: ThemeLayoutSizeKind::Lg,
+            shape: AvatarShape::default(),
+            layer: None,
+            margin: Edges::default(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Adds an avatar.
+    pub fn avatar(mut self, avatar: Avatar) -> Self {
+        self.avatars.push(avatar);
+        self
+    }
+
+    /// Adds multiple avatars.
+    pub fn avatars(mut self, avatars: impl IntoIterator<Item = Avatar>) -> Self {
+        self.avatars.extend(avatars);
+        self
+    }
+
+    /// Sets how many avatars are shown before the rest are collapsed into a count.
+    pub fn max(mut self, max: usize) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Sets the size of every avatar in the group.
+    pub fn size(mut self, size: ThemeLayoutSizeKind) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the mask shape of every avatar in the group.
+    pub fn shape(mut self, shape: AvatarShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Sets the background layer the group sits on, used to outline each avatar.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+}
+
+impl Default for AvatarGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for AvatarGroup {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        const RING_WIDTH: Pixels = px(2.);
+
+        let size = self.size.resolve(cx);
+        let corner_radius = avatar_corner_radius(self.shape, &self.size, cx);
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let overflow_color = layer.next().resolve(cx);
+        let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
+        let overlap = -(size * 0.3).round();
+
+        let (visible, hidden) = overflow_split(self.avatars.len(), self.max);
+
+        // Each avatar sits on a slightly larger plate of the background color,
+        // which outlines it against the one it overlaps.
+        let ringed = |index: usize| {
+            div()
+                .relative()
+                .flex_shrink_0()
+                .p(RING_WIDTH)
+                .when(index > 0, |this| this.ml(overlap))
+                .child(
+                    squircle()
+                        .absolute_expand()
+                        .rounded(corner_radius + RING_WIDTH)
+                        .bg(background_color),
+                )
+        };
+
+        let (size_kind, shape) = (self.size, self.shape);
+
+        div()
+            .flex()
+            .items_center()
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .children(self.avatars.into_iter().take(visible).enumerate().map(
+                |(index, mut avatar)| {
+                    avatar.size = size_kind;
+                    avatar.shape = shape;
+                    avatar.layer = Some(layer);
+
+                    ringed(index).child(avatar)
+                },
+            ))
+            .when(hidden > 0, |this| {
+                this.child(
+                    ringed(visible).child(
+                        div()
+                            .relative()
+                            .size(size)
+                            .flex()
+                            .items_center()
+                            .justify_center()
+                            .text_size(size * 0.35)
+                            .font_weight(FontWeight::MEDIUM)
+                            .text_color(secondary_text_color)
+                            .child(
+                                squircle()
+                                    .absolute_expand()
+                                    .rounded(corner_radius)
+                                    .bg(overflow_color),
+                            )
+                            .child(div().relative().child(format!("+{hidden}"))),
+                    ),
+                )
+            })
+    }
+}
+
+fn avatar_corner_radius(shape: AvatarShape, size: &ThemeLayoutSizeKind, cx: &App) -> Pixels {
+    match shape {
+        AvatarShape::Circle => px(100.),
+        AvatarShape::Squircle => size.corner_radii().resolve(cx),
+    }
+}
+
+/// Returns up to two initials from the first and last words of a name.
+fn initials(name: &str) -> SharedString {
+    let mut words = name.split_whitespace();
+    let first = words.next().and_then(|word| word.chars().next());
+    let last = words.last().and_then(|word| word.chars().next());
+
+    first
+        .into_iter()
+        .chain(last)
+        .flat_map(char::to_uppercase)
+        .collect::<String>()
+        .into()
+}
+
+/// Returns a hue for a name's fallback color. The same name always gets the
+/// same hue, so a user keeps their color across sessions.
+fn name_hue(name: &str) -> f32 {
+    // FNV-1a, which unlike `DefaultHasher` is stable across releases.
+    let hash = name.bytes().fold(0x811c9dc5_u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+
+    (hash % 360) as f32 / 360.
+}
+
+/// Returns how many avatars are shown and how many are collapsed into the count.
+fn overflow_split(count: usize, max: usize) -> (usize, usize) {
+    if count <= max {
+        return (count, 0);
+    }
+
+    // The count takes the place of the last avatar that would have fit.
+    let visible = max.saturating_sub(1);
+    (visible, count - visible)
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+
+    #[gpui::test]
+    fn test_avatar_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let avatar = Avatar::new("Ada Lovelace")
+                .image("avatars/ada.png")
+                .size(ThemeLayoutSizeKind::Xl)
+                .shape(AvatarShape::Squircle)
+                .status(AvatarStatus::Online);
+
+            assert_eq!(avatar.name, SharedString::from("Ada Lovelace"));
+            assert!(avatar.image.is_some(), "Avatar should have an image");
+            assert!(matches!(avatar.size, ThemeLayoutSizeKind::Xl));
+            assert_eq!(avatar.shape, AvatarShape::Squircle);
+            assert_eq!(avatar.status, Some(AvatarStatus::Online));
+        });
+    }
+
+    #[gpui::test]
+    fn test_avatar_group_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let group = AvatarGroup::new()
+                .avatar(Avatar::new("Ada"))
+                .avatars([Avatar::new("Grace"), Avatar::new("Alan")])
+                .max(2);
+
+            assert_eq!(group.avatars.len(), 3);
+            assert_eq!(group.max, 2);
+        });
+    }
+
+    #[test]
+    fn test_initials() {
+        assert_eq!(initials("Ada Lovelace").to_string(), "AL");
+        assert_eq!(initials("grace brewster hopper").to_string(), "GH");
+        assert_eq!(initials("alan").to_string(), "A");
+        assert_eq!(initials("  ").to_string(), "");
+        assert_eq!(initials("émile zola").to_string(), "ÉZ");
+    }
+
+    #[test]
+    fn test_name_hue() {
+        assert_eq!(name_hue("Ada Lovelace"), name_hue("Ada Lovelace"));
+        assert_ne!(name_hue("Ada Lovelace"), name_hue("Alan Turing"));
+        assert!((0. ..1.).contains(&name_hue("Grace Hopper")));
+    }
+
+    #[test]
+    fn test_overflow_split() {
+        assert_eq!(overflow_split(3, 4), (3, 0));
+        assert_eq!(overflow_split(4, 4), (4, 0));
+        assert_eq!(overflow_split(6, 4), (3, 3));
+        assert_eq!(overflow_split(2, 0), (0, 2));
+    }
+}
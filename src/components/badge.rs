@@ -0,0 +1,193 @@
+use gpui::{
+    App, Edges, IntoElement, Length, ParentElement, Pixels, RenderOnce, SharedString, Styled,
+    Window, div, prelude::FluentBuilder, px,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    theme::{ThemeAccentKind, ThemeExt},
+    utils::SquircleExt,
+};
+
+/// A small indicator showing a count, such as unread messages, or a plain dot.
+#[derive(IntoElement)]
+pub struct Badge {
+    accent: ThemeAccentKind,
+    count: u32,
+    max: u32,
+    dot: bool,
+    margin: Edges<Option<Length>>,
+}
+
+impl Badge {
+    /// Creates a new badge with a count of zero.
+    pub fn new() -> Self {
+        Self {
+            accent: ThemeAccentKind::Primary,
+            count: 0,
+            max: 99,
+            dot: false,
+            margin: Edges::default(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the count to display. The badge is hidden while the count is zero.
+    pub fn count(mut self, count: u32) -> Self {
+        self.count = count;
+        self
+    }
+
+    /// Sets the largest count shown before it is abbreviated, such as "99+".
+    pub fn max(mut self, max: u32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Shows a dot instead of a count.
+    pub fn dot(mut self, dot: bool) -> Self {
+        self.dot = dot;
+        self
+    }
+
+    /// Sets the accent color of the badge.
+    pub fn accent(mut self, accent: ThemeAccentKind) -> Self {
+        self.accent = accent;
+        self
+    }
+}
+
+impl Default for Badge {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for Badge {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        const DOT_SIZE: Pixels = px(8.);
+
+        let height = cx.get_theme().layout.size.md;
+        let padding = cx.get_theme().layout.padding.md;
+        let text_size = cx
+            .get_theme()
+            .layout
+            .text
+            .default_font
+            .sizes
+            .caption
+            .clone();
+        let primary_text_color = cx.get_theme().variants.active(cx).colors.text.primary;
+        let accent_color = self.accent.resolve(cx);
+
+        let label = (!self.dot)
+            .then(|| badge_label(self.count, self.max))
+            .flatten();
+
+        div()
+            .relative()
+            .flex()
+            .flex_shrink_0()
+            .items_center()
+            .justify_center()
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .map(|this| match (self.dot, label) {
+                (true, _) => this.size(DOT_SIZE).rounded(px(100.)).bg(accent_color),
+                (false, Some(label)) => this
+                    .h(height)
+                    .min_w(height)
+                    .px(padding * 1.25)
+                    .text_size(text_size)
+                    .text_color(primary_text_color)
+                    .child(
+                        squircle()
+                            .absolute_expand()
+                            .rounded(px(100.))
+                            .bg(accent_color)
+                            .border(px(1.))
+                            .border_inside()
+                            .border_highlight(0.15),
+                    )
+                    .child(div().relative().child(label)),
+                (false, None) => this.hidden(),
+            })
+    }
+}
+
+/// Returns the text for a count badge, abbreviating counts above `max`, or
+/// `None` when there is nothing to show.
+fn badge_label(count: u32, max: u32) -> Option<SharedString> {
+    match count {
+        0 => None,
+        count if count > max => Some(format!("{max}+").into()),
+        count => Some(count.to_string().into()),
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+
+    #[gpui::test]
+    fn test_badge_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let badge = Badge::new()
+                .count(4)
+                .max(9)
+                .accent(ThemeAccentKind::Destructive);
+
+            assert_eq!(badge.count, 4);
+            assert_eq!(badge.max, 9);
+            assert!(!badge.dot, "Badge should show a count");
+            assert!(matches!(badge.accent, ThemeAccentKind::Destructive));
+        });
+    }
+
+    #[test]
+    fn test_badge_label() {
+        assert_eq!(badge_label(0, 99), None);
+        assert_eq!(badge_label(7, 99).map(|l| l.to_string()), Some("7".into()));
+        assert_eq!(
+            badge_label(99, 99).map(|l| l.to_string()),
+            Some("99".into())
+        );
+        assert_eq!(
+            badge_label(100, 99).map(|l| l.to_string()),
+            Some("99+".into())
+        );
+    }
+}
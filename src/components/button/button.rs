@@ -10,14 +10,14 @@ use gpui_squircle::{SquircleStyled, squircle};
 use gpui_transitions::Lerp;
 
 use crate::{
-    components::Icon,
+    components::{Icon, Spinner},
     conitional_transition,
     extensions::{
         mouse_behavior::{MouseBehavior, MouseBehaviorExt},
         mouse_handleable::{MouseHandleable, MouseHandlers},
     },
     primitives::{FocusRing, min_w0_wrapper},
-    theme::ThemeExt,
+    theme::{ThemeExt, ThemeLayerKind},
     utils::{
         ElementIdExt, PixelsExt, PositionalChildren, PositionalParentElement, RgbaExt, SquircleExt,
         disabled_transition,
@@ -64,7 +64,9 @@ pub struct Button {
     icon: Option<SharedString>,
     icon_size: SizeRefinement<Length>,
     variant: ButtonVariantEither,
+    layer: Option<ThemeLayerKind>,
     disabled: bool,
+    loading: bool,
     force_hover: bool,
     focus_handle: Option<FocusHandle>,
     on_hover: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
@@ -86,7 +88,9 @@ impl Button {
                 height: Some(px(14.).into()),
             },
             variant: ButtonVariantEither::Left(ButtonVariant::Primary),
+            layer: None,
             disabled: false,
+            loading: false,
             force_hover: false,
             focus_handle: None,
             on_hover: None,
@@ -149,6 +153,12 @@ impl Button {
         self
     }
 
+    /// Shows a spinner in place of the icon and disables the button while true.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
     /// Forces the hover visual state regardless of actual hover.
     pub fn force_hover(mut self, force_hover: bool) -> Self {
         self.force_hover = force_hover;
@@ -161,6 +171,13 @@ impl Button {
         self
     }
 
+    /// Sets the background layer the button sits on, which its hover and focus colors blend into.
+    /// Defaults to the layer provided by the enclosing surface, or primary outside of one.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
     /// Sets the visual variant determining colors and styling.
     // ButtonVariantEither is an internal wrapper type for
     // allowing both `ButtonVariant` and `GranularButtonVariant`.
@@ -449,7 +466,10 @@ macro_rules! apply_corner_radii {
 
 impl RenderOnce for Button {
     fn render(self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        let variant = self.variant.into_granular(cx);
+        let layer = self
+            .layer
+            .unwrap_or_else(|| ThemeLayerKind::current_or(ThemeLayerKind::Primary, cx));
+        let variant = self.variant.into_granular(layer, cx);
         let line_height = cx.get_theme().layout.text.default_font.line_height;
         let text_size = cx.get_theme().layout.text.default_font.sizes.body.clone();
         let padding_override = self.style.padding;
@@ -489,7 +509,7 @@ impl RenderOnce for Button {
             .clone();
         let is_focus = focus_handle.is_focused(window);
 
-        let is_disabled = self.disabled;
+        let is_disabled = self.disabled || self.loading;
         let disabled_transition = disabled_transition(self.id.clone(), window, cx, is_disabled);
 
         if is_focus && is_disabled {
@@ -593,16 +613,29 @@ impl RenderOnce for Button {
                             .map(|this| {
                                 let text_color = *text_color_transition.evaluate(window, cx);
 
-                                this.when_some(self.icon.as_ref(), |this, icon| {
-                                    this.child(
-                                        Icon::new(icon)
-                                            .color(text_color)
-                                            .rotate(self.style.icon_rotate)
-                                            .map(|mut this| {
-                                                this.size = self.icon_size.clone();
-                                                this
-                                            }),
-                                    )
+                                this.map(|this| {
+                                    if self.loading {
+                                        return this.child(
+                                            Spinner::new(self.id.with_suffix("spinner"))
+                                                .color(text_color)
+                                                .map(|mut this| {
+                                                    this.icon_size = Some(self.icon_size.clone());
+                                                    this
+                                                }),
+                                        );
+                                    }
+
+                                    this.when_some(self.icon.as_ref(), |this, icon| {
+                                        this.child(
+                                            Icon::new(icon)
+                                                .color(text_color)
+                                                .rotate(self.style.icon_rotate)
+                                                .map(|mut this| {
+                                                    this.size = self.icon_size.clone();
+                                                    this
+                                                }),
+                                        )
+                                    })
                                 })
                                 .when_some(
                                     self.text.clone(),
@@ -622,7 +655,7 @@ impl RenderOnce for Button {
                     .children(self.children.right),
             )
             .children(self.children.bottom)
-            .when(!self.disabled, |this| {
+            .when(!is_disabled, |this| {
                 let is_hover_state_on_hover = is_hover_state.clone();
                 let is_click_down_state_on_mouse_down = is_click_down_state.clone();
                 let is_click_down_state_on_click = is_click_down_state.clone();
@@ -763,27 +796,32 @@ pub enum ButtonVariant {
 }
 
 impl ButtonVariant {
-    /// Converts this variant to a granular variant using theme colors.
+    /// Converts this variant to a granular variant using theme colors,
+    /// for a button on the layer provided by the enclosing surface,
+    /// or on the primary layer outside of one.
     pub fn as_granular(&self, cx: &App) -> GranularButtonVariant {
+        self.as_granular_on(ThemeLayerKind::current_or(ThemeLayerKind::Primary, cx), cx)
+    }
+
+    /// Converts this variant to a granular variant using theme colors,
+    /// for a button on `layer`.
+    pub fn as_granular_on(&self, layer: ThemeLayerKind, cx: &App) -> GranularButtonVariant {
         const HOVER_STRENGTH: f32 = 0.15;
         const FOCUS_STRENGTH: f32 = 0.35;
 
         const SECONDARY_ALPHA: f32 = 0.1;
 
         let colors = &cx.get_theme().variants.active(cx).colors;
-        let primary_background = colors.background.primary;
+        let surface_color = layer.resolve(cx);
 
-        fn secondary_variant(
-            primary_background: &Rgba,
-            main_color: &Rgba,
-        ) -> GranularButtonVariant {
+        fn secondary_variant(surface_color: &Rgba, main_color: &Rgba) -> GranularButtonVariant {
             GranularButtonVariant {
                 bg_color: main_color.alpha(SECONDARY_ALPHA),
                 bg_hover_color: main_color
-                    .lerp(&primary_background, HOVER_STRENGTH)
+                    .lerp(&surface_color, HOVER_STRENGTH)
                     .alpha(SECONDARY_ALPHA),
                 bg_focus_color: main_color
-                    .lerp(&primary_background, FOCUS_STRENGTH)
+                    .lerp(&surface_color, FOCUS_STRENGTH)
                     .alpha(SECONDARY_ALPHA),
                 text_color: *main_color,
                 highlight_alpha: 0.05,
@@ -791,12 +829,12 @@ impl ButtonVariant {
             }
         }
 
-        fn ghost_variant(primary_background: &Rgba, main_color: &Rgba) -> GranularButtonVariant {
+        fn ghost_variant(surface_color: &Rgba, main_color: &Rgba) -> GranularButtonVariant {
             GranularButtonVariant {
                 bg_color: main_color.alpha(0.),
                 bg_hover_color: main_color.alpha(SECONDARY_ALPHA),
                 bg_focus_color: main_color
-                    .lerp(&primary_background, HOVER_STRENGTH)
+                    .lerp(&surface_color, HOVER_STRENGTH)
                     .alpha(SECONDARY_ALPHA),
                 text_color: *main_color,
                 highlight_alpha: 0.,
@@ -807,49 +845,35 @@ impl ButtonVariant {
         match self {
             ButtonVariant::Primary => GranularButtonVariant {
                 bg_color: colors.accent.primary,
-                bg_hover_color: colors
-                    .accent
-                    .primary
-                    .lerp(&primary_background, HOVER_STRENGTH),
-                bg_focus_color: colors
-                    .accent
-                    .primary
-                    .lerp(&primary_background, FOCUS_STRENGTH),
+                bg_hover_color: colors.accent.primary.lerp(&surface_color, HOVER_STRENGTH),
+                bg_focus_color: colors.accent.primary.lerp(&surface_color, FOCUS_STRENGTH),
                 text_color: colors.text.primary,
                 highlight_alpha: 0.15,
                 highlight_active_alpha: 0.15,
             },
 
-            ButtonVariant::Secondary => {
-                secondary_variant(&primary_background, &colors.text.primary)
-            }
+            ButtonVariant::Secondary => secondary_variant(&surface_color, &colors.text.primary),
 
-            ButtonVariant::SecondaryGhost => {
-                ghost_variant(&primary_background, &colors.text.primary)
-            }
+            ButtonVariant::SecondaryGhost => ghost_variant(&surface_color, &colors.text.primary),
 
-            ButtonVariant::Tertiary => {
-                secondary_variant(&primary_background, &colors.text.secondary)
-            }
+            ButtonVariant::Tertiary => secondary_variant(&surface_color, &colors.text.secondary),
 
-            ButtonVariant::TertiaryGhost => {
-                ghost_variant(&primary_background, &colors.text.secondary)
-            }
+            ButtonVariant::TertiaryGhost => ghost_variant(&surface_color, &colors.text.secondary),
 
             ButtonVariant::Constructive => {
-                secondary_variant(&primary_background, &colors.accent.constructive)
+                secondary_variant(&surface_color, &colors.accent.constructive)
             }
 
             ButtonVariant::ConstructiveGhost => {
-                ghost_variant(&primary_background, &colors.accent.constructive)
+                ghost_variant(&surface_color, &colors.accent.constructive)
             }
 
             ButtonVariant::Destructive => {
-                secondary_variant(&primary_background, &colors.accent.destructive)
+                secondary_variant(&surface_color, &colors.accent.destructive)
             }
 
             ButtonVariant::DestructiveGhost => {
-                ghost_variant(&primary_background, &colors.accent.destructive)
+                ghost_variant(&surface_color, &colors.accent.destructive)
             }
         }
     }
@@ -861,9 +885,9 @@ enum ButtonVariantEither {
 }
 
 impl ButtonVariantEither {
-    fn into_granular(self, cx: &mut App) -> GranularButtonVariant {
+    fn into_granular(self, layer: ThemeLayerKind, cx: &mut App) -> GranularButtonVariant {
         match self {
-            ButtonVariantEither::Left(left) => left.as_granular(cx),
+            ButtonVariantEither::Left(left) => left.as_granular_on(layer, cx),
             ButtonVariantEither::Right(right) => right,
         }
     }
@@ -914,6 +938,56 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    fn test_button_layer(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let button = Button::new("test-button");
+            assert!(
+                button.layer.is_none(),
+                "Button should default to the enclosing layer"
+            );
+
+            let button = button.layer(ThemeLayerKind::Secondary);
+            assert!(
+                matches!(button.layer, Some(ThemeLayerKind::Secondary)),
+                "Button should have secondary layer"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_button_variant_outside_layer_scope_blends_with_primary(cx: &mut TestAppContext) {
+        use crate::theme::Theme;
+
+        cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+            let colors = &cx.get_theme().variants.active(cx).colors;
+
+            let variant = ButtonVariant::Primary.as_granular(cx);
+            assert_eq!(
+                variant.bg_hover_color,
+                colors.accent.primary.lerp(&colors.background.primary, 0.15),
+                "Unscoped buttons should hover toward the primary background"
+            );
+            assert_eq!(
+                variant.bg_focus_color,
+                colors.accent.primary.lerp(&colors.background.primary, 0.35),
+                "Unscoped buttons should focus toward the primary background"
+            );
+
+            let variant = ButtonVariant::Secondary.as_granular(cx);
+            assert_eq!(
+                variant.bg_hover_color,
+                colors
+                    .text
+                    .primary
+                    .lerp(&colors.background.primary, 0.15)
+                    .alpha(0.1),
+                "Unscoped secondary buttons should hover toward the primary background"
+            );
+        });
+    }
+
     #[gpui::test]
     fn test_button_icon(cx: &mut TestAppContext) {
         cx.update(|_cx| {
@@ -934,6 +1008,18 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    fn test_button_loading_state(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let button = Button::new("test-button");
+            assert!(!button.loading, "Button should start not loading");
+
+            let button = button.loading(true);
+            assert!(button.loading, "Button should be loading");
+            assert!(!button.disabled, "Loading should not change disabled");
+        });
+    }
+
     #[gpui::test]
     fn test_button_variants(cx: &mut TestAppContext) {
         cx.update(|_cx| {
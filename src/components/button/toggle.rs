@@ -10,6 +10,7 @@ use crate::{
         mouse_behavior::{MouseBehavior, MouseBehaviorExt},
         mouse_handleable::{MouseHandleable, MouseHandlers},
     },
+    theme::ThemeLayerKind,
     utils::RgbaExt,
 };
 
@@ -17,6 +18,7 @@ use crate::{
 #[derive(IntoElement)]
 pub struct Toggle {
     variant: ToggleVariantEither,
+    layer: Option<ThemeLayerKind>,
     checked: bool,
     base: Button,
 }
@@ -27,6 +29,7 @@ impl Toggle {
         Self {
             checked: false,
             variant: ToggleVariantEither::Left(ToggleVariant::Primary),
+            layer: None,
             base: Button::new(id),
         }
     }
@@ -97,6 +100,13 @@ impl Toggle {
         self
     }
 
+    /// Sets the background layer the toggle sits on, which its hover and focus colors blend into.
+    /// Defaults to the layer provided by the enclosing surface, or primary outside of one.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
     /// Sets the visual variant determining colors for checked and unchecked states.
     // ToggleVariantEither is an internal wrapper type for
     // allowing both `ButtonVariant` and `GranularToggleVariant`.
@@ -339,7 +349,10 @@ impl MouseBehaviorExt for Toggle {
 
 impl RenderOnce for Toggle {
     fn render(self, _window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        let variant = self.variant.into_granular(cx);
+        let layer = self
+            .layer
+            .unwrap_or_else(|| ThemeLayerKind::current_or(ThemeLayerKind::Primary, cx));
+        let variant = self.variant.into_granular(layer, cx);
 
         self.base.variant(if self.checked {
             variant.truthy
@@ -379,8 +392,8 @@ pub struct GranularToggleVariant {
 }
 
 impl GranularToggleVariant {
-    fn from_button_variant(variant: ButtonVariant, cx: &App) -> Self {
-        let variant = variant.as_granular(cx);
+    fn from_button_variant(variant: ButtonVariant, layer: ThemeLayerKind, cx: &App) -> Self {
+        let variant = variant.as_granular_on(layer, cx);
 
         Self {
             truthy: variant.clone(),
@@ -395,9 +408,9 @@ enum ToggleVariantEither {
 }
 
 impl ToggleVariantEither {
-    fn into_granular(self, cx: &mut App) -> GranularToggleVariant {
+    fn into_granular(self, layer: ThemeLayerKind, cx: &mut App) -> GranularToggleVariant {
         match self {
-            ToggleVariantEither::Left(left) => left.as_granular_toggle(cx),
+            ToggleVariantEither::Left(left) => left.as_granular_toggle_on(layer, cx),
             ToggleVariantEither::Right(right) => right,
         }
     }
@@ -416,23 +429,31 @@ impl From<GranularToggleVariant> for ToggleVariantEither {
 }
 
 impl ToggleVariant {
-    /// Converts this variant to a granular toggle variant using theme colors.
+    /// Converts this variant to a granular toggle variant using theme colors,
+    /// for a toggle on the layer provided by the enclosing surface,
+    /// or on the primary layer outside of one.
     pub fn as_granular_toggle(&self, cx: &App) -> GranularToggleVariant {
+        self.as_granular_toggle_on(ThemeLayerKind::current_or(ThemeLayerKind::Primary, cx), cx)
+    }
+
+    /// Converts this variant to a granular toggle variant using theme colors,
+    /// for a toggle on `layer`.
+    pub fn as_granular_toggle_on(&self, layer: ThemeLayerKind, cx: &App) -> GranularToggleVariant {
         match self {
             ToggleVariant::Primary => {
-                GranularToggleVariant::from_button_variant(ButtonVariant::Primary, cx)
+                GranularToggleVariant::from_button_variant(ButtonVariant::Primary, layer, cx)
             }
             ToggleVariant::Secondary => {
-                GranularToggleVariant::from_button_variant(ButtonVariant::Secondary, cx)
+                GranularToggleVariant::from_button_variant(ButtonVariant::Secondary, layer, cx)
             }
             ToggleVariant::Tertiary => {
-                GranularToggleVariant::from_button_variant(ButtonVariant::Tertiary, cx)
+                GranularToggleVariant::from_button_variant(ButtonVariant::Tertiary, layer, cx)
             }
             ToggleVariant::Constructive => {
-                GranularToggleVariant::from_button_variant(ButtonVariant::Constructive, cx)
+                GranularToggleVariant::from_button_variant(ButtonVariant::Constructive, layer, cx)
             }
             ToggleVariant::Destructive => {
-                GranularToggleVariant::from_button_variant(ButtonVariant::Destructive, cx)
+                GranularToggleVariant::from_button_variant(ButtonVariant::Destructive, layer, cx)
             }
         }
     }
@@ -451,6 +472,20 @@ mod tests {
     use super::*;
     use gpui::TestAppContext;
 
+    #[gpui::test]
+    fn test_toggle_variant_outside_layer_scope_blends_with_primary(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            let toggle = ToggleVariant::Primary.as_granular_toggle(cx);
+            let button = ButtonVariant::Primary.as_granular_on(ThemeLayerKind::Primary, cx);
+            assert_eq!(toggle.truthy.bg_hover_color, button.bg_hover_color);
+            assert_eq!(toggle.truthy.bg_focus_color, button.bg_focus_color);
+        });
+    }
+
     #[gpui::test]
     fn test_toggle_mouse_behavior_default(cx: &mut TestAppContext) {
         cx.update(|_cx| {
@@ -0,0 +1,191 @@
+use gpui::{
+    AnyElement, App, IntoElement, ParentElement, RenderOnce, StyleRefinement, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+use smallvec::SmallVec;
+
+use crate::{
+    primitives::layer_scope,
+    theme::{ThemeExt, ThemeLayerKind, ThemeLayoutCornerRadiiKind},
+};
+
+/// A themed surface that groups related content.
+///
+/// Children without an explicit layer, including nested cards, render one
+/// layer above the card so they stand out from its background.
+#[derive(IntoElement)]
+pub struct Card {
+    layer: Option<ThemeLayerKind>,
+    corner_radius: ThemeLayoutCornerRadiiKind,
+    children: SmallVec<[AnyElement; 2]>,
+    style: StyleRefinement,
+}
+
+impl Card {
+    /// Creates a new, empty card.
+    pub fn new() -> Self {
+        Self {
+            layer: None,
+            corner_radius: ThemeLayoutCornerRadiiKind::Lg,
+            children: SmallVec::new(),
+            style: StyleRefinement::default().flex().flex_col(),
+        }
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the corner radius from the theme. Defaults to large.
+    pub fn rounded(mut self, corner_radius: ThemeLayoutCornerRadiiKind) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+}
+
+impl Default for Card {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ParentElement for Card {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl Styled for Card {
+    fn style(&mut self) -> &mut StyleRefinement {
+        &mut self.style
+    }
+}
+
+impl RenderOnce for Card {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let padding = cx.get_theme().layout.padding.xl;
+
+        let mut style = self.style;
+        for edge in [
+            &mut style.padding.top,
+            &mut style.padding.bottom,
+            &mut style.padding.left,
+            &mut style.padding.right,
+        ] {
+            edge.get_or_insert_with(|| padding.into());
+        }
+
+        layer_scope(
+            layer.nested(),
+            div()
+                .relative()
+                .map(|mut this| {
+                    this.style().refine(&style);
+                    this
+                })
+                .child(
+                    squircle()
+                        .absolute_expand()
+                        .rounded(self.corner_radius.resolve(cx))
+                        .bg(background_color)
+                        .border(px(1.))
+                        .border_inside()
+                        .border_color(border_color),
+                )
+                .children(self.children),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+    use crate::theme::Theme;
+    use gpui::{AppContext, Render, TestAppContext, VisualTestContext, point, size};
+
+    #[gpui::test]
+    fn test_card_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let card = Card::new()
+                .layer(ThemeLayerKind::Primary)
+                .rounded(ThemeLayoutCornerRadiiKind::Md)
+                .child("Settings");
+
+            assert!(matches!(card.layer, Some(ThemeLayerKind::Primary)));
+            assert!(matches!(card.corner_radius, ThemeLayoutCornerRadiiKind::Md));
+            assert_eq!(card.children.len(), 1);
+        });
+    }
+
+    #[gpui::test]
+    fn test_card_provides_nested_layer(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |_window, cx| cx.new(|_cx| EmptyView))
+                .unwrap()
+        });
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+
+        let outer = Rc::new(Cell::new(None));
+        let inner = Rc::new(Cell::new(None));
+        let outside = Rc::new(Cell::new(None));
+
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(px(400.), px(400.)),
+            |_window, _cx| {
+                div()
+                    .child(
+                        Card::new()
+                            .layer(ThemeLayerKind::Primary)
+                            .child(LayerProbe(outer.clone()))
+                            .child(Card::new().child(LayerProbe(inner.clone()))),
+                    )
+                    .child(LayerProbe(outside.clone()))
+            },
+        );
+
+        assert!(matches!(outer.get(), Some(ThemeLayerKind::Secondary)));
+        assert!(matches!(inner.get(), Some(ThemeLayerKind::Tertiary)));
+        assert!(matches!(outside.get(), Some(ThemeLayerKind::Tertiary)));
+        cx.update(|_window, cx| {
+            assert!(matches!(
+                ThemeLayerKind::current(cx),
+                ThemeLayerKind::Tertiary
+            ));
+        });
+    }
+
+    struct EmptyView;
+
+    impl Render for EmptyView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            _cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            div()
+        }
+    }
+
+    /// Records the layer a component would inherit where it is rendered.
+    #[derive(IntoElement)]
+    struct LayerProbe(Rc<Cell<Option<ThemeLayerKind>>>);
+
+    impl RenderOnce for LayerProbe {
+        fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+            self.0.set(Some(ThemeLayerKind::current(cx)));
+            div()
+        }
+    }
+}
@@ -37,7 +37,7 @@ pub enum ChatBubbleAnchor {
 #[derive(IntoElement)]
 pub struct ChatBubble {
     id: ElementId,
-    layer: ThemeLayerKind,
+    layer: Option<ThemeLayerKind>,
     anchor: ChatBubbleAnchor,
     children: SmallVec<[AnyElement; 2]>,
     focus_handle: Option<FocusHandle>,
@@ -49,7 +49,7 @@ impl ChatBubble {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            layer: ThemeLayerKind::Tertiary,
+            layer: None,
             anchor: ChatBubbleAnchor::default(),
             children: SmallVec::new(),
             focus_handle: None,
@@ -88,6 +88,13 @@ impl ChatBubble {
         self
     }
 
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
     /// Sets the anchor position, which determines which corner has reduced radius.
     pub fn anchor(mut self, anchor: ChatBubbleAnchor) -> Self {
         self.anchor = anchor;
@@ -103,8 +110,9 @@ impl ChatBubble {
 
 impl RenderOnce for ChatBubble {
     fn render(self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        let background_color = self.layer.resolve(cx);
-        let border_color = self.layer.next().resolve(cx);
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
         let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
         let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
         let corner_radius = cx.get_theme().layout.corner_radii.xl;
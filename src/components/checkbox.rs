@@ -24,7 +24,7 @@ use crate::{
 pub struct Checkbox {
     id: ElementId,
     icon: SharedString,
-    layer: ThemeLayerKind,
+    layer: Option<ThemeLayerKind>,
     checked: bool,
     disabled: bool,
     force_hover: bool,
@@ -41,7 +41,7 @@ impl Checkbox {
         Self {
             id: id.into(),
             icon: TesseraeIconKind::Checkmark.into(),
-            layer: ThemeLayerKind::Tertiary,
+            layer: None,
             checked: false,
             disabled: false,
             force_hover: false,
@@ -97,8 +97,9 @@ impl Checkbox {
     }
 
     /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
     pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
-        self.layer = layer;
+        self.layer = Some(layer);
         self
     }
 
@@ -148,8 +149,9 @@ impl RenderOnce for Checkbox {
         let corner_radius = cx.get_theme().layout.corner_radii.sm;
         let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
         let primary_text_color = cx.get_theme().variants.active(cx).colors.text.primary;
-        let background_color = self.layer.resolve(cx);
-        let border_color = self.layer.next().resolve(cx);
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
         let border_hover_color = border_color.lerp(&primary_text_color, 0.07);
         let border_click_down_color = border_color.lerp(&primary_text_color, 0.16);
 
@@ -401,13 +403,13 @@ mod tests {
         cx.update(|_cx| {
             let checkbox = Checkbox::new("test-checkbox").layer(ThemeLayerKind::Primary);
             assert!(
-                matches!(checkbox.layer, ThemeLayerKind::Primary),
+                matches!(checkbox.layer, Some(ThemeLayerKind::Primary)),
                 "Checkbox should have primary layer"
             );
 
             let checkbox = Checkbox::new("test-checkbox").layer(ThemeLayerKind::Secondary);
             assert!(
-                matches!(checkbox.layer, ThemeLayerKind::Secondary),
+                matches!(checkbox.layer, Some(ThemeLayerKind::Secondary)),
                 "Checkbox should have secondary layer"
             );
         });
@@ -436,7 +438,7 @@ mod tests {
             assert!(checkbox.checked, "Checkbox should be checked");
             assert!(checkbox.disabled, "Checkbox should be disabled");
             assert!(
-                matches!(checkbox.layer, ThemeLayerKind::Secondary),
+                matches!(checkbox.layer, Some(ThemeLayerKind::Secondary)),
                 "Checkbox should have secondary layer"
             );
         });
@@ -0,0 +1,226 @@
+use std::rc::Rc;
+
+use gpui::{
+    App, Edges, ElementId, FocusHandle, IntoElement, Length, RenderOnce, SharedString, Window,
+    prelude::FluentBuilder, px,
+};
+
+use crate::{
+    ElementIdExt, PositionalParentElement, TesseraeIconKind,
+    components::{Button, ButtonVariant, Toggle, ToggleVariant},
+    extensions::{
+        mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+        mouse_handleable::{MouseHandleable, MouseHandlers},
+    },
+    theme::{ThemeExt, ThemeLayoutCornerRadiiKind},
+};
+
+/// A compact, optionally selectable and removable item, such as an attachment.
+#[derive(IntoElement)]
+pub struct Chip {
+    id: ElementId,
+    text: Option<SharedString>,
+    icon: Option<SharedString>,
+    selected: bool,
+    disabled: bool,
+    variant: ToggleVariant,
+    corner_radius: ThemeLayoutCornerRadiiKind,
+    focus_handle: Option<FocusHandle>,
+    on_remove: Option<Rc<dyn Fn(&mut Window, &mut App) + 'static>>,
+    mouse_handlers: MouseHandlers,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+}
+
+impl Chip {
+    /// Creates a new chip with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            text: None,
+            icon: None,
+            selected: false,
+            disabled: false,
+            variant: ToggleVariant::Secondary,
+            corner_radius: ThemeLayoutCornerRadiiKind::Md,
+            focus_handle: None,
+            on_remove: None,
+            mouse_handlers: MouseHandlers::new(),
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the chip's text label.
+    pub fn text(mut self, text: impl Into<SharedString>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets a leading icon.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the selected state. Use `on_click` to toggle it.
+    pub fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Sets the disabled state, preventing interaction.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the visual style used while selected.
+    pub fn variant(mut self, variant: ToggleVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Sets the corner radius from the theme. Defaults to medium.
+    pub fn rounded(mut self, corner_radius: ThemeLayoutCornerRadiiKind) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    /// Sets the focus handle for keyboard navigation.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
+    /// Shows a remove button that invokes `on_remove` when clicked.
+    pub fn on_remove(mut self, on_remove: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.on_remove = Some(Rc::new(on_remove));
+        self
+    }
+}
+
+impl MouseHandleable for Chip {
+    fn mouse_handlers_mut(&mut self) -> &mut MouseHandlers {
+        &mut self.mouse_handlers
+    }
+}
+
+impl MouseBehaviorExt for Chip {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for Chip {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let padding = cx.get_theme().layout.padding.md;
+        let corner_radius = self.corner_radius.resolve(cx);
+        let is_removable = self.on_remove.is_some();
+
+        let mut toggle = Toggle::new(self.id.clone())
+            .variant(self.variant)
+            .checked(self.selected)
+            .disabled(self.disabled)
+            .rounded(corner_radius)
+            .icon_size(px(12.))
+            .gap(padding * 1.5)
+            .pt(padding)
+            .pb(padding)
+            .pl(padding * 2.5)
+            .pr(if is_removable { padding } else { padding * 2.5 })
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .when_some(self.focus_handle, |this, focus_handle| {
+                this.focus_handle(focus_handle)
+            })
+            .when_some(self.icon, |this, icon| this.icon(icon))
+            .when_some(self.text, |this, text| this.text(text));
+
+        if let Some(on_remove) = self.on_remove {
+            toggle = toggle.child_right(
+                Button::new(self.id.with_suffix("remove"))
+                    .variant(ButtonVariant::TertiaryGhost)
+                    .icon(TesseraeIconKind::Close)
+                    .icon_size(px(8.))
+                    .p(px(4.))
+                    .rounded(px(100.))
+                    .disabled(self.disabled)
+                    .on_click(move |_event, window, cx| (on_remove)(window, cx)),
+            );
+        }
+
+        *toggle.mouse_handlers_mut() = self.mouse_handlers;
+        *toggle.mouse_behavior_mut() = self.mouse_behavior;
+
+        toggle
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+
+    #[gpui::test]
+    fn test_chip_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let chip = Chip::new("test-chip")
+                .text("notes.pdf")
+                .icon("icons/test.svg")
+                .selected(true)
+                .rounded(ThemeLayoutCornerRadiiKind::Sm)
+                .on_remove(|_window, _cx| {});
+
+            assert_eq!(chip.text, Some("notes.pdf".into()));
+            assert!(chip.icon.is_some(), "Chip should have an icon");
+            assert!(chip.selected, "Chip should be selected");
+            assert!(chip.on_remove.is_some(), "Chip should be removable");
+            assert!(matches!(chip.corner_radius, ThemeLayoutCornerRadiiKind::Sm));
+        });
+    }
+
+    #[gpui::test]
+    fn test_chip_defaults(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let chip = Chip::new("test-chip");
+
+            assert!(!chip.selected, "Chip should start unselected");
+            assert!(!chip.disabled, "Chip should start enabled");
+            assert!(chip.on_remove.is_none(), "Chip should not be removable");
+        });
+    }
+}
@@ -0,0 +1,394 @@
+use std::sync::Arc;
+
+use gpui::{
+    App, ElementId, Entity, FocusHandle, FontWeight, InteractiveElement, IntoElement,
+    ParentElement, Pixels, RenderOnce, Rgba, Styled, Window, div, prelude::FluentBuilder, px,
+    radians,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    ElementIdExt, TesseraeIconKind,
+    components::{
+        Button, ButtonVariant, Toggle, ToggleVariant,
+        date_picker::{
+            CalendarDate, Confirm, DatePickerState, DateRange, Dismiss, EndOfWeek, NextDay,
+            NextMonth, NextWeek, NextYear, PreviousDay, PreviousMonth, PreviousWeek, PreviousYear,
+            StartOfWeek, month_grid, month_name,
+        },
+    },
+    extensions::{
+        deferrable::{Deferrable, DeferredConfig},
+        mouse_handleable::MouseHandleable,
+    },
+    primitives::layer_scope,
+    theme::{ThemeExt, ThemeLayerKind},
+    views::RootWindowExt,
+};
+
+/// The calendar popover of a DatePicker component, rendered as a deferred overlay.
+#[derive(IntoElement)]
+pub struct DatePickerCalendar {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    state: Arc<DatePickerState>,
+    focus_handle: Option<FocusHandle>,
+    deferred_config: DeferredConfig,
+}
+
+impl DatePickerCalendar {
+    /// Creates a new calendar with the given ID and shared state.
+    pub fn new(id: impl Into<ElementId>, state: impl Into<Arc<DatePickerState>>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            state: state.into(),
+            focus_handle: None,
+            deferred_config: DeferredConfig::default(),
+        }
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the focus handle the calendar grid tracks for keyboard navigation.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+}
+
+impl Deferrable for DatePickerCalendar {
+    const DEFAULT_PRIORITY: usize = 1;
+
+    fn deferred_config_mut(&mut self) -> &mut DeferredConfig {
+        &mut self.deferred_config
+    }
+
+    fn deferred_config(&self) -> &DeferredConfig {
+        &self.deferred_config
+    }
+}
+
+impl RenderOnce for DatePickerCalendar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let corner_radius = cx.get_theme().layout.corner_radii.md;
+        let padding = cx.get_theme().layout.padding.md;
+        let cell_size = cx.get_theme().layout.size.lg;
+        let caption_size = cx.get_theme().layout.text.default_font.sizes.caption;
+        let (primary_text_color, secondary_text_color) =
+            cx.get_theme().variants.active(cx).colors.text.all();
+        let accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+
+        let state = self.state.clone();
+        let menu_visible_delta = state.menu_visible_transition.evaluate(window, cx).value();
+
+        let focus_handle = self
+            .focus_handle
+            .as_ref()
+            .unwrap_or_else(|| {
+                window
+                    .use_keyed_state(
+                        self.id.with_suffix("state:focus_handle"),
+                        cx,
+                        |_window, cx| cx.focus_handle().tab_stop(true),
+                    )
+                    .read(cx)
+            })
+            .clone();
+
+        // The date under the mouse, which previews the range while picking its last date.
+        let hovered_date = window.use_keyed_state(
+            self.id.with_suffix("state:hovered_date"),
+            cx,
+            |_window, _cx| None::<CalendarDate>,
+        );
+
+        if menu_visible_delta == 0. && hovered_date.read(cx).is_some() {
+            hovered_date.update(cx, |this, _cx| *this = None);
+        }
+
+        let is_keyboard_nav = focus_handle.is_focused(window);
+        let highlighted_date = state.highlighted_date(cx);
+        let show_highlight = is_keyboard_nav && hovered_date.read(cx).is_none();
+
+        let range = match state.range_anchor(cx) {
+            Some(anchor) => {
+                let end = hovered_date
+                    .read(cx)
+                    .or(show_highlight.then_some(highlighted_date))
+                    .unwrap_or(anchor);
+                Some(DateRange::new(anchor, end))
+            }
+            None => state.selected_range(cx),
+        };
+
+        let visible_month = state.visible_month(cx);
+        let first_weekday = state.first_weekday;
+
+        let days = CalendarDays {
+            id: self.id.clone(),
+            state: state.clone(),
+            range,
+            visible_month,
+            highlighted_date: show_highlight.then_some(highlighted_date),
+            hovered_date: hovered_date.clone(),
+            cell_size,
+            corner_radius: corner_radius - padding,
+            today_color: accent_color,
+        };
+
+        // Wraps a highlight movement for a key action, clearing the hover state
+        // so the keyboard highlight shows.
+        let on_move_highlight = |move_date: fn(CalendarDate) -> CalendarDate| {
+            let state = state.clone();
+            let hovered_date = hovered_date.clone();
+
+            move |_window: &mut Window, cx: &mut App| {
+                hovered_date.update(cx, |this, _cx| *this = None);
+                state.move_highlight(cx, move_date);
+            }
+        };
+        let previous_day = on_move_highlight(|date| date.add_days(-1));
+        let next_day = on_move_highlight(|date| date.add_days(1));
+        let previous_week = on_move_highlight(|date| date.add_days(-7));
+        let next_week = on_move_highlight(|date| date.add_days(7));
+        let previous_month = on_move_highlight(|date| date.add_months(-1));
+        let next_month = on_move_highlight(|date| date.add_months(1));
+        let previous_year = on_move_highlight(|date| date.add_years(-1));
+        let next_year = on_move_highlight(|date| date.add_years(1));
+
+        let state_for_start = state.clone();
+        let state_for_end = state.clone();
+        let state_for_confirm = state.clone();
+        let state_for_dismiss = state.clone();
+
+        // Clicks keep focus where it was, then hand it back to the input, as
+        // buttons blur whatever else is focused.
+        let nav_button = |id: &str, months: i32, enabled: bool| {
+            let state = state.clone();
+            let rotation = if months < 0 { 90. } else { -90. };
+
+            Button::new(self.id.with_suffix(id))
+                .variant(ButtonVariant::TertiaryGhost)
+                .icon(TesseraeIconKind::ArrowDown)
+                .icon_size(px(11.))
+                .icon_rotate(radians(rotation * std::f32::consts::PI / 180.))
+                .p(padding)
+                .rounded(corner_radius - padding)
+                .disabled(!enabled)
+                .on_any_mouse_down(|_event, window, _cx| {
+                    window.prevent_default();
+                })
+                .on_click(move |_event, window, cx| {
+                    state.shift_visible_month(cx, months);
+                    state.input.read(cx).focus_handle.clone().focus(window, cx);
+                })
+        };
+
+        let nav_group = |id: &str, label: String, step: i32| {
+            let has_before = state.has_month_before(visible_month.add_months(1 - step));
+            let has_after = state.has_month_after(visible_month.add_months(step - 1));
+
+            div()
+                .flex()
+                .items_center()
+                .gap(px(2.))
+                .child(nav_button(&format!("{id}:previous"), -step, has_before))
+                .child(
+                    div()
+                        .text_color(primary_text_color)
+                        .font_weight(FontWeight::MEDIUM)
+                        .child(label),
+                )
+                .child(nav_button(&format!("{id}:next"), step, has_after))
+        };
+
+        // We only want the click event if the calendar
+        // is transitioning towards the visible state.
+        if menu_visible_delta != 0. && state.menu_visible_transition.read_goal(cx) == &true.into() {
+            let state = state.clone();
+
+            window.on_root_mouse_down(cx, move |_event, window, cx| {
+                if !state.any_picker_focused(window, cx) {
+                    state.hide_menu(cx);
+                }
+            });
+        }
+
+        div()
+            .id(self.id.clone())
+            .key_context("DatePickerCalendar")
+            .track_focus(&focus_handle)
+            .on_action(move |_: &PreviousDay, window, cx| previous_day(window, cx))
+            .on_action(move |_: &NextDay, window, cx| next_day(window, cx))
+            .on_action(move |_: &PreviousWeek, window, cx| previous_week(window, cx))
+            .on_action(move |_: &NextWeek, window, cx| next_week(window, cx))
+            .on_action(move |_: &PreviousMonth, window, cx| previous_month(window, cx))
+            .on_action(move |_: &NextMonth, window, cx| next_month(window, cx))
+            .on_action(move |_: &PreviousYear, window, cx| previous_year(window, cx))
+            .on_action(move |_: &NextYear, window, cx| next_year(window, cx))
+            .on_action(move |_: &StartOfWeek, _window, cx| {
+                state_for_start.move_highlight_to_start_of_week(cx);
+            })
+            .on_action(move |_: &EndOfWeek, _window, cx| {
+                state_for_end.move_highlight_to_end_of_week(cx);
+            })
+            .on_action(move |_: &Confirm, window, cx| {
+                let state = &state_for_confirm;
+
+                if state.confirm_highlight(cx).is_ok() && state.range_anchor(cx).is_none() {
+                    state.dismiss_menu(cx);
+                    state.input.read(cx).focus_handle.clone().focus(window, cx);
+                }
+            })
+            .on_action(move |_: &Dismiss, window, cx| {
+                state_for_dismiss.dismiss_menu(cx);
+                state_for_dismiss
+                    .input
+                    .read(cx)
+                    .focus_handle
+                    .clone()
+                    .focus(window, cx);
+            })
+            .opacity(menu_visible_delta)
+            .flex()
+            .flex_col()
+            .gap(padding)
+            .p(padding)
+            .child(
+                squircle()
+                    .absolute_expand()
+                    .rounded(corner_radius)
+                    .bg(background_color)
+                    .border_color(border_color)
+                    .border(px(1.))
+                    .border_inside(),
+            )
+            .child(
+                div()
+                    .w_full()
+                    .flex()
+                    .items_center()
+                    .justify_between()
+                    .gap(padding)
+                    .child(nav_group(
+                        "month",
+                        month_name(visible_month.month()).to_string(),
+                        1,
+                    ))
+                    .child(nav_group("year", visible_month.year().to_string(), 12)),
+            )
+            .child(div().flex().children((0..7).map(|column| {
+                div()
+                    .w(cell_size)
+                    .flex()
+                    .justify_center()
+                    .text_size(caption_size)
+                    .text_color(secondary_text_color)
+                    .child(first_weekday.add_days(column).short_name())
+            })))
+            .child(
+                div().w(cell_size * 7.).flex().flex_wrap().children(
+                    month_grid(visible_month, first_weekday).map(|date| days.render(date)),
+                ),
+            )
+            // The deferred content is drawn after any enclosing surface, so the
+            // layer resolved here is provided to it again.
+            .map(|this| self.apply_deferred(layer_scope(layer, this)))
+    }
+}
+
+/// Renders the days of a calendar's month grid.
+struct CalendarDays {
+    id: ElementId,
+    state: Arc<DatePickerState>,
+    /// The selected range, or the range being picked.
+    range: Option<DateRange>,
+    visible_month: CalendarDate,
+    /// The date highlighted with the keyboard, if it should show.
+    highlighted_date: Option<CalendarDate>,
+    hovered_date: Entity<Option<CalendarDate>>,
+    cell_size: Pixels,
+    corner_radius: Pixels,
+    today_color: Rgba,
+}
+
+impl CalendarDays {
+    fn render(&self, date: CalendarDate) -> impl IntoElement {
+        let is_endpoint = self
+            .range
+            .is_some_and(|range| range.start() == date || range.end() == date);
+        let is_in_range = !is_endpoint && self.range.is_some_and(|range| range.contains(date));
+        let is_outside_month = !date.is_same_month(self.visible_month);
+        let is_disabled = !self.state.is_date_selectable(date);
+        let is_today = date == self.state.today();
+
+        let variant = if is_endpoint {
+            ToggleVariant::Primary
+        } else if is_in_range {
+            ToggleVariant::Secondary
+        } else {
+            ToggleVariant::Tertiary
+        };
+
+        let state = self.state.clone();
+        let hovered_date = self.hovered_date.clone();
+
+        div()
+            .relative()
+            .size(self.cell_size)
+            .when(is_outside_month && !is_in_range, |this| this.opacity(0.5))
+            .child(
+                Toggle::new(self.id.with_suffix("day").with_suffix(date.to_string()))
+                    .text(date.day().to_string())
+                    .checked(is_endpoint || is_in_range)
+                    .variant(variant)
+                    .force_hover(self.highlighted_date == Some(date))
+                    .disabled(is_disabled)
+                    .w_full()
+                    .min_h_full()
+                    .p(px(0.))
+                    .justify_center()
+                    .rounded(self.corner_radius)
+                    .on_any_mouse_down(|_event, window, _cx| {
+                        window.prevent_default();
+                    })
+                    .on_hover(move |is_hovered, _window, cx| {
+                        hovered_date.update(cx, |this, cx| {
+                            if *is_hovered {
+                                *this = Some(date);
+                            } else if *this == Some(date) {
+                                *this = None;
+                            }
+                            cx.notify();
+                        });
+                    })
+                    .on_click(move |_event, window, cx| {
+                        // A finished pick closes the calendar, a started range keeps it open.
+                        if state.select_date(cx, date).is_ok() && state.range_anchor(cx).is_none() {
+                            state.dismiss_menu(cx);
+                        }
+
+                        state.input.read(cx).focus_handle.clone().focus(window, cx);
+                    }),
+            )
+            .when(is_today && !is_endpoint, |this| {
+                this.child(
+                    squircle()
+                        .absolute_expand()
+                        .rounded(self.corner_radius)
+                        .border(px(1.))
+                        .border_inside()
+                        .border_color(self.today_color),
+                )
+            })
+    }
+}
@@ -0,0 +1,441 @@
+use std::fmt;
+
+/// How many days a month's calendar grid shows: six full weeks, enough for any month.
+pub(crate) const GRID_DAYS: usize = 42;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Monday,
+    Weekday::Tuesday,
+    Weekday::Wednesday,
+    Weekday::Thursday,
+    Weekday::Friday,
+    Weekday::Saturday,
+    Weekday::Sunday,
+];
+
+/// A day of the week.
+#[allow(missing_docs, reason = "variants are named after the days")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    #[default]
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Returns the two letter abbreviation shown above calendar columns, such as "Mo".
+    pub fn short_name(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mo",
+            Weekday::Tuesday => "Tu",
+            Weekday::Wednesday => "We",
+            Weekday::Thursday => "Th",
+            Weekday::Friday => "Fr",
+            Weekday::Saturday => "Sa",
+            Weekday::Sunday => "Su",
+        }
+    }
+
+    /// Returns the weekday `days` days after this one, or before for negative `days`.
+    pub fn add_days(self, days: i64) -> Self {
+        WEEKDAYS[(self as i64 + days).rem_euclid(7) as usize]
+    }
+
+    /// Returns how many days after `start` this weekday comes, from 0 to 6.
+    pub fn days_since(self, start: Weekday) -> u8 {
+        (self as i64 - start as i64).rem_euclid(7) as u8
+    }
+}
+
+/// Returns true if `year` is a leap year in the Gregorian calendar.
+pub fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Returns how many days the given month has, with months numbered from 1 to 12.
+pub fn days_in_month(year: i32, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Returns the English name of a month, with months numbered from 1 to 12.
+pub fn month_name(month: u8) -> &'static str {
+    MONTH_NAMES[month.clamp(1, 12) as usize - 1]
+}
+
+/// A date in the Gregorian calendar, without a time of day or time zone.
+///
+/// Dates are ordered chronologically.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+impl CalendarDate {
+    /// Creates a date, returning `None` if the month or day doesn't exist.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+
+        Some(Self { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Returns the day of the week.
+    pub fn weekday(self) -> Weekday {
+        // 1970-01-01 was a Thursday.
+        Weekday::Thursday.add_days(self.days_since_epoch())
+    }
+
+    /// Returns the date `days` days later, or earlier for negative `days`.
+    pub fn add_days(self, days: i64) -> Self {
+        Self::from_days_since_epoch(self.days_since_epoch() + days)
+    }
+
+    /// Returns the date `months` months later, or earlier for negative `months`.
+    ///
+    /// The day is clamped to the length of the new month, so a month after
+    /// January 31st is the last day of February.
+    pub fn add_months(self, months: i32) -> Self {
+        let index = self.year as i64 * 12 + (self.month as i64 - 1) + months as i64;
+        let year = index.div_euclid(12) as i32;
+        let month = (index.rem_euclid(12) + 1) as u8;
+
+        Self {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the date `years` years later, or earlier for negative `years`.
+    ///
+    /// February 29th becomes February 28th in years that aren't leap years.
+    pub fn add_years(self, years: i32) -> Self {
+        self.add_months(years.saturating_mul(12))
+    }
+
+    /// Returns the first day of this date's month.
+    pub fn first_of_month(self) -> Self {
+        Self { day: 1, ..self }
+    }
+
+    /// Returns the last day of this date's month.
+    pub fn last_of_month(self) -> Self {
+        Self {
+            day: days_in_month(self.year, self.month),
+            ..self
+        }
+    }
+
+    /// Returns the first day of the week containing this date.
+    pub fn start_of_week(self, first_weekday: Weekday) -> Self {
+        self.add_days(-(self.weekday().days_since(first_weekday) as i64))
+    }
+
+    /// Returns the last day of the week containing this date.
+    pub fn end_of_week(self, first_weekday: Weekday) -> Self {
+        self.start_of_week(first_weekday).add_days(6)
+    }
+
+    /// Returns how many days after this date `other` is, negative if it's earlier.
+    pub fn days_until(self, other: CalendarDate) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// Returns true if both dates are in the same month of the same year.
+    pub fn is_same_month(self, other: CalendarDate) -> bool {
+        self.year == other.year && self.month == other.month
+    }
+
+    /// Parses a date written as `YYYY-MM-DD`, also accepting `/` or `.` as separators.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.trim().split(['-', '/', '.']);
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+
+        if parts.next().is_some()
+            || !(1..=4).contains(&year.len())
+            || !(1..=2).contains(&month.len())
+            || !(1..=2).contains(&day.len())
+            || ![year, month, day]
+                .iter()
+                .all(|part| part.bytes().all(|byte| byte.is_ascii_digit()))
+        {
+            return None;
+        }
+
+        Self::new(year.parse().ok()?, month.parse().ok()?, day.parse().ok()?)
+    }
+
+    /// Days since 1970-01-01, using the algorithm from
+    /// <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>.
+    fn days_since_epoch(self) -> i64 {
+        let month = self.month as i64;
+        let day = self.day as i64;
+        let year = self.year as i64 - (month <= 2) as i64;
+
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// The inverse of [`Self::days_since_epoch`].
+    fn from_days_since_epoch(days: i64) -> Self {
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = if shifted_month < 10 {
+            shifted_month + 3
+        } else {
+            shifted_month - 9
+        };
+
+        Self {
+            year: (year_of_era + era * 400 + (month <= 2) as i64) as i32,
+            month: month as u8,
+            day: day as u8,
+        }
+    }
+}
+
+impl fmt::Display for CalendarDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// An inclusive range of dates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    start: CalendarDate,
+    end: CalendarDate,
+}
+
+impl DateRange {
+    /// Creates the range between two dates, given in either order.
+    pub fn new(a: CalendarDate, b: CalendarDate) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    /// Creates a range covering a single day.
+    pub fn single(date: CalendarDate) -> Self {
+        Self {
+            start: date,
+            end: date,
+        }
+    }
+
+    /// Returns the first day of the range.
+    pub fn start(self) -> CalendarDate {
+        self.start
+    }
+
+    /// Returns the last day of the range.
+    pub fn end(self) -> CalendarDate {
+        self.end
+    }
+
+    /// Returns true if the range starts and ends on the same day.
+    pub fn is_single_day(self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns true if `date` is within the range, including its first and last day.
+    pub fn contains(self, date: CalendarDate) -> bool {
+        (self.start..=self.end).contains(&date)
+    }
+
+    /// Returns how many days the range covers, counting its first and last day.
+    pub fn days(self) -> i64 {
+        self.start.days_until(self.end) + 1
+    }
+
+    /// Parses a range written as two dates separated by ` - `, ` – ` or ` to `,
+    /// or a single date for a range of one day.
+    pub fn parse(text: &str) -> Option<Self> {
+        for separator in ["–", "—", " - ", " to "] {
+            if let Some((start, end)) = text.split_once(separator) {
+                return Some(Self::new(
+                    CalendarDate::parse(start)?,
+                    CalendarDate::parse(end)?,
+                ));
+            }
+        }
+
+        CalendarDate::parse(text).map(Self::single)
+    }
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_single_day() {
+            write!(f, "{}", self.start)
+        } else {
+            write!(f, "{} – {}", self.start, self.end)
+        }
+    }
+}
+
+/// Returns the dates shown in a month's calendar grid, starting on the week
+/// that contains the first of the month.
+pub(crate) fn month_grid(
+    month: CalendarDate,
+    first_weekday: Weekday,
+) -> impl Iterator<Item = CalendarDate> {
+    let start = month.first_of_month().start_of_week(first_weekday);
+    (0..GRID_DAYS as i64).map(move |offset| start.add_days(offset))
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u8, day: u8) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_days_that_dont_exist() {
+        assert!(CalendarDate::new(2024, 2, 29).is_some());
+        assert!(CalendarDate::new(2023, 2, 29).is_none());
+        assert!(CalendarDate::new(2026, 4, 31).is_none());
+        assert!(CalendarDate::new(2026, 13, 1).is_none());
+        assert!(CalendarDate::new(2026, 1, 0).is_none());
+    }
+
+    #[test]
+    fn test_leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2026));
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(2000, 2, 29).weekday(), Weekday::Tuesday);
+        assert_eq!(date(2026, 10, 18).weekday(), Weekday::Sunday);
+        assert_eq!(date(1969, 12, 31).weekday(), Weekday::Wednesday);
+    }
+
+    #[test]
+    fn test_add_days_crosses_months_and_years() {
+        assert_eq!(date(2026, 12, 31).add_days(1), date(2027, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2026, 10, 18).add_days(-365), date(2025, 10, 18));
+        assert_eq!(date(2026, 10, 18).days_until(date(2026, 11, 1)), 14);
+    }
+
+    #[test]
+    fn test_add_months_clamps_the_day() {
+        assert_eq!(date(2026, 1, 31).add_months(1), date(2026, 2, 28));
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2026, 1, 15).add_months(-1), date(2025, 12, 15));
+        assert_eq!(date(2026, 10, 18).add_months(15), date(2028, 1, 18));
+        assert_eq!(date(2024, 2, 29).add_years(1), date(2025, 2, 28));
+    }
+
+    #[test]
+    fn test_weeks() {
+        let sunday = date(2026, 10, 18);
+        assert_eq!(sunday.start_of_week(Weekday::Monday), date(2026, 10, 12));
+        assert_eq!(sunday.end_of_week(Weekday::Monday), sunday);
+        assert_eq!(sunday.start_of_week(Weekday::Sunday), sunday);
+        assert_eq!(Weekday::Sunday.days_since(Weekday::Monday), 6);
+    }
+
+    #[test]
+    fn test_month_grid_starts_on_first_weekday() {
+        let grid: Vec<_> = month_grid(date(2026, 10, 18), Weekday::Monday).collect();
+
+        assert_eq!(grid.len(), GRID_DAYS);
+        assert_eq!(grid[0], date(2026, 9, 28));
+        assert_eq!(grid[3], date(2026, 10, 1));
+        assert_eq!(grid[GRID_DAYS - 1], date(2026, 11, 8));
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(CalendarDate::parse("2026-10-18"), Some(date(2026, 10, 18)));
+        assert_eq!(CalendarDate::parse(" 2026/1/5 "), Some(date(2026, 1, 5)));
+        assert_eq!(CalendarDate::parse("2026.10.18"), Some(date(2026, 10, 18)));
+        assert_eq!(CalendarDate::parse("2026-02-30"), None);
+        assert_eq!(CalendarDate::parse("2026-10"), None);
+        assert_eq!(CalendarDate::parse("2026-10-18-1"), None);
+        assert_eq!(CalendarDate::parse("20x6-10-18"), None);
+        assert_eq!(date(2026, 1, 5).to_string(), "2026-01-05");
+    }
+
+    #[test]
+    fn test_range() {
+        let range = DateRange::new(date(2026, 10, 18), date(2026, 10, 1));
+
+        assert_eq!(range.start(), date(2026, 10, 1));
+        assert_eq!(range.end(), date(2026, 10, 18));
+        assert_eq!(range.days(), 18);
+        assert!(range.contains(date(2026, 10, 1)) && range.contains(date(2026, 10, 18)));
+        assert!(!range.contains(date(2026, 10, 19)));
+        assert_eq!(range.to_string(), "2026-10-01 – 2026-10-18");
+        assert_eq!(DateRange::parse(&range.to_string()), Some(range));
+        assert_eq!(DateRange::parse("2026-10-01 to 2026-10-18"), Some(range));
+        assert_eq!(DateRange::parse("2026-10-18 - 2026-10-01"), Some(range));
+        assert_eq!(
+            DateRange::parse("2026-10-18"),
+            Some(DateRange::single(date(2026, 10, 18)))
+        );
+        assert_eq!(DateRange::parse("2026-10-01 to soon"), None);
+    }
+}
@@ -0,0 +1,606 @@
+use std::sync::Arc;
+
+use gpui::{
+    App, Bounds, Edges, ElementId, InteractiveElement, IntoElement, Length, MouseButton,
+    ParentElement, Pixels, RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window,
+    canvas, div, prelude::FluentBuilder, px, radians, relative,
+};
+
+use crate::{
+    ElementIdExt, PositionalParentElement, TesseraeIconKind,
+    components::{
+        Icon, Input,
+        select::{SelectMenuPlacement, measure_height},
+    },
+    conitional_transition_update,
+    extensions::{
+        deferrable::{Deferrable, DeferredConfig},
+        mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+    },
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::disabled_transition,
+};
+
+mod date;
+pub use date::*;
+
+mod state;
+pub use state::*;
+
+mod calendar;
+pub use calendar::*;
+
+struct DatePickerStyles {
+    margin: Edges<Option<Length>>,
+    width: Length,
+    min_width: Option<Length>,
+    max_width: Option<Length>,
+    menu_placement: SelectMenuPlacement,
+    placeholder: Option<SharedString>,
+}
+
+impl Default for DatePickerStyles {
+    fn default() -> Self {
+        Self {
+            margin: Edges::default(),
+            width: Length::Auto,
+            min_width: None,
+            max_width: None,
+            menu_placement: SelectMenuPlacement::default(),
+            placeholder: None,
+        }
+    }
+}
+
+/// A date input with a calendar popover for picking a date or a range of dates.
+///
+/// Dates can be typed as `YYYY-MM-DD`, with ranges written as two dates
+/// separated by a dash.
+#[derive(IntoElement)]
+pub struct DatePicker {
+    id: ElementId,
+    disabled: bool,
+    layer: Option<ThemeLayerKind>,
+    state: Arc<DatePickerState>,
+    mouse_behavior: MouseBehavior,
+    deferred_config: DeferredConfig,
+    style: DatePickerStyles,
+}
+
+impl DatePicker {
+    /// Creates a new date picker with the given ID and shared state.
+    pub fn new(id: impl Into<ElementId>, state: impl Into<Arc<DatePickerState>>) -> Self {
+        Self {
+            id: id.into(),
+            disabled: false,
+            layer: None,
+            state: state.into(),
+            mouse_behavior: MouseBehavior::default(),
+            deferred_config: DeferredConfig::default(),
+            style: DatePickerStyles::default(),
+        }
+    }
+
+    /// Sets the layer of the date picker.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets where the calendar opens.
+    /// Defaults to below, flipping above when there's more room there.
+    pub fn menu_placement(mut self, placement: SelectMenuPlacement) -> Self {
+        self.style.menu_placement = placement;
+        self
+    }
+
+    /// Sets a custom placeholder text shown when no date is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.style.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets a fixed width.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.style.width = width.into();
+        self
+    }
+
+    /// Sets width to auto, sizing based on content.
+    pub fn w_auto(mut self) -> Self {
+        self.style.width = Length::Auto;
+        self
+    }
+
+    /// Sets width to fill the parent container.
+    pub fn w_full(mut self) -> Self {
+        self.style.width = relative(100.).into();
+        self
+    }
+
+    /// Sets the minimum width of the element. [Docs](https://tailwindcss.com/docs/min-width)
+    pub fn min_w(mut self, width: impl Into<Length>) -> Self {
+        self.style.min_width = Some(width.into());
+        self
+    }
+
+    /// Sets the maximum width of the element. [Docs](https://tailwindcss.com/docs/max-width)
+    pub fn max_w(mut self, width: impl Into<Length>) -> Self {
+        self.style.max_width = Some(width.into());
+        self
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.style.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the disabled state, preventing interaction.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl MouseBehaviorExt for DatePicker {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl Deferrable for DatePicker {
+    const DEFAULT_PRIORITY: usize = 1;
+
+    fn deferred_config_mut(&mut self) -> &mut DeferredConfig {
+        &mut self.deferred_config
+    }
+
+    fn deferred_config(&self) -> &DeferredConfig {
+        &self.deferred_config
+    }
+}
+
+impl RenderOnce for DatePicker {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let state = self.state.clone();
+
+        let focus_handle = window
+            .use_keyed_state(
+                self.id.with_suffix("state:focus_handle"),
+                cx,
+                |_window, cx| cx.focus_handle(),
+            )
+            .read(cx)
+            .clone();
+        let calendar_focus_handle = window
+            .use_keyed_state(
+                self.id.with_suffix("state:calendar_focus_handle"),
+                cx,
+                |_window, cx| cx.focus_handle().tab_stop(true),
+            )
+            .read(cx)
+            .clone();
+
+        // Register this DatePicker's focus handle with the shared state
+        state.register_focus_handle(cx, &focus_handle);
+
+        // Contains both the input and the calendar.
+        let is_focus = focus_handle.contains_focused(window, cx);
+
+        let is_disabled = self.disabled;
+        let disabled_transition = disabled_transition(self.id.clone(), window, cx, is_disabled);
+
+        if is_disabled && is_focus {
+            window.blur();
+        }
+
+        // Typed dates are picked once focus leaves the picker, and a calendar
+        // dismissed with Escape stays closed until then.
+        let was_focus = window.use_keyed_state(
+            self.id.with_suffix("state:was_focus"),
+            cx,
+            |_window, _cx| false,
+        );
+        if *was_focus.read(cx) != is_focus {
+            was_focus.update(cx, |this, _cx| *this = is_focus);
+
+            if !is_focus {
+                let _ = state.commit_input(cx);
+                state.hide_menu(cx);
+                state.menu.reset_dismissed(cx);
+            }
+        }
+
+        // Show the date being typed in the calendar.
+        let input_text = state.input.read(cx).value();
+        let last_input_text = window.use_keyed_state(
+            self.id.with_suffix("state:last_input_text"),
+            cx,
+            |_window, _cx| SharedString::default(),
+        );
+        if *last_input_text.read(cx) != input_text {
+            last_input_text.update(cx, |this, _cx| *this = input_text);
+
+            if is_focus {
+                state.preview_input(cx);
+            }
+        }
+
+        let menu_visible_transition = conitional_transition_update!(
+            cx,
+            state.menu_visible_transition.clone(),
+            {
+                is_focus && !state.is_menu_dismissed(cx) => true,
+                _ => false
+            }
+        );
+
+        let menu_visible_delta = menu_visible_transition.evaluate(window, cx).value();
+
+        // Where the picker is in the window and how tall its calendar is, to open
+        // the calendar where it fits.
+        let trigger_bounds = window.use_keyed_state(
+            self.id.with_suffix("state:trigger_bounds"),
+            cx,
+            |_window, _cx| None::<Bounds<Pixels>>,
+        );
+        let menu_height = window.use_keyed_state(
+            self.id.with_suffix("state:menu_height"),
+            cx,
+            |_window, _cx| px(0.),
+        );
+
+        let menu_gap = cx.get_theme().layout.padding.md;
+        let menu_placement = match *trigger_bounds.read(cx) {
+            Some(bounds) => {
+                self.style
+                    .menu_placement
+                    .resolve(
+                        bounds,
+                        window.viewport_size().height,
+                        *menu_height.read(cx),
+                        menu_gap,
+                    )
+                    .0
+            }
+            None => SelectMenuPlacement::Below,
+        };
+
+        let placeholder = self.style.placeholder.clone().unwrap_or_else(|| {
+            if state.is_range() {
+                "YYYY-MM-DD – YYYY-MM-DD".into()
+            } else {
+                "YYYY-MM-DD".into()
+            }
+        });
+
+        let state_on_submit = state.clone();
+
+        div()
+            .id(self.id.clone())
+            .w(self.style.width)
+            .h_auto()
+            .when_some(self.style.margin.top, |this, v| this.mt(v))
+            .when_some(self.style.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.style.margin.left, |this, v| this.ml(v))
+            .when_some(self.style.margin.right, |this, v| this.mr(v))
+            .when_some(self.style.min_width, |this, v| this.min_w(v))
+            .when_some(self.style.max_width, |this, v| this.max_w(v))
+            .flex()
+            .flex_col()
+            .opacity(*disabled_transition.evaluate(window, cx))
+            .child(
+                canvas(
+                    move |bounds, _window, cx| {
+                        trigger_bounds.update(cx, |this, cx| {
+                            if *this != Some(bounds) {
+                                *this = Some(bounds);
+                                cx.notify();
+                            }
+                        });
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(
+                Input::new(self.id.with_suffix("input"), state.input.clone())
+                    .w_full()
+                    .layer(layer)
+                    .disabled(is_disabled)
+                    .placeholder(placeholder)
+                    .on_submit(move |_window, cx| {
+                        if state_on_submit.commit_input(cx).is_ok()
+                            && state_on_submit.range_anchor(cx).is_none()
+                        {
+                            state_on_submit.dismiss_menu(cx);
+                        }
+                    })
+                    .child_right(
+                        Icon::new(TesseraeIconKind::ArrowDown)
+                            .size(px(11.))
+                            .color(secondary_text_color)
+                            .map(|this| {
+                                let rotation = radians(
+                                    ((1. - menu_visible_delta) * 180.) * std::f32::consts::PI
+                                        / 180.0,
+                                );
+
+                                this.rotate(rotation)
+                            }),
+                    ),
+            )
+            .when(menu_visible_delta != 0., |this| {
+                let deferred_config = self.deferred_config;
+
+                this.child(
+                    div()
+                        .absolute()
+                        .left_0()
+                        .map(|this| match menu_placement {
+                            SelectMenuPlacement::Above => this.bottom(relative(1.)).pb(menu_gap),
+                            _ => this.top_full().pt(menu_gap),
+                        })
+                        .child(measure_height(menu_height))
+                        .child(
+                            DatePickerCalendar::new(self.id.with_suffix("calendar"), state.clone())
+                                .layer(layer)
+                                .focus_handle(calendar_focus_handle.clone())
+                                .map(|mut this| {
+                                    *this.deferred_config_mut() = deferred_config;
+                                    this
+                                }),
+                        ),
+                )
+            })
+            .when(!is_disabled, |this| {
+                let behavior = self.mouse_behavior;
+
+                let state_on_open = state.clone();
+                let state_on_dismiss = state.clone();
+                let state_on_mouse_down = state.clone();
+
+                this.key_context("DatePicker")
+                    .on_action(move |_: &Open, window, cx| {
+                        // The first press opens a dismissed calendar, the next one
+                        // moves focus into it for keyboard navigation.
+                        if !state_on_open
+                            .menu
+                            .is_shown(cx, &state_on_open.menu_visible_transition)
+                        {
+                            state_on_open.show_menu(cx);
+                        } else {
+                            calendar_focus_handle.focus(window, cx);
+                        }
+                    })
+                    .on_action(move |_: &Dismiss, _window, cx| {
+                        state_on_dismiss.dismiss_menu(cx);
+                    })
+                    .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                        behavior.apply(window, cx);
+                        state_on_mouse_down.show_menu(cx);
+
+                        if !is_focus {
+                            state_on_mouse_down
+                                .input
+                                .read(cx)
+                                .focus_handle
+                                .clone()
+                                .focus(window, cx);
+                        }
+                    })
+                    .track_focus(&focus_handle)
+            })
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use std::{cell::RefCell, rc::Rc};
+
+    use gpui::TestAppContext;
+
+    fn date(year: i32, month: u8, day: u8) -> CalendarDate {
+        CalendarDate::new(year, month, day).unwrap()
+    }
+
+    fn set_input_text(state: &DatePickerState, cx: &mut App, text: &str) {
+        state.input.update(cx, |this, _cx| {
+            this.value = Some(SharedString::from(text.to_string()));
+        });
+    }
+
+    #[gpui::test]
+    fn test_date_picker_creation(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let state = DatePickerState::from_cx(cx, date(2024, 3, 1));
+            let picker = DatePicker::new("test-date-picker", state);
+            assert!(!picker.disabled, "DatePicker should start enabled");
+        });
+    }
+
+    #[gpui::test]
+    fn test_select_date_emits_event(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| DatePickerState::from_cx(cx, date(2024, 3, 1)));
+        let events = Rc::new(RefCell::new(Vec::new()));
+
+        cx.update(|cx| {
+            let events = events.clone();
+            cx.subscribe(
+                state.events(),
+                move |_events, event: &DateSelectionChanged, _cx| {
+                    events.borrow_mut().push(*event);
+                },
+            )
+            .detach();
+        });
+
+        cx.update(|cx| {
+            state.select_date(cx, date(2024, 3, 15)).unwrap();
+            // Picking the same date again doesn't emit another event.
+            state.select_date(cx, date(2024, 3, 15)).unwrap();
+
+            assert_eq!(state.selected_date(cx), Some(date(2024, 3, 15)));
+            assert_eq!(state.input.read(cx).value(), "2024-03-15");
+            assert_eq!(state.visible_month(cx), date(2024, 3, 1));
+        });
+
+        assert_eq!(
+            *events.borrow(),
+            vec![DateSelectionChanged {
+                old: None,
+                new: Some(DateRange::single(date(2024, 3, 15))),
+            }]
+        );
+    }
+
+    #[gpui::test]
+    fn test_select_range_in_two_steps(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| {
+            let mut state = DatePickerState::from_cx(cx, date(2024, 3, 1));
+            state.set_selection_mode(DateSelectionMode::Range);
+            state
+        });
+
+        cx.update(|cx| {
+            state.select_date(cx, date(2024, 3, 20)).unwrap();
+            assert_eq!(state.range_anchor(cx), Some(date(2024, 3, 20)));
+            assert_eq!(state.selected_range(cx), None);
+            assert_eq!(state.input.read(cx).value(), "2024-03-20 – ");
+
+            // The second date can come before the first one.
+            state.select_date(cx, date(2024, 3, 10)).unwrap();
+            assert_eq!(state.range_anchor(cx), None);
+            assert_eq!(
+                state.selected_range(cx),
+                Some(DateRange::new(date(2024, 3, 10), date(2024, 3, 20)))
+            );
+            assert_eq!(state.input.read(cx).value(), "2024-03-10 – 2024-03-20");
+        });
+    }
+
+    #[gpui::test]
+    fn test_select_date_outside_limits(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| {
+            let mut state = DatePickerState::from_cx(cx, date(2024, 3, 1));
+            state.set_min_date(Some(date(2024, 1, 1)));
+            state.set_max_date(Some(date(2024, 12, 31)));
+            state.set_disabled_dates(|date| date.weekday() == Weekday::Sunday);
+            state
+        });
+
+        cx.update(|cx| {
+            assert_eq!(
+                state.select_date(cx, date(2023, 12, 31)),
+                Err(DateSelectError::BeforeMin)
+            );
+            assert_eq!(
+                state.select_date(cx, date(2025, 1, 1)),
+                Err(DateSelectError::AfterMax)
+            );
+            assert_eq!(
+                state.select_date(cx, date(2024, 3, 17)),
+                Err(DateSelectError::Disabled)
+            );
+            assert_eq!(state.selected_range(cx), None);
+
+            assert!(state.select_date(cx, date(2024, 3, 18)).is_ok());
+        });
+    }
+
+    #[gpui::test]
+    fn test_commit_input(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| DatePickerState::from_cx(cx, date(2024, 3, 1)));
+
+        cx.update(|cx| {
+            set_input_text(&state, cx, "2024/02/29");
+            assert!(state.commit_input(cx).is_ok());
+            assert_eq!(state.selected_date(cx), Some(date(2024, 2, 29)));
+            assert_eq!(state.input.read(cx).value(), "2024-02-29");
+
+            // Text that isn't a date is replaced by the selection again.
+            set_input_text(&state, cx, "2023-02-29");
+            assert_eq!(state.commit_input(cx), Err(DateSelectError::Invalid));
+            assert_eq!(state.selected_date(cx), Some(date(2024, 2, 29)));
+            assert_eq!(state.input.read(cx).value(), "2024-02-29");
+
+            set_input_text(&state, cx, "");
+            assert!(state.commit_input(cx).is_ok());
+            assert_eq!(state.selected_date(cx), None);
+        });
+    }
+
+    #[gpui::test]
+    fn test_move_highlight(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| {
+            let mut state = DatePickerState::from_cx(cx, date(2024, 3, 1));
+            state.set_min_date(Some(date(2024, 2, 27)));
+            state
+        });
+
+        cx.update(|cx| {
+            state.move_highlight(cx, |date| date.add_days(-1));
+            assert_eq!(state.highlighted_date(cx), date(2024, 2, 29));
+            assert_eq!(state.visible_month(cx), date(2024, 2, 1));
+
+            // The highlight stops at the min date.
+            state.move_highlight(cx, |date| date.add_days(-7));
+            assert_eq!(state.highlighted_date(cx), date(2024, 2, 27));
+
+            state.confirm_highlight(cx).unwrap();
+            assert_eq!(state.selected_date(cx), Some(date(2024, 2, 27)));
+        });
+    }
+
+    #[gpui::test]
+    fn test_hide_menu_drops_unfinished_range(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| {
+            let mut state = DatePickerState::from_cx(cx, date(2024, 3, 1));
+            state.set_selection_mode(DateSelectionMode::Range);
+            state
+        });
+
+        cx.update(|cx| {
+            state
+                .select_range(cx, DateRange::new(date(2024, 3, 1), date(2024, 3, 5)))
+                .unwrap();
+            state.select_date(cx, date(2024, 4, 1)).unwrap();
+            assert_eq!(state.range_anchor(cx), Some(date(2024, 4, 1)));
+
+            state.hide_menu(cx);
+            assert_eq!(state.range_anchor(cx), None);
+            assert_eq!(state.input.read(cx).value(), "2024-03-01 – 2024-03-05");
+        });
+    }
+}
@@ -0,0 +1,588 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::rc::Rc;
+
+use gpui::{
+    App, AppContext, ElementId, Entity, EventEmitter, FocusHandle, KeyBinding, SharedString,
+    Window, actions,
+};
+use gpui_transitions::{BoolLerp, Transition};
+use thiserror::Error;
+
+use crate::{
+    ElementIdExt,
+    components::date_picker::{CalendarDate, DateRange, Weekday},
+    primitives::input::InputState,
+    utils::{PopoverState, TypedSelection},
+};
+
+actions!(
+    date_picker,
+    [
+        PreviousDay,
+        NextDay,
+        PreviousWeek,
+        NextWeek,
+        PreviousMonth,
+        NextMonth,
+        PreviousYear,
+        NextYear,
+        StartOfWeek,
+        EndOfWeek,
+        Confirm,
+        Dismiss,
+        Open
+    ]
+);
+
+/// Type alias for the callback deciding which dates can't be picked.
+pub type IsDateDisabledFn = Rc<dyn Fn(CalendarDate) -> bool>;
+
+/// Whether a date picker picks a single date or a range of dates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DateSelectionMode {
+    /// Picking a date replaces the selection and closes the calendar.
+    #[default]
+    Single,
+    /// The first date picked starts a range and the second one ends it.
+    Range,
+}
+
+/// Emitted by [`DatePickerState::events`] when the selected dates change.
+///
+/// A single date is a range starting and ending on the same day.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DateSelectionChanged {
+    /// The dates selected before the change.
+    pub old: Option<DateRange>,
+    /// The dates selected after the change.
+    pub new: Option<DateRange>,
+}
+
+/// Emits [`DateSelectionChanged`] events for a [`DatePickerState`].
+///
+/// Subscribe to it with `cx.subscribe(state.events(), ...)`.
+pub struct DatePickerStateEvents;
+
+impl EventEmitter<DateSelectionChanged> for DatePickerStateEvents {}
+
+/// Shared state for a DatePicker component, managing the selected dates,
+/// the month shown in the calendar and calendar visibility.
+pub struct DatePickerState {
+    /// The selected dates. A single date is a range of one day.
+    pub(crate) selection: Entity<Option<DateRange>>,
+    /// The first date of a range whose last date hasn't been picked yet.
+    pub(crate) range_anchor: Entity<Option<CalendarDate>>,
+    /// The first day of the month shown in the calendar.
+    pub(crate) visible_month: Entity<CalendarDate>,
+    /// The date moved with the arrow keys, picked with enter.
+    pub(crate) highlighted_date: Entity<Option<CalendarDate>>,
+    /// The text field the dates can be typed into.
+    pub(crate) input: Entity<InputState>,
+    pub(crate) events: Entity<DatePickerStateEvents>,
+    pub(crate) selection_mode: DateSelectionMode,
+    pub(crate) min_date: Option<CalendarDate>,
+    pub(crate) max_date: Option<CalendarDate>,
+    pub(crate) is_date_disabled: Option<IsDateDisabledFn>,
+    pub(crate) first_weekday: Weekday,
+    pub(crate) today: CalendarDate,
+    /// Animated transition for calendar visibility.
+    pub menu_visible_transition: Transition<BoolLerp<f32>>,
+    /// Dismissal and focus state of the calendar, shared by all DatePicker
+    /// components using this state.
+    pub(crate) menu: PopoverState,
+}
+
+impl DatePickerState {
+    /// Creates state using window-keyed storage, persisting across renders.
+    ///
+    /// `today` is the current date in the user's time zone. The calendar marks
+    /// it and opens on its month until a date is picked.
+    pub fn from_window(
+        id: impl Into<ElementId>,
+        window: &mut Window,
+        cx: &mut App,
+        today: CalendarDate,
+    ) -> Self {
+        let id = id.into();
+
+        Self {
+            selection: window.use_keyed_state(
+                id.with_suffix("state:selection"),
+                cx,
+                |_window, _cx| None,
+            ),
+            range_anchor: window.use_keyed_state(
+                id.with_suffix("state:range_anchor"),
+                cx,
+                |_window, _cx| None,
+            ),
+            visible_month: window.use_keyed_state(
+                id.with_suffix("state:visible_month"),
+                cx,
+                |_window, _cx| today.first_of_month(),
+            ),
+            highlighted_date: window.use_keyed_state(
+                id.with_suffix("state:highlighted_date"),
+                cx,
+                |_window, _cx| None,
+            ),
+            input: window.use_keyed_state(id.with_suffix("state:input"), cx, |_window, cx| {
+                InputState::new(cx)
+            }),
+            events: window.use_keyed_state(id.with_suffix("state:events"), cx, |_window, _cx| {
+                DatePickerStateEvents
+            }),
+            selection_mode: DateSelectionMode::default(),
+            min_date: None,
+            max_date: None,
+            is_date_disabled: None,
+            first_weekday: Weekday::default(),
+            today,
+            menu_visible_transition: PopoverState::visible_transition_from_window(&id, window, cx),
+            menu: PopoverState::from_window(&id, window, cx),
+        }
+    }
+
+    /// Creates state from an App context, with `today` as the current date in
+    /// the user's time zone.
+    pub fn from_cx(cx: &mut App, today: CalendarDate) -> Self {
+        Self {
+            selection: cx.new(|_cx| None),
+            range_anchor: cx.new(|_cx| None),
+            visible_month: cx.new(|_cx| today.first_of_month()),
+            highlighted_date: cx.new(|_cx| None),
+            input: cx.new(|cx| InputState::new(cx)),
+            events: cx.new(|_cx| DatePickerStateEvents),
+            selection_mode: DateSelectionMode::default(),
+            min_date: None,
+            max_date: None,
+            is_date_disabled: None,
+            first_weekday: Weekday::default(),
+            today,
+            menu_visible_transition: PopoverState::visible_transition_from_cx(cx),
+            menu: PopoverState::from_cx(cx),
+        }
+    }
+
+    /// Sets whether a single date or a range of dates is picked.
+    pub fn set_selection_mode(&mut self, selection_mode: DateSelectionMode) {
+        self.selection_mode = selection_mode;
+    }
+
+    /// Returns true if a range of dates is picked.
+    pub fn is_range(&self) -> bool {
+        self.selection_mode == DateSelectionMode::Range
+    }
+
+    /// Sets the earliest date that can be picked.
+    pub fn set_min_date(&mut self, min_date: Option<CalendarDate>) {
+        self.min_date = min_date;
+    }
+
+    /// Sets the latest date that can be picked.
+    pub fn set_max_date(&mut self, max_date: Option<CalendarDate>) {
+        self.max_date = max_date;
+    }
+
+    /// Sets a callback returning true for dates that can't be picked, such as weekends.
+    pub fn set_disabled_dates(
+        &mut self,
+        is_date_disabled: impl Fn(CalendarDate) -> bool + 'static,
+    ) {
+        self.is_date_disabled = Some(Rc::new(is_date_disabled));
+    }
+
+    /// Sets the day each week of the calendar starts on. Defaults to Monday.
+    pub fn set_first_weekday(&mut self, first_weekday: Weekday) {
+        self.first_weekday = first_weekday;
+    }
+
+    /// Sets the date marked as today in the calendar, such as when the day changes.
+    pub fn set_today(&mut self, today: CalendarDate) {
+        self.today = today;
+    }
+
+    /// Returns the date marked as today in the calendar.
+    pub fn today(&self) -> CalendarDate {
+        self.today
+    }
+
+    /// Returns the entity that emits [`DateSelectionChanged`] events.
+    pub fn events(&self) -> &Entity<DatePickerStateEvents> {
+        &self.events
+    }
+
+    /// Returns the text field the dates can be typed into.
+    pub fn input(&self) -> &Entity<InputState> {
+        &self.input
+    }
+
+    /// Checks whether `date` can be picked.
+    pub fn check_date(&self, date: CalendarDate) -> Result<(), DateSelectError> {
+        if self.min_date.is_some_and(|min_date| date < min_date) {
+            return Err(DateSelectError::BeforeMin);
+        }
+
+        if self.max_date.is_some_and(|max_date| date > max_date) {
+            return Err(DateSelectError::AfterMax);
+        }
+
+        if self
+            .is_date_disabled
+            .as_ref()
+            .is_some_and(|is_date_disabled| is_date_disabled(date))
+        {
+            return Err(DateSelectError::Disabled);
+        }
+
+        Ok(())
+    }
+
+    /// Returns true if `date` can be picked.
+    pub fn is_date_selectable(&self, date: CalendarDate) -> bool {
+        self.check_date(date).is_ok()
+    }
+
+    /// Returns the selected dates. A single date is a range of one day.
+    pub fn selected_range(&self, cx: &App) -> Option<DateRange> {
+        *self.selection.read(cx)
+    }
+
+    /// Returns the selected date, or the first selected date in range mode.
+    pub fn selected_date(&self, cx: &App) -> Option<CalendarDate> {
+        self.selected_range(cx).map(DateRange::start)
+    }
+
+    /// Returns the first date of a range whose last date hasn't been picked yet.
+    pub fn range_anchor(&self, cx: &App) -> Option<CalendarDate> {
+        *self.range_anchor.read(cx)
+    }
+
+    /// Picks a date, returning an error if it can't be picked.
+    ///
+    /// In range mode the first date picked starts a new range, and the
+    /// second one completes it.
+    pub fn select_date(&self, cx: &mut App, date: CalendarDate) -> Result<(), DateSelectError> {
+        self.check_date(date)?;
+
+        let anchor = self.range_anchor(cx);
+        self.set_range_anchor(cx, None);
+
+        match (self.selection_mode, anchor) {
+            (DateSelectionMode::Range, None) => {
+                self.set_range_anchor(cx, Some(date));
+                self.set_highlighted_date(cx, date);
+                self.sync_input_to_selection(cx);
+            }
+            (DateSelectionMode::Range, Some(anchor)) => {
+                self.set_selection(cx, Some(DateRange::new(anchor, date)));
+            }
+            (DateSelectionMode::Single, _) => {
+                self.set_selection(cx, Some(DateRange::single(date)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Selects a range of dates, returning an error if either end can't be picked.
+    ///
+    /// Only the first date of the range is kept in single mode.
+    pub fn select_range(&self, cx: &mut App, range: DateRange) -> Result<(), DateSelectError> {
+        self.check_date(range.start())?;
+
+        let range = match self.selection_mode {
+            DateSelectionMode::Range => {
+                self.check_date(range.end())?;
+                range
+            }
+            DateSelectionMode::Single => DateRange::single(range.start()),
+        };
+
+        self.set_range_anchor(cx, None);
+        self.set_selection(cx, Some(range));
+
+        Ok(())
+    }
+
+    /// Clears the selection and any range being picked.
+    pub fn clear_selection(&self, cx: &mut App) {
+        self.set_range_anchor(cx, None);
+        self.set_selection(cx, None);
+    }
+
+    /// Sets the selection, emitting a [`DateSelectionChanged`] event if it changed,
+    /// and shows it in the input and calendar.
+    fn set_selection(&self, cx: &mut App, new: Option<DateRange>) {
+        let old = self.selected_range(cx);
+
+        if old != new {
+            self.selection.update(cx, |this, cx| {
+                *this = new;
+                cx.notify();
+            });
+
+            self.events.update(cx, |_this, cx| {
+                cx.emit(DateSelectionChanged { old, new });
+            });
+        }
+
+        if let Some(range) = new {
+            self.set_highlighted_date(cx, range.end());
+        }
+
+        self.sync_input_to_selection(cx);
+    }
+
+    fn set_range_anchor(&self, cx: &mut App, anchor: Option<CalendarDate>) {
+        self.range_anchor.update(cx, |this, cx| {
+            if *this != anchor {
+                *this = anchor;
+                cx.notify();
+            }
+        });
+    }
+
+    /// Returns the text the input shows for the current selection.
+    pub fn selection_text(&self, cx: &App) -> SharedString {
+        if let Some(anchor) = self.range_anchor(cx) {
+            return format!("{anchor} – ").into();
+        }
+
+        self.selected_range(cx)
+            .map(|range| range.to_string().into())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the text of the input with the current selection.
+    pub fn sync_input_to_selection(&self, cx: &mut App) {
+        TypedSelection::sync_input_to_selection(self, cx);
+    }
+
+    /// Selects the dates typed into the input, or clears the selection if it's empty.
+    ///
+    /// If the text isn't a date that can be picked, the input goes back to
+    /// showing the selection and the error is returned.
+    pub fn commit_input(&self, cx: &mut App) -> Result<(), DateSelectError> {
+        TypedSelection::commit_input(self, cx)
+    }
+
+    /// Shows and highlights the date being typed into the input, once it's a whole date.
+    pub(crate) fn preview_input(&self, cx: &mut App) {
+        if let Some(Ok(range)) = self.parse_input(cx) {
+            self.set_highlighted_date(cx, range.end());
+        }
+    }
+
+    /// Returns the first day of the month shown in the calendar.
+    pub fn visible_month(&self, cx: &App) -> CalendarDate {
+        *self.visible_month.read(cx)
+    }
+
+    /// Shows the month containing `date` in the calendar.
+    pub fn show_month(&self, cx: &mut App, date: CalendarDate) {
+        let month = date.first_of_month();
+
+        self.visible_month.update(cx, |this, cx| {
+            if *this != month {
+                *this = month;
+                cx.notify();
+            }
+        });
+    }
+
+    /// Shows the month `months` months after the one in the calendar, or before
+    /// for negative `months`, moving the highlighted date along with it.
+    pub fn shift_visible_month(&self, cx: &mut App, months: i32) {
+        let highlighted_date = self.highlighted_date(cx);
+        let month = self.visible_month(cx).add_months(months);
+
+        self.show_month(cx, month);
+
+        if !highlighted_date.is_same_month(month) {
+            self.highlighted_date.update(cx, |this, cx| {
+                *this = Some(highlighted_date.add_months(months));
+                cx.notify();
+            });
+        }
+    }
+
+    /// Returns the date the arrow keys move from: the highlighted date, else the
+    /// selection, else today, kept between the min and max dates.
+    pub fn highlighted_date(&self, cx: &App) -> CalendarDate {
+        let date = self
+            .highlighted_date
+            .read(cx)
+            .or_else(|| self.range_anchor(cx))
+            .or_else(|| self.selected_range(cx).map(DateRange::end))
+            .unwrap_or(self.today);
+
+        self.clamp_date(date)
+    }
+
+    /// Highlights `date` and shows its month.
+    pub fn set_highlighted_date(&self, cx: &mut App, date: CalendarDate) {
+        let date = self.clamp_date(date);
+
+        self.highlighted_date.update(cx, |this, cx| {
+            if *this != Some(date) {
+                *this = Some(date);
+                cx.notify();
+            }
+        });
+
+        self.show_month(cx, date);
+    }
+
+    /// Moves the highlight to the date `move_date` returns for the current one.
+    pub fn move_highlight(
+        &self,
+        cx: &mut App,
+        move_date: impl FnOnce(CalendarDate) -> CalendarDate,
+    ) {
+        let date = move_date(self.highlighted_date(cx));
+        self.set_highlighted_date(cx, date);
+    }
+
+    /// Moves the highlight to the start of its week.
+    pub fn move_highlight_to_start_of_week(&self, cx: &mut App) {
+        let first_weekday = self.first_weekday;
+        self.move_highlight(cx, |date| date.start_of_week(first_weekday));
+    }
+
+    /// Moves the highlight to the end of its week.
+    pub fn move_highlight_to_end_of_week(&self, cx: &mut App) {
+        let first_weekday = self.first_weekday;
+        self.move_highlight(cx, |date| date.end_of_week(first_weekday));
+    }
+
+    /// Picks the highlighted date, returning an error if it can't be picked.
+    pub fn confirm_highlight(&self, cx: &mut App) -> Result<(), DateSelectError> {
+        self.select_date(cx, self.highlighted_date(cx))
+    }
+
+    fn clamp_date(&self, date: CalendarDate) -> CalendarDate {
+        let date = self.min_date.map_or(date, |min_date| date.max(min_date));
+        self.max_date.map_or(date, |max_date| date.min(max_date))
+    }
+
+    /// Returns true if the month before `month` has dates after the min date.
+    pub(crate) fn has_month_before(&self, month: CalendarDate) -> bool {
+        self.min_date
+            .is_none_or(|min_date| month.first_of_month() > min_date)
+    }
+
+    /// Returns true if the month after `month` has dates before the max date.
+    pub(crate) fn has_month_after(&self, month: CalendarDate) -> bool {
+        self.max_date
+            .is_none_or(|max_date| month.last_of_month() < max_date)
+    }
+
+    /// Registers a focus handle from a DatePicker component using this state.
+    /// Called automatically when a DatePicker component renders.
+    pub(crate) fn register_focus_handle(&self, cx: &mut App, focus_handle: &FocusHandle) {
+        self.menu.register_focus_handle(cx, focus_handle);
+    }
+
+    /// Checks if any DatePicker component using this state has focus.
+    pub fn any_picker_focused(&self, window: &Window, cx: &mut App) -> bool {
+        self.menu.any_focused(window, cx)
+    }
+
+    /// Hides the calendar, dropping any range that was started but not finished.
+    pub fn hide_menu(&self, cx: &mut App) {
+        if self.range_anchor(cx).is_some() {
+            self.set_range_anchor(cx, None);
+            self.sync_input_to_selection(cx);
+        }
+
+        self.highlighted_date.update(cx, |this, _cx| *this = None);
+        self.menu.hide(cx, &self.menu_visible_transition);
+    }
+
+    /// Hides the calendar and keeps it closed while the picker stays focused,
+    /// until it's opened again with [`DatePickerState::show_menu`].
+    pub fn dismiss_menu(&self, cx: &mut App) {
+        self.hide_menu(cx);
+        self.menu.dismiss(cx);
+    }
+
+    /// Returns true if the calendar was dismissed while the picker kept focus.
+    pub fn is_menu_dismissed(&self, cx: &App) -> bool {
+        self.menu.is_dismissed(cx)
+    }
+
+    /// Shows the calendar.
+    pub fn show_menu(&self, cx: &mut App) {
+        self.menu.show(cx, &self.menu_visible_transition);
+    }
+}
+
+impl TypedSelection for DatePickerState {
+    type Value = DateRange;
+    type Error = DateSelectError;
+
+    fn input(&self) -> &Entity<InputState> {
+        &self.input
+    }
+
+    fn selection_text(&self, cx: &App) -> SharedString {
+        self.selection_text(cx)
+    }
+
+    fn parse(&self, text: &str) -> Result<DateRange, DateSelectError> {
+        let range = match self.selection_mode {
+            DateSelectionMode::Single => CalendarDate::parse(text).map(DateRange::single),
+            DateSelectionMode::Range => DateRange::parse(text),
+        };
+
+        range.ok_or(DateSelectError::Invalid)
+    }
+
+    fn select_parsed(&self, cx: &mut App, range: DateRange) -> Result<(), DateSelectError> {
+        self.select_range(cx, range)
+    }
+
+    fn clear_selection(&self, cx: &mut App) {
+        self.clear_selection(cx);
+    }
+}
+
+/// Errors that can occur when picking dates.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateSelectError {
+    /// The typed text isn't a date.
+    #[error("The text isn't a valid date.")]
+    Invalid,
+    /// The date is before the earliest date that can be picked.
+    #[error("The date is before the earliest date that can be picked.")]
+    BeforeMin,
+    /// The date is after the latest date that can be picked.
+    #[error("The date is after the latest date that can be picked.")]
+    AfterMax,
+    /// The date is disabled.
+    #[error("The date is disabled.")]
+    Disabled,
+}
+
+/// Registers key bindings for date picker keyboard navigation.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("down", Open, Some("DatePicker > TextInput")),
+        KeyBinding::new("escape", Dismiss, Some("DatePicker > TextInput")),
+        KeyBinding::new("left", PreviousDay, Some("DatePickerCalendar")),
+        KeyBinding::new("right", NextDay, Some("DatePickerCalendar")),
+        KeyBinding::new("up", PreviousWeek, Some("DatePickerCalendar")),
+        KeyBinding::new("down", NextWeek, Some("DatePickerCalendar")),
+        KeyBinding::new("pageup", PreviousMonth, Some("DatePickerCalendar")),
+        KeyBinding::new("pagedown", NextMonth, Some("DatePickerCalendar")),
+        KeyBinding::new("shift-pageup", PreviousYear, Some("DatePickerCalendar")),
+        KeyBinding::new("shift-pagedown", NextYear, Some("DatePickerCalendar")),
+        KeyBinding::new("home", StartOfWeek, Some("DatePickerCalendar")),
+        KeyBinding::new("end", EndOfWeek, Some("DatePickerCalendar")),
+        KeyBinding::new("enter", Confirm, Some("DatePickerCalendar")),
+        KeyBinding::new("space", Confirm, Some("DatePickerCalendar")),
+        KeyBinding::new("escape", Dismiss, Some("DatePickerCalendar")),
+    ]);
+}
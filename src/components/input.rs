@@ -61,7 +61,7 @@ pub struct Input {
     disabled: bool,
     force_hover: bool,
     on_hover: Option<Box<dyn Fn(&bool, &mut gpui::Window, &mut App) + 'static>>,
-    layer: ThemeLayerKind,
+    layer: Option<ThemeLayerKind>,
     children: PositionalChildren,
     style: InputStyles,
     base: PrimitiveInput,
@@ -77,7 +77,7 @@ impl Input {
             disabled: false,
             force_hover: false,
             on_hover: None,
-            layer: ThemeLayerKind::Tertiary,
+            layer: None,
             children: PositionalChildren::default(),
             style: InputStyles::default(),
             base: PrimitiveInput::new(id, state),
@@ -202,8 +202,9 @@ impl Input {
     }
 
     /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
     pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
-        self.layer = layer;
+        self.layer = Some(layer);
         self
     }
 
@@ -488,8 +489,9 @@ impl RenderOnce for Input {
             cx.get_theme().variants.active(cx).colors.text.all();
         let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
         let destructive_accent_color = cx.get_theme().variants.active(cx).colors.accent.destructive;
-        let background_color = self.layer.resolve(cx);
-        let border_color = self.layer.next().resolve(cx);
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
         let border_hover_color = border_color.lerp(&primary_text_color, 0.07);
         let font_family = cx.get_theme().layout.text.default_font.family[0].clone();
         let line_height = cx.get_theme().layout.text.default_font.line_height;
@@ -699,13 +701,13 @@ mod tests {
         cx.update(|_cx| {
             let input = Input::new("test-input", state.clone()).layer(ThemeLayerKind::Primary);
             assert!(
-                matches!(input.layer, ThemeLayerKind::Primary),
+                matches!(input.layer, Some(ThemeLayerKind::Primary)),
                 "Input should have primary layer"
             );
 
             let input = Input::new("test-input", state).layer(ThemeLayerKind::Secondary);
             assert!(
-                matches!(input.layer, ThemeLayerKind::Secondary),
+                matches!(input.layer, Some(ThemeLayerKind::Secondary)),
                 "Input should have secondary layer"
             );
         });
@@ -894,7 +896,7 @@ mod tests {
 
             assert!(!input.invalid);
             assert!(!input.disabled);
-            assert!(matches!(input.layer, ThemeLayerKind::Tertiary));
+            assert!(matches!(input.layer, Some(ThemeLayerKind::Tertiary)));
         });
     }
 
@@ -1,20 +1,74 @@
+mod avatar;
+pub use avatar::*;
+
+mod badge;
+pub use badge::*;
+
 mod button;
 pub use button::*;
 
+mod card;
+pub use card::*;
+
 mod chat_bubble;
 pub use chat_bubble::*;
 
 mod checkbox;
 pub use checkbox::*;
 
+mod chip;
+pub use chip::*;
+
 mod icon;
 pub use icon::*;
 
 mod input;
 pub use input::*;
 
+mod progress_bar;
+pub use progress_bar::*;
+
+mod scroll_area;
+pub use scroll_area::*;
+
+mod segmented_control;
+pub use segmented_control::*;
+
+mod spinner;
+pub use spinner::*;
+
 mod switch;
 pub use switch::*;
 
+mod tag;
+pub use tag::*;
+
+mod virtual_list;
+pub use virtual_list::*;
+
+/// Collapsible sections and accordions with animated expansion.
+pub mod accordion;
+
+/// Date input with a calendar popover for picking dates and date ranges.
+pub mod date_picker;
+
 /// Dropdown select component with keyboard navigation and search.
 pub mod select;
+
+/// Mutually exclusive options with roving keyboard focus.
+pub mod radio_group;
+
+/// Navigation sidebar with sections, nested groups and a collapsible icon-only mode.
+pub mod sidebar;
+
+/// Draggable slider with stepped and two-thumb range modes.
+pub mod slider;
+
+/// Resizable panes separated by draggable handles.
+pub mod split_pane;
+
+/// Tab list with keyboard navigation, an animated indicator and lazily rendered panels.
+pub mod tabs;
+
+/// Time input with a popover list of times.
+pub mod time_picker;
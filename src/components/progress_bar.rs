@@ -0,0 +1,228 @@
+use std::time::Duration;
+
+use gpui::{
+    Animation, AnimationExt, App, Edges, ElementId, IntoElement, Length, ParentElement, RenderOnce,
+    Styled, Window, div, ease_in_out, ease_out_quint, prelude::FluentBuilder, px, relative,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    ElementIdExt, conitional_transition,
+    theme::{ThemeAccentKind, ThemeExt, ThemeLayerKind},
+};
+
+/// Fraction of the track covered by the sliding segment of an indeterminate bar.
+const INDETERMINATE_WIDTH: f32 = 0.35;
+
+/// A horizontal bar showing the progress of a task.
+#[derive(IntoElement)]
+pub struct ProgressBar {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    accent: ThemeAccentKind,
+    value: Option<f32>,
+    margin: Edges<Option<Length>>,
+    width: Length,
+}
+
+impl ProgressBar {
+    /// Creates a new progress bar with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            accent: ThemeAccentKind::Primary,
+            value: Some(0.),
+            margin: Edges::default(),
+            width: relative(1.).into(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the accent color of the fill.
+    pub fn accent(mut self, accent: ThemeAccentKind) -> Self {
+        self.accent = accent;
+        self
+    }
+
+    /// Sets the progress, from 0 to 1.
+    pub fn value(mut self, value: f32) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// Shows continuous activity instead of a specific amount of progress.
+    pub fn indeterminate(mut self) -> Self {
+        self.value = None;
+        self
+    }
+}
+
+impl RenderOnce for ProgressBar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let height = (cx.get_theme().layout.padding.md * 1.5).round();
+        let accent_color = self.accent.resolve(cx);
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+
+        let fill = match self.value {
+            Some(value) => {
+                let fraction = *conitional_transition!(
+                    self.id.with_suffix("state:transition:fill"),
+                    window,
+                    cx,
+                    Duration::from_millis(365),
+                    progress_fraction(value)
+                )
+                .with_easing(ease_out_quint())
+                .evaluate(window, cx);
+
+                div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left_0()
+                    .w(relative(fraction))
+                    .rounded(px(100.))
+                    .bg(accent_color)
+                    .into_any_element()
+            }
+            None => div()
+                .absolute()
+                .top_0()
+                .bottom_0()
+                .w(relative(INDETERMINATE_WIDTH))
+                .rounded(px(100.))
+                .bg(accent_color)
+                .with_animation(
+                    self.id.with_suffix("indeterminate"),
+                    Animation::new(Duration::from_millis(1200))
+                        .repeat()
+                        .with_easing(ease_in_out),
+                    |this, delta| this.left(relative(indeterminate_offset(delta))),
+                )
+                .into_any_element(),
+        };
+
+        div()
+            .relative()
+            .w(self.width)
+            .h(height)
+            .min_h(height)
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .child(
+                squircle()
+                    .absolute_expand()
+                    .rounded(px(100.))
+                    .bg(background_color)
+                    .border(px(1.))
+                    .border_inside()
+                    .border_color(border_color),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .size_full()
+                    .rounded(px(100.))
+                    .overflow_hidden()
+                    .child(fill),
+            )
+    }
+}
+
+/// Clamps a progress value into a fraction of the track.
+fn progress_fraction(value: f32) -> f32 {
+    if value.is_nan() {
+        return 0.;
+    }
+
+    value.clamp(0., 1.)
+}
+
+/// Returns the left edge of the indeterminate segment, sliding it in from
+/// fully off the start of the track to fully off the end.
+fn indeterminate_offset(delta: f32) -> f32 {
+    -INDETERMINATE_WIDTH + delta * (1. + INDETERMINATE_WIDTH)
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+
+    #[gpui::test]
+    fn test_progress_bar_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let progress_bar = ProgressBar::new("test-progress")
+                .value(0.4)
+                .accent(ThemeAccentKind::Constructive);
+
+            assert_eq!(progress_bar.value, Some(0.4));
+            assert!(matches!(progress_bar.accent, ThemeAccentKind::Constructive));
+
+            let progress_bar = progress_bar.indeterminate();
+            assert_eq!(progress_bar.value, None);
+        });
+    }
+
+    #[test]
+    fn test_progress_fraction() {
+        assert_eq!(progress_fraction(0.5), 0.5);
+        assert_eq!(progress_fraction(-1.), 0.);
+        assert_eq!(progress_fraction(2.), 1.);
+        assert_eq!(progress_fraction(f32::NAN), 0.);
+    }
+
+    #[test]
+    fn test_indeterminate_offset() {
+        assert_eq!(indeterminate_offset(0.), -INDETERMINATE_WIDTH);
+        assert_eq!(indeterminate_offset(1.), 1.);
+    }
+}
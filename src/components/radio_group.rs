@@ -0,0 +1,688 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::{rc::Rc, sync::Arc, time::Duration};
+
+use gpui::{
+    App, AppContext, Axis, CursorStyle, Edges, ElementId, Entity, FocusHandle, InteractiveElement,
+    IntoElement, KeyBinding, Length, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement, Styled, Window, actions, div, ease_out_quint,
+    prelude::FluentBuilder, px,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+use gpui_transitions::Lerp;
+
+use crate::{
+    ElementIdExt, conitional_transition,
+    extensions::mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+    primitives::FocusRing,
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{RgbaExt, SquircleExt, checked_transition, disabled_transition, next_enabled_index},
+};
+
+actions!(radio_group, [SelectPrev, SelectNext, SelectFocused]);
+
+/// Registers key bindings for moving between and selecting radio options.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some("RadioGroup")),
+        KeyBinding::new("left", SelectPrev, Some("RadioGroup")),
+        KeyBinding::new("down", SelectNext, Some("RadioGroup")),
+        KeyBinding::new("right", SelectNext, Some("RadioGroup")),
+        KeyBinding::new("space", SelectFocused, Some("RadioGroup")),
+    ]);
+}
+
+/// Type alias for the selection change callback.
+pub type OnRadioChangeFn = Rc<dyn Fn(&SharedString, &mut Window, &mut App)>;
+
+/// Shared state for a [`RadioGroup`], tracking which option is selected.
+pub struct RadioGroupState {
+    pub(crate) selected: Entity<Option<SharedString>>,
+}
+
+impl RadioGroupState {
+    /// Creates state from a pre-existing entity, useful for tests or manual setup.
+    pub fn new(selected: Entity<Option<SharedString>>) -> Self {
+        Self { selected }
+    }
+
+    /// Creates state using window-keyed storage, persisting across renders.
+    pub fn from_window(id: impl Into<ElementId>, window: &mut Window, cx: &mut App) -> Self {
+        Self {
+            selected: window.use_keyed_state(
+                id.into().with_suffix("state:selected"),
+                cx,
+                |_window, _cx| None,
+            ),
+        }
+    }
+
+    /// Creates state from an App context with an optional initial selection.
+    pub fn from_cx(cx: &mut App, selected: Option<SharedString>) -> Self {
+        Self {
+            selected: cx.new(|_cx| selected),
+        }
+    }
+
+    /// Returns the value of the selected option, if any.
+    pub fn selected(&self, cx: &App) -> Option<SharedString> {
+        self.selected.read(cx).clone()
+    }
+
+    /// Returns true if the option with the given value is selected.
+    pub fn is_selected(&self, value: &str, cx: &App) -> bool {
+        self.selected.read(cx).as_deref() == Some(value)
+    }
+
+    /// Selects the option with the given value.
+    pub fn select(&self, value: impl Into<SharedString>, cx: &mut App) {
+        let value = value.into();
+
+        self.selected.update(cx, |this, cx| {
+            *this = Some(value);
+            cx.notify();
+        });
+    }
+
+    /// Clears the selection.
+    pub fn clear(&self, cx: &mut App) {
+        self.selected.update(cx, |this, cx| {
+            *this = None;
+            cx.notify();
+        });
+    }
+}
+
+/// A single option within a [`RadioGroup`].
+pub struct Radio {
+    value: SharedString,
+    label: Option<SharedString>,
+    disabled: bool,
+    focus_handle: Option<FocusHandle>,
+}
+
+impl Radio {
+    /// Creates a new option identified by `value`.
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: None,
+            disabled: false,
+            focus_handle: None,
+        }
+    }
+
+    /// Sets the text shown next to the radio.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the disabled state, preventing this option from being selected.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the focus handle for keyboard navigation.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+}
+
+/// A group of mutually exclusive options with roving keyboard focus.
+#[derive(IntoElement)]
+pub struct RadioGroup {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    state: Arc<RadioGroupState>,
+    radios: Vec<Radio>,
+    axis: Axis,
+    disabled: bool,
+    on_change: Option<OnRadioChangeFn>,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+}
+
+impl RadioGroup {
+    /// Creates a new radio group with the given ID and shared state.
+    pub fn new(id: impl Into<ElementId>, state: impl Into<Arc<RadioGroupState>>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            state: state.into(),
+            radios: Vec::new(),
+            axis: Axis::Vertical,
+            disabled: false,
+            on_change: None,
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Adds an option to the group.
+    pub fn radio(mut self, radio: Radio) -> Self {
+        self.radios.push(radio);
+        self
+    }
+
+    /// Adds multiple options to the group.
+    pub fn radios(mut self, radios: impl IntoIterator<Item = Radio>) -> Self {
+        self.radios.extend(radios);
+        self
+    }
+
+    /// Lays the options out in a row.
+    pub fn horizontal(mut self) -> Self {
+        self.axis = Axis::Horizontal;
+        self
+    }
+
+    /// Lays the options out in a column. This is the default.
+    pub fn vertical(mut self) -> Self {
+        self.axis = Axis::Vertical;
+        self
+    }
+
+    /// Sets the disabled state of every option in the group.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets a callback invoked with the newly selected value.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl MouseBehaviorExt for RadioGroup {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for RadioGroup {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let size = cx.get_theme().layout.size.md;
+        let padding = cx.get_theme().layout.padding.md;
+        let text_size = cx.get_theme().layout.text.default_font.sizes.body.clone();
+        let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+        let primary_text_color = cx.get_theme().variants.active(cx).colors.text.primary;
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let border_hover_color = border_color.lerp(&primary_text_color, 0.07);
+        let border_click_down_color = border_color.lerp(&primary_text_color, 0.16);
+
+        let selected = self.state.selected(cx);
+
+        let values: Rc<[SharedString]> = self.radios.iter().map(|r| r.value.clone()).collect();
+        let disabled: Rc<[bool]> = self
+            .radios
+            .iter()
+            .map(|radio| self.disabled || radio.disabled)
+            .collect();
+
+        let radio_ids: Vec<ElementId> = values
+            .iter()
+            .map(|value| self.id.with_suffix(format!("radio:{value}")))
+            .collect();
+
+        let focus_handles: Rc<[FocusHandle]> = self
+            .radios
+            .iter()
+            .zip(&radio_ids)
+            .map(|(radio, radio_id)| {
+                radio
+                    .focus_handle
+                    .as_ref()
+                    .unwrap_or_else(|| {
+                        window
+                            .use_keyed_state(
+                                radio_id.with_suffix("state:focus_handle"),
+                                cx,
+                                |_window, cx| cx.focus_handle(),
+                            )
+                            .read(cx)
+                    })
+                    .clone()
+            })
+            .collect();
+
+        // Only one option is reachable with tab, arrow keys move between the rest.
+        let selected_index = selected
+            .as_ref()
+            .and_then(|selected| values.iter().position(|value| value == selected));
+        let tab_stop_index = roving_index(selected_index, &disabled);
+
+        let select: Rc<dyn Fn(usize, &mut Window, &mut App)> = {
+            let state = self.state.clone();
+            let values = values.clone();
+            let on_change = self.on_change.clone();
+
+            Rc::new(move |idx, window, cx| {
+                let Some(value) = values.get(idx) else {
+                    return;
+                };
+
+                if state.is_selected(value, cx) {
+                    return;
+                }
+
+                state.select(value.clone(), cx);
+
+                if let Some(on_change) = on_change.as_ref() {
+                    (on_change)(value, window, cx);
+                }
+            })
+        };
+
+        let move_focus = {
+            let focus_handles = focus_handles.clone();
+            let disabled = disabled.clone();
+            let select = select.clone();
+
+            move |forward: bool, window: &mut Window, cx: &mut App| {
+                let Some(current) = focus_handles
+                    .iter()
+                    .position(|focus_handle| focus_handle.is_focused(window))
+                else {
+                    return;
+                };
+
+                if let Some(next) = next_enabled_index(current, &disabled, forward) {
+                    focus_handles[next].focus(window, cx);
+                    (select)(next, window, cx);
+                }
+            }
+        };
+        let move_focus = Rc::new(move_focus);
+        let move_focus_prev = move_focus.clone();
+
+        let focus_handles_on_select = focus_handles.clone();
+        let disabled_on_select = disabled.clone();
+        let select_on_select = select.clone();
+
+        let behavior = self.mouse_behavior;
+
+        div()
+            .id(self.id.clone())
+            .key_context("RadioGroup")
+            .flex()
+            .map(|this| match self.axis {
+                Axis::Vertical => this.flex_col().gap(padding),
+                Axis::Horizontal => this.flex_row().flex_wrap().gap(padding * 3.),
+            })
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .on_action(move |_: &SelectPrev, window, cx| (move_focus_prev)(false, window, cx))
+            .on_action(move |_: &SelectNext, window, cx| (move_focus)(true, window, cx))
+            .on_action(move |_: &SelectFocused, window, cx| {
+                let focused = focus_handles_on_select
+                    .iter()
+                    .position(|focus_handle| focus_handle.is_focused(window));
+
+                if let Some(idx) = focused.filter(|idx| !disabled_on_select[*idx]) {
+                    (select_on_select)(idx, window, cx);
+                }
+            })
+            .children(self.radios.into_iter().zip(radio_ids).enumerate().map(
+                |(idx, (radio, radio_id))| {
+                    let is_disabled = disabled[idx];
+                    let is_checked = selected.as_ref() == Some(&radio.value);
+
+                    let checked_transition = checked_transition(
+                        radio_id.clone(),
+                        window,
+                        cx,
+                        Duration::from_millis(285),
+                        is_checked,
+                    );
+
+                    let is_hover_state = window.use_keyed_state(
+                        radio_id.with_suffix("state:hover"),
+                        cx,
+                        |_cx, _window| false,
+                    );
+                    let is_hover = *is_hover_state.read(cx);
+
+                    let is_click_down_state = window.use_keyed_state(
+                        radio_id.with_suffix("state:click_down"),
+                        cx,
+                        |_cx, _window| false,
+                    );
+                    let is_click_down = *is_click_down_state.read(cx);
+
+                    let focus_handle = focus_handles[idx]
+                        .clone()
+                        .tab_stop(tab_stop_index == Some(idx));
+                    let is_focus = focus_handle.is_focused(window);
+
+                    let disabled_transition =
+                        disabled_transition(radio_id.clone(), window, cx, is_disabled);
+
+                    if is_focus && is_disabled {
+                        window.blur();
+                    }
+
+                    let border_color_transition = conitional_transition!(
+                        radio_id.with_suffix("state:transition:border_color"),
+                        window,
+                        cx,
+                        Duration::from_millis(365),
+                        {
+                            is_focus => primary_accent_color,
+                            is_click_down => border_click_down_color,
+                            is_hover => border_hover_color,
+                            _ => border_color
+                        }
+                    )
+                    .with_easing(ease_out_quint());
+
+                    let checked_delta = *checked_transition.evaluate(window, cx);
+
+                    div()
+                        .id(radio_id.clone())
+                        .flex()
+                        .items_center()
+                        .gap(padding * 2.)
+                        .cursor(if is_disabled {
+                            CursorStyle::OperationNotAllowed
+                        } else {
+                            CursorStyle::PointingHand
+                        })
+                        .opacity(*disabled_transition.evaluate(window, cx))
+                        .child(
+                            div()
+                                .relative()
+                                .flex()
+                                .items_center()
+                                .justify_center()
+                                .size(size)
+                                .min_w(size)
+                                .min_h(size)
+                                .child(
+                                    FocusRing::new(
+                                        radio_id.with_suffix("focus_ring"),
+                                        focus_handle.clone(),
+                                    )
+                                    .rounded(px(100.)),
+                                )
+                                .child(
+                                    squircle()
+                                        .absolute_expand()
+                                        .rounded(px(100.))
+                                        .bg(background_color)
+                                        .border(px(1.))
+                                        .border_inside()
+                                        .border_color(
+                                            *border_color_transition.evaluate(window, cx),
+                                        ),
+                                )
+                                .child(
+                                    squircle()
+                                        .absolute_expand()
+                                        .rounded(px(100.))
+                                        .border(px(1.))
+                                        .border_inside()
+                                        .bg(primary_accent_color.alpha(checked_delta))
+                                        .border_highlight(checked_delta * 0.15),
+                                )
+                                // The dot grows in from the center as the radio is checked.
+                                .child(
+                                    div()
+                                        .size(size * 0.4 * checked_delta)
+                                        .rounded(px(100.))
+                                        .bg(primary_text_color),
+                                ),
+                        )
+                        .when_some(radio.label, |this, label| {
+                            this.child(
+                                div()
+                                    .text_size(text_size)
+                                    .text_color(primary_text_color)
+                                    .child(label),
+                            )
+                        })
+                        .when(!is_disabled, |this| {
+                            let is_hover_state_on_hover = is_hover_state.clone();
+                            let is_click_down_state_on_mouse_down = is_click_down_state.clone();
+                            let is_click_down_state_on_click = is_click_down_state.clone();
+                            let select = select.clone();
+
+                            this.on_hover(move |hover, _window, cx| {
+                                is_hover_state_on_hover.update(cx, |this, cx| {
+                                    *this = *hover;
+                                    cx.notify();
+                                });
+                            })
+                            .on_mouse_down(gpui::MouseButton::Left, move |_, window, cx| {
+                                behavior.apply(window, cx);
+
+                                is_click_down_state_on_mouse_down.update(cx, |this, cx| {
+                                    *this = true;
+                                    cx.notify();
+                                });
+                            })
+                            .on_click(move |_event, window, cx| {
+                                behavior.apply(window, cx);
+
+                                if !is_focus {
+                                    window.blur();
+                                }
+
+                                is_click_down_state_on_click.update(cx, |this, cx| {
+                                    *this = false;
+                                    cx.notify();
+                                });
+
+                                (select)(idx, window, cx);
+                            })
+                            .on_mouse_up_out(gpui::MouseButton::Left, move |_event, _window, cx| {
+                                is_hover_state.update(cx, |this, cx| {
+                                    *this = false;
+                                    cx.notify();
+                                });
+
+                                is_click_down_state.update(cx, |this, cx| {
+                                    *this = false;
+                                    cx.notify();
+                                });
+                            })
+                            .track_focus(&focus_handle)
+                        })
+                },
+            ))
+    }
+}
+
+/// Returns the option that should be reachable with tab: the selected one if it
+/// is enabled, otherwise the first enabled option.
+fn roving_index(selected: Option<usize>, disabled: &[bool]) -> Option<usize> {
+    selected
+        .filter(|idx| disabled.get(*idx) == Some(&false))
+        .or_else(|| disabled.iter().position(|disabled| !disabled))
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::{TestAppContext, VisualTestContext};
+
+    #[gpui::test]
+    fn test_radio_group_state(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let state = RadioGroupState::from_cx(cx, None);
+            assert_eq!(state.selected(cx), None);
+
+            state.select("small", cx);
+            assert!(state.is_selected("small", cx));
+            assert!(!state.is_selected("large", cx));
+
+            state.select("large", cx);
+            assert_eq!(state.selected(cx), Some("large".into()));
+
+            state.clear(cx);
+            assert_eq!(state.selected(cx), None);
+        });
+    }
+
+    #[gpui::test]
+    fn test_radio_group_builder(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let state = RadioGroupState::from_cx(cx, None);
+            let group = RadioGroup::new("test-radio-group", state)
+                .radio(Radio::new("a"))
+                .radios([Radio::new("b"), Radio::new("c").disabled(true)])
+                .horizontal()
+                .disabled(true);
+
+            assert_eq!(group.radios.len(), 3);
+            assert!(group.radios[2].disabled);
+            assert_eq!(group.axis, Axis::Horizontal);
+            assert!(group.disabled, "Radio group should be disabled");
+            assert_eq!(group.vertical().axis, Axis::Vertical);
+        });
+    }
+
+    #[test]
+    fn test_roving_index() {
+        assert_eq!(roving_index(Some(2), &[false, false, false]), Some(2));
+        assert_eq!(roving_index(None, &[false, false]), Some(0));
+        assert_eq!(roving_index(Some(0), &[true, false]), Some(1));
+        assert_eq!(roving_index(None, &[true, true]), None);
+        assert_eq!(roving_index(None, &[]), None);
+    }
+
+    #[gpui::test]
+    fn test_radio_group_renders_in_window(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |_window, cx| {
+                let state = Arc::new(RadioGroupState::from_cx(cx, Some("b".into())));
+                let focus_handle = cx.focus_handle();
+                cx.new(|_cx| RadioGroupTestView {
+                    state,
+                    focus_handle,
+                    changes: Vec::new(),
+                })
+            })
+            .unwrap()
+        });
+
+        let _cx = VisualTestContext::from_window(window.into(), cx);
+    }
+
+    #[gpui::test]
+    fn test_radio_group_keyboard_navigation(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        let (view, cx) = cx.add_window_view(|window, cx| {
+            cx.set_theme(Theme::DEFAULT);
+            init(cx);
+
+            let focus_handle = cx.focus_handle();
+            focus_handle.focus(window, cx);
+
+            RadioGroupTestView {
+                state: Arc::new(RadioGroupState::from_cx(cx, None)),
+                focus_handle,
+                changes: Vec::new(),
+            }
+        });
+
+        cx.simulate_keystrokes("space");
+        cx.simulate_keystrokes("down");
+        cx.simulate_keystrokes("down");
+        cx.simulate_keystrokes("up");
+
+        cx.update(|_window, cx| {
+            let view = view.read(cx);
+            // "b" is disabled, so navigation skips over it.
+            let changes: Vec<String> = view.changes.iter().map(|c| c.to_string()).collect();
+            assert_eq!(changes, vec!["a", "c", "a", "c"]);
+            assert!(view.state.is_selected("c", cx));
+        });
+    }
+
+    struct RadioGroupTestView {
+        state: Arc<RadioGroupState>,
+        focus_handle: FocusHandle,
+        changes: Vec<SharedString>,
+    }
+
+    impl gpui::Render for RadioGroupTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            let entity = cx.entity();
+
+            RadioGroup::new("test-radio-group", self.state.clone())
+                .radio(
+                    Radio::new("a")
+                        .label("Option A")
+                        .focus_handle(self.focus_handle.clone()),
+                )
+                .radio(Radio::new("b").label("Option B").disabled(true))
+                .radio(Radio::new("c").label("Option C"))
+                .on_change(move |value, _window, cx| {
+                    let value = value.clone();
+                    entity.update(cx, |this, cx| {
+                        this.changes.push(value);
+                        cx.notify();
+                    });
+                })
+        }
+    }
+}
@@ -0,0 +1,614 @@
+use std::time::Duration;
+
+use gpui::{
+    AnyElement, App, Axis, Edges, ElementId, InteractiveElement, IntoElement, Length, MouseButton,
+    ParentElement, Pixels, RenderOnce, ScrollHandle, StatefulInteractiveElement, Styled, Window,
+    div, ease_out_quint, linear_color_stop, linear_gradient, prelude::FluentBuilder, px, relative,
+};
+use smallvec::SmallVec;
+
+use crate::{
+    ElementIdExt, conitional_transition,
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::RgbaExt,
+    views::RootWindowExt,
+};
+
+const TRACK_SIZE: f32 = 10.;
+const THUMB_INSET: f32 = 2.;
+const MIN_THUMB_LENGTH: f32 = 20.;
+
+/// Which directions a [`ScrollArea`] scrolls in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAxes {
+    /// Scroll vertically only.
+    #[default]
+    Vertical,
+    /// Scroll horizontally only.
+    Horizontal,
+    /// Scroll in both directions.
+    Both,
+}
+
+impl ScrollAxes {
+    fn axes(&self) -> &'static [Axis] {
+        match self {
+            ScrollAxes::Vertical => &[Axis::Vertical],
+            ScrollAxes::Horizontal => &[Axis::Horizontal],
+            ScrollAxes::Both => &[Axis::Vertical, Axis::Horizontal],
+        }
+    }
+}
+
+/// An in-progress scrollbar thumb drag.
+#[derive(Clone, Copy, PartialEq)]
+struct ScrollbarDrag {
+    axis: Axis,
+    pointer_start: f32,
+    scrolled_start: f32,
+}
+
+/// A scroll container with overlay scrollbars and edge fades.
+#[derive(IntoElement)]
+pub struct ScrollArea {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    axes: ScrollAxes,
+    scroll_handle: Option<ScrollHandle>,
+    auto_hide: bool,
+    shadows: bool,
+    children: SmallVec<[AnyElement; 2]>,
+    margin: Edges<Option<Length>>,
+    width: Length,
+    height: Length,
+    max_height: Option<Length>,
+}
+
+impl ScrollArea {
+    /// Creates a new scroll area with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            axes: ScrollAxes::default(),
+            scroll_handle: None,
+            auto_hide: true,
+            shadows: true,
+            children: SmallVec::new(),
+            margin: Edges::default(),
+            width: relative(1.).into(),
+            height: Length::Auto,
+            max_height: None,
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height. Defaults to the height of the content.
+    pub fn h(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the maximum height, beyond which the content scrolls.
+    pub fn max_h(mut self, max_height: impl Into<Length>) -> Self {
+        self.max_height = Some(max_height.into());
+        self
+    }
+
+    /// Sets the background layer the edge fades blend into.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets which directions the content scrolls in.
+    pub fn axes(mut self, axes: ScrollAxes) -> Self {
+        self.axes = axes;
+        self
+    }
+
+    /// Sets the scroll handle, allowing the offset to be read or set from outside.
+    pub fn scroll_handle(mut self, scroll_handle: ScrollHandle) -> Self {
+        self.scroll_handle = Some(scroll_handle);
+        self
+    }
+
+    /// Hides the scrollbars until the area is hovered. Defaults to true.
+    pub fn auto_hide(mut self, auto_hide: bool) -> Self {
+        self.auto_hide = auto_hide;
+        self
+    }
+
+    /// Fades the content out at edges that can be scrolled further. Defaults to true.
+    pub fn shadows(mut self, shadows: bool) -> Self {
+        self.shadows = shadows;
+        self
+    }
+}
+
+impl ParentElement for ScrollArea {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+impl RenderOnce for ScrollArea {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        const SHADOW_SIZE: Pixels = px(12.);
+
+        let primary_text_color = cx.get_theme().variants.active(cx).colors.text.primary;
+        let background_color = self
+            .layer
+            .unwrap_or_else(|| ThemeLayerKind::current(cx))
+            .resolve(cx);
+
+        let scroll_handle = self.scroll_handle.clone().unwrap_or_else(|| {
+            window
+                .use_keyed_state(self.id.with_suffix("state:scroll_handle"), cx, |_, _| {
+                    ScrollHandle::new()
+                })
+                .read(cx)
+                .clone()
+        });
+
+        let is_hover_state =
+            window.use_keyed_state(self.id.with_suffix("state:hover"), cx, |_cx, _window| false);
+        let is_hover = *is_hover_state.read(cx);
+
+        let dragging_state =
+            window.use_keyed_state(self.id.with_suffix("state:dragging"), cx, |_cx, _window| {
+                None::<ScrollbarDrag>
+            });
+        let dragging = *dragging_state.read(cx);
+
+        // Scroll handle geometry is from the previous frame. Offsets are negative,
+        // so they are flipped into a positive scrolled distance here.
+        let viewport_bounds = scroll_handle.bounds();
+        let max_offset = scroll_handle.max_offset();
+        let offset = scroll_handle.offset();
+        let scrolled = |axis: Axis| -(offset.along(axis).to_f64() as f32);
+        let max_scrolled = |axis: Axis| max_offset.along(axis).to_f64() as f32;
+
+        let axes = self.axes.axes();
+        let is_scrollable = |axis: Axis| axes.contains(&axis) && max_scrolled(axis) > 0.;
+        let both_scrollable = is_scrollable(Axis::Vertical) && is_scrollable(Axis::Horizontal);
+
+        let show_scrollbars = !self.auto_hide || is_hover || dragging.is_some();
+        let scrollbar_opacity = *conitional_transition!(
+            self.id.with_suffix("state:transition:scrollbar_opacity"),
+            window,
+            cx,
+            Duration::from_millis(285),
+            {
+                show_scrollbars => 1.,
+                _ => 0.
+            }
+        )
+        .with_easing(ease_out_quint())
+        .evaluate(window, cx);
+
+        // Keep following the pointer after it leaves the track, until it is released.
+        if let Some(drag) = dragging {
+            let scroll_handle = scroll_handle.clone();
+            let thumb_length = thumb_metrics_for(&scroll_handle, drag.axis, both_scrollable)
+                .map(|(_, length)| length)
+                .unwrap_or(0.);
+
+            window.on_root_mouse_move(cx, move |event, window, _cx| {
+                let viewport = scroll_handle.bounds().size.along(drag.axis).to_f64() as f32;
+                let max_scrolled = scroll_handle.max_offset().along(drag.axis).to_f64() as f32;
+                let track = track_length(viewport, both_scrollable);
+                let delta = event.position.along(drag.axis).to_f64() as f32 - drag.pointer_start;
+
+                set_scrolled(
+                    &scroll_handle,
+                    drag.axis,
+                    dragged_offset(
+                        drag.scrolled_start,
+                        delta,
+                        max_scrolled,
+                        track,
+                        thumb_length,
+                    ),
+                );
+                window.refresh();
+            });
+
+            let dragging_state = dragging_state.clone();
+            window.on_root_mouse_up(cx, move |_event, _window, cx| {
+                dragging_state.update(cx, |this, cx| {
+                    *this = None;
+                    cx.notify();
+                });
+            });
+        }
+
+        let mut scrollbars: SmallVec<[AnyElement; 2]> = SmallVec::new();
+
+        for axis in axes.iter().copied().filter(|axis| is_scrollable(*axis)) {
+            let Some((thumb_start, thumb_length)) =
+                thumb_metrics_for(&scroll_handle, axis, both_scrollable)
+            else {
+                continue;
+            };
+
+            let track_id = self.id.with_suffix(match axis {
+                Axis::Vertical => "scrollbar:vertical",
+                Axis::Horizontal => "scrollbar:horizontal",
+            });
+
+            let is_track_hover_state =
+                window.use_keyed_state(track_id.with_suffix("state:hover"), cx, |_cx, _window| {
+                    false
+                });
+            let is_track_hover = *is_track_hover_state.read(cx);
+            let is_axis_dragging = dragging.is_some_and(|drag| drag.axis == axis);
+
+            let thumb_color = *conitional_transition!(
+                track_id.with_suffix("state:transition:thumb_color"),
+                window,
+                cx,
+                Duration::from_millis(185),
+                {
+                    is_axis_dragging => primary_text_color.alpha(0.5),
+                    is_track_hover => primary_text_color.alpha(0.4),
+                    _ => primary_text_color.alpha(0.25)
+                }
+            )
+            .with_easing(ease_out_quint())
+            .evaluate(window, cx);
+
+            let track_origin = viewport_bounds.origin.along(axis).to_f64() as f32 + THUMB_INSET;
+            let scrolled_now = scrolled(axis);
+            let max_scrolled_now = max_scrolled(axis);
+            let track = track_length(
+                viewport_bounds.size.along(axis).to_f64() as f32,
+                both_scrollable,
+            );
+
+            let thumb = div()
+                .absolute()
+                .rounded(px(100.))
+                .bg(thumb_color)
+                .map(|this| match axis {
+                    Axis::Vertical => this
+                        .left(px(THUMB_INSET))
+                        .right(px(THUMB_INSET))
+                        .top(px(thumb_start))
+                        .h(px(thumb_length)),
+                    Axis::Horizontal => this
+                        .top(px(THUMB_INSET))
+                        .bottom(px(THUMB_INSET))
+                        .left(px(thumb_start))
+                        .w(px(thumb_length)),
+                });
+
+            let scroll_handle = scroll_handle.clone();
+            let dragging_state = dragging_state.clone();
+
+            scrollbars.push(
+                div()
+                    .id(track_id)
+                    .absolute()
+                    .opacity(scrollbar_opacity)
+                    .map(|this| match axis {
+                        Axis::Vertical => this
+                            .top(px(THUMB_INSET))
+                            .right_0()
+                            .w(px(TRACK_SIZE))
+                            .h(px(track)),
+                        Axis::Horizontal => this
+                            .left(px(THUMB_INSET))
+                            .bottom_0()
+                            .h(px(TRACK_SIZE))
+                            .w(px(track)),
+                    })
+                    .child(thumb)
+                    .when(show_scrollbars, |this| {
+                        this.on_hover(move |hover, _window, cx| {
+                            is_track_hover_state.update(cx, |this, cx| {
+                                *this = *hover;
+                                cx.notify();
+                            });
+                        })
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            move |event, window, cx| {
+                                cx.stop_propagation();
+
+                                let pointer = event.position.along(axis).to_f64() as f32;
+                                let position = pointer - track_origin;
+
+                                // Clicking the track jumps the thumb to the pointer, then
+                                // continues as a drag so the thumb can be pulled from there.
+                                let scrolled_start = if (thumb_start..=thumb_start + thumb_length)
+                                    .contains(&position)
+                                {
+                                    scrolled_now
+                                } else {
+                                    let target = track_click_offset(
+                                        position,
+                                        max_scrolled_now,
+                                        track,
+                                        thumb_length,
+                                    );
+                                    set_scrolled(&scroll_handle, axis, target);
+                                    window.refresh();
+                                    target
+                                };
+
+                                dragging_state.update(cx, |this, cx| {
+                                    *this = Some(ScrollbarDrag {
+                                        axis,
+                                        pointer_start: pointer,
+                                        scrolled_start,
+                                    });
+                                    cx.notify();
+                                });
+                            },
+                        )
+                    })
+                    .into_any_element(),
+            );
+        }
+
+        let shadow = |axis: Axis, at_start: bool| {
+            let gradient = |angle: f32| {
+                linear_gradient(
+                    angle,
+                    linear_color_stop(background_color, 0.),
+                    linear_color_stop(background_color.alpha(0.), 1.),
+                )
+            };
+
+            let this = div().absolute();
+            match (axis, at_start) {
+                (Axis::Vertical, true) => this
+                    .top_0()
+                    .left_0()
+                    .right_0()
+                    .h(SHADOW_SIZE)
+                    .bg(gradient(180.)),
+                (Axis::Vertical, false) => this
+                    .bottom_0()
+                    .left_0()
+                    .right_0()
+                    .h(SHADOW_SIZE)
+                    .bg(gradient(0.)),
+                (Axis::Horizontal, true) => this
+                    .left_0()
+                    .top_0()
+                    .bottom_0()
+                    .w(SHADOW_SIZE)
+                    .bg(gradient(90.)),
+                (Axis::Horizontal, false) => this
+                    .right_0()
+                    .top_0()
+                    .bottom_0()
+                    .w(SHADOW_SIZE)
+                    .bg(gradient(270.)),
+            }
+        };
+
+        let shadows: SmallVec<[_; 4]> = if self.shadows {
+            axes.iter()
+                .copied()
+                .filter(|axis| is_scrollable(*axis))
+                .flat_map(|axis| {
+                    let scrolled = scrolled(axis);
+                    [
+                        (scrolled > 0.).then(|| shadow(axis, true)),
+                        (scrolled < max_scrolled(axis)).then(|| shadow(axis, false)),
+                    ]
+                })
+                .flatten()
+                .collect()
+        } else {
+            SmallVec::new()
+        };
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .w(self.width)
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .on_hover(move |hover, _window, cx| {
+                is_hover_state.update(cx, |this, cx| {
+                    *this = *hover;
+                    cx.notify();
+                });
+            })
+            .child(
+                div()
+                    .id(self.id.with_suffix("viewport"))
+                    .w_full()
+                    .h(self.height)
+                    .when_some(self.max_height, |this, max_height| this.max_h(max_height))
+                    .map(|this| match self.axes {
+                        ScrollAxes::Vertical => this.overflow_y_scroll(),
+                        ScrollAxes::Horizontal => this.overflow_x_scroll(),
+                        ScrollAxes::Both => this.overflow_scroll(),
+                    })
+                    .track_scroll(&scroll_handle)
+                    .children(self.children),
+            )
+            .children(shadows)
+            .children(scrollbars)
+    }
+}
+
+/// Returns the length of a scrollbar track, leaving room at the end for the
+/// other scrollbar when both are shown.
+fn track_length(viewport: f32, both_scrollable: bool) -> f32 {
+    let reserved = if both_scrollable { TRACK_SIZE } else { 0. };
+    (viewport - THUMB_INSET * 2. - reserved).max(0.)
+}
+
+/// Returns the thumb position and length for `axis` of the scroll handle.
+fn thumb_metrics_for(
+    scroll_handle: &ScrollHandle,
+    axis: Axis,
+    both_scrollable: bool,
+) -> Option<(f32, f32)> {
+    let viewport = scroll_handle.bounds().size.along(axis).to_f64() as f32;
+
+    thumb_metrics(
+        viewport,
+        scroll_handle.max_offset().along(axis).to_f64() as f32,
+        -(scroll_handle.offset().along(axis).to_f64() as f32),
+        track_length(viewport, both_scrollable),
+        MIN_THUMB_LENGTH,
+    )
+}
+
+/// Returns the start and length of a thumb along its track, or `None` when
+/// there is nothing to scroll.
+fn thumb_metrics(
+    viewport: f32,
+    max_scrolled: f32,
+    scrolled: f32,
+    track: f32,
+    min_thumb: f32,
+) -> Option<(f32, f32)> {
+    if max_scrolled <= 0. || viewport <= 0. || track <= 0. {
+        return None;
+    }
+
+    let content = viewport + max_scrolled;
+    let length = (viewport / content * track).clamp(min_thumb.min(track), track);
+    let start = (track - length) * (scrolled / max_scrolled).clamp(0., 1.);
+
+    Some((start, length))
+}
+
+/// Returns the scrolled distance that centers the thumb on a track click.
+fn track_click_offset(position: f32, max_scrolled: f32, track: f32, thumb_length: f32) -> f32 {
+    let travel = track - thumb_length;
+    if travel <= 0. {
+        return 0.;
+    }
+
+    ((position - thumb_length / 2.) / travel).clamp(0., 1.) * max_scrolled
+}
+
+/// Returns the scrolled distance after dragging the thumb by `delta` pixels.
+fn dragged_offset(
+    scrolled_start: f32,
+    delta: f32,
+    max_scrolled: f32,
+    track: f32,
+    thumb_length: f32,
+) -> f32 {
+    let travel = track - thumb_length;
+    if travel <= 0. {
+        return scrolled_start;
+    }
+
+    (scrolled_start + delta * max_scrolled / travel).clamp(0., max_scrolled.max(0.))
+}
+
+fn set_scrolled(scroll_handle: &ScrollHandle, axis: Axis, scrolled: f32) {
+    let mut offset = scroll_handle.offset();
+
+    match axis {
+        Axis::Vertical => offset.y = px(-scrolled),
+        Axis::Horizontal => offset.x = px(-scrolled),
+    }
+
+    scroll_handle.set_offset(offset);
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+
+    #[gpui::test]
+    fn test_scroll_area_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let scroll_area = ScrollArea::new("test-scroll-area")
+                .axes(ScrollAxes::Both)
+                .auto_hide(false)
+                .shadows(false)
+                .max_h(px(200.))
+                .child(div())
+                .child(div());
+
+            assert_eq!(scroll_area.axes, ScrollAxes::Both);
+            assert!(!scroll_area.auto_hide, "Scrollbars should always show");
+            assert!(!scroll_area.shadows, "Shadows should be disabled");
+            assert!(scroll_area.max_height.is_some());
+            assert_eq!(scroll_area.children.len(), 2);
+        });
+    }
+
+    #[test]
+    fn test_thumb_metrics() {
+        // Content twice the viewport gives a thumb half the track.
+        assert_eq!(thumb_metrics(100., 100., 0., 100., 20.), Some((0., 50.)));
+        assert_eq!(thumb_metrics(100., 100., 100., 100., 20.), Some((50., 50.)));
+        assert_eq!(thumb_metrics(100., 100., 50., 100., 20.), Some((25., 50.)));
+
+        // Very long content is clamped to the minimum thumb length.
+        assert_eq!(thumb_metrics(100., 9900., 0., 100., 20.), Some((0., 20.)));
+
+        assert_eq!(thumb_metrics(100., 0., 0., 100., 20.), None);
+        assert_eq!(thumb_metrics(100., 100., 0., 0., 20.), None);
+    }
+
+    #[test]
+    fn test_track_click_offset() {
+        assert_eq!(track_click_offset(50., 100., 100., 50.), 50.);
+        assert_eq!(track_click_offset(0., 100., 100., 50.), 0.);
+        assert_eq!(track_click_offset(100., 100., 100., 50.), 100.);
+        assert_eq!(track_click_offset(50., 100., 50., 50.), 0.);
+    }
+
+    #[test]
+    fn test_dragged_offset() {
+        assert_eq!(dragged_offset(0., 25., 100., 100., 50.), 50.);
+        assert_eq!(dragged_offset(50., -100., 100., 100., 50.), 0.);
+        assert_eq!(dragged_offset(50., 100., 100., 100., 50.), 100.);
+        assert_eq!(dragged_offset(30., 10., 100., 50., 50.), 30.);
+    }
+}
@@ -0,0 +1,536 @@
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    App, Bounds, Edges, ElementId, IntoElement, Length, ParentElement, Pixels, RenderOnce,
+    SharedString, Styled, Window, canvas, div, ease_out_quint, prelude::FluentBuilder, px,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    ElementIdExt,
+    components::{Toggle, ToggleVariant},
+    conitional_transition,
+    extensions::{
+        mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+        mouse_handleable::MouseHandleable,
+    },
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{SquircleExt, disabled_transition},
+};
+
+/// A single segment within a [`SegmentedControl`].
+pub struct Segment {
+    value: SharedString,
+    text: Option<SharedString>,
+    icon: Option<SharedString>,
+    disabled: bool,
+}
+
+impl Segment {
+    /// Creates a new segment identified by `value`.
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            text: None,
+            icon: None,
+            disabled: false,
+        }
+    }
+
+    /// Sets the segment's text label.
+    pub fn text(mut self, text: impl Into<SharedString>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Sets an icon to display in the segment. Leave the text unset for an icon-only segment.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the disabled state, preventing this segment from being selected.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// A row of toggles sharing one rounded container, with an indicator that
+/// slides to the selected segment.
+#[derive(IntoElement)]
+pub struct SegmentedControl {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    variant: ToggleVariant,
+    segments: Vec<Segment>,
+    selected: Vec<SharedString>,
+    multiple: bool,
+    disabled: bool,
+    on_change: Option<Rc<dyn Fn(&[SharedString], &mut Window, &mut App) + 'static>>,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+    width: Length,
+}
+
+impl SegmentedControl {
+    /// Creates a new segmented control with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            variant: ToggleVariant::Primary,
+            segments: Vec::new(),
+            selected: Vec::new(),
+            multiple: false,
+            disabled: false,
+            on_change: None,
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+            width: Length::Auto,
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets a fixed width. Segments share the available space evenly.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the toggle variant used for the segments and the selected indicator.
+    pub fn variant(mut self, variant: ToggleVariant) -> Self {
+        self.variant = variant;
+        self
+    }
+
+    /// Adds a segment to the control.
+    pub fn segment(mut self, segment: Segment) -> Self {
+        self.segments.push(segment);
+        self
+    }
+
+    /// Adds multiple segments to the control.
+    pub fn segments(mut self, segments: impl IntoIterator<Item = Segment>) -> Self {
+        self.segments.extend(segments);
+        self
+    }
+
+    /// Sets the values of the selected segments.
+    pub fn selected(mut self, selected: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.selected = selected.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Allows any number of segments to be selected at once.
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// Sets the disabled state of every segment.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets a callback invoked with the new selection when a segment is clicked.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&[SharedString], &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+}
+
+impl MouseBehaviorExt for SegmentedControl {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for SegmentedControl {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let corner_radius = cx.get_theme().layout.corner_radii.md;
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let indicator_variant = self.variant.as_granular_toggle_on(layer, cx).truthy;
+
+        let segment_count = self.segments.len();
+        let values: Rc<[SharedString]> = self.segments.iter().map(|s| s.value.clone()).collect();
+        let selected: Rc<[SharedString]> = if self.multiple {
+            self.selected.into()
+        } else {
+            self.selected.into_iter().take(1).collect()
+        };
+
+        // Bounds of every segment, captured during prepaint to position the indicator.
+        let segment_bounds_state = window.use_keyed_state(
+            self.id.with_suffix("state:segment_bounds"),
+            cx,
+            |_window, _cx| Vec::<Bounds<Pixels>>::new(),
+        );
+        segment_bounds_state.update(cx, |this, _cx| this.truncate(segment_count));
+
+        let disabled_transition = disabled_transition(self.id.clone(), window, cx, self.disabled);
+
+        // Only a single selection can be shown by one sliding indicator.
+        let indicator = if self.multiple {
+            None
+        } else {
+            selected
+                .first()
+                .and_then(|selected| values.iter().position(|value| value == selected))
+                .and_then(|idx| {
+                    let bounds = segment_bounds_state.read(cx);
+                    let first = bounds.first()?;
+                    let segment = bounds.get(idx)?;
+
+                    (segment.size.width > px(0.)).then(|| {
+                        let (left_radius, right_radius) =
+                            segment_corner_radii(idx, segment_count, corner_radius);
+
+                        (
+                            segment.origin.x - first.origin.x,
+                            segment.size.width,
+                            left_radius,
+                            right_radius,
+                        )
+                    })
+                })
+        };
+
+        // The transitions are only created once the segments have been measured,
+        // so the indicator starts on the selected segment rather than sliding in.
+        let indicator = indicator.map(|(left, width, left_radius, right_radius)| {
+            let mut transition = |suffix: &str, value: Pixels| {
+                *conitional_transition!(
+                    self.id
+                        .with_suffix("state:transition:indicator")
+                        .with_suffix(suffix),
+                    window,
+                    cx,
+                    Duration::from_millis(285),
+                    value
+                )
+                .with_easing(ease_out_quint())
+                .evaluate(window, cx)
+            };
+
+            (
+                transition("left", left),
+                transition("width", width),
+                transition("left_radius", left_radius),
+                transition("right_radius", right_radius),
+            )
+        });
+        let has_indicator = indicator.is_some();
+
+        let on_change = self.on_change;
+        let multiple = self.multiple;
+        let behavior = self.mouse_behavior;
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .flex()
+            .w(self.width)
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .opacity(*disabled_transition.evaluate(window, cx))
+            .child(
+                squircle()
+                    .absolute_expand()
+                    .rounded(corner_radius)
+                    .bg(background_color)
+                    .border(px(1.))
+                    .border_inside()
+                    .border_color(border_color),
+            )
+            .when_some(
+                indicator,
+                |this, (left, width, left_radius, right_radius)| {
+                    this.child(
+                        div()
+                            .absolute()
+                            .top_0()
+                            .bottom_0()
+                            .left(left)
+                            .w(width)
+                            .child(
+                                squircle()
+                                    .absolute_expand()
+                                    .rounded_tl(left_radius)
+                                    .rounded_bl(left_radius)
+                                    .rounded_tr(right_radius)
+                                    .rounded_br(right_radius)
+                                    .bg(indicator_variant.bg_color)
+                                    .border(px(1.))
+                                    .border_inside()
+                                    .border_highlight(indicator_variant.highlight_alpha),
+                            ),
+                    )
+                },
+            )
+            .children(self.segments.into_iter().enumerate().map(|(idx, segment)| {
+                let is_selected = selected.contains(&segment.value);
+                let (left_radius, right_radius) =
+                    segment_corner_radii(idx, segment_count, corner_radius);
+
+                let mut toggle =
+                    Toggle::new(self.id.with_suffix(format!("segment:{}", segment.value)))
+                        .variant(self.variant)
+                        // The indicator draws the selected background once it's positioned.
+                        .checked(is_selected && !has_indicator)
+                        .disabled(self.disabled || segment.disabled)
+                        .w_full()
+                        .rounded_tl(left_radius)
+                        .rounded_bl(left_radius)
+                        .rounded_tr(right_radius)
+                        .rounded_br(right_radius);
+
+                if let Some(icon) = segment.icon {
+                    toggle = toggle.icon(icon);
+                }
+
+                if let Some(text) = segment.text {
+                    toggle = toggle.text(text);
+                }
+
+                let values = values.clone();
+                let selected = selected.clone();
+                let on_change = on_change.clone();
+                let value = segment.value;
+
+                *toggle.mouse_behavior_mut() = behavior;
+
+                let toggle = toggle.on_click(move |_event, window, cx| {
+                    let new_selection = next_selection(&values, &selected, &value, multiple);
+
+                    if *new_selection == *selected {
+                        return;
+                    }
+
+                    if let Some(on_change) = on_change.as_ref() {
+                        (on_change)(&new_selection, window, cx);
+                    }
+                });
+
+                let segment_bounds_state = segment_bounds_state.clone();
+
+                div()
+                    .relative()
+                    .flex_1()
+                    .child(
+                        canvas(
+                            move |bounds, _window, cx| {
+                                segment_bounds_state.update(cx, |this, cx| {
+                                    if this.len() <= idx {
+                                        this.resize(idx + 1, Bounds::default());
+                                    }
+
+                                    if this[idx] != bounds {
+                                        this[idx] = bounds;
+                                        cx.notify();
+                                    }
+                                });
+                            },
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full(),
+                    )
+                    .child(toggle)
+            }))
+    }
+}
+
+/// Returns the left and right corner radii for a segment, rounding only the
+/// outer corners of the first and last segments.
+fn segment_corner_radii(idx: usize, len: usize, corner_radius: Pixels) -> (Pixels, Pixels) {
+    let left = if idx == 0 { corner_radius } else { px(0.) };
+    let right = if idx + 1 == len {
+        corner_radius
+    } else {
+        px(0.)
+    };
+
+    (left, right)
+}
+
+/// Returns the selection after clicking the segment with `value`, keeping
+/// segment order in multi-selection mode.
+fn next_selection(
+    values: &[SharedString],
+    selected: &[SharedString],
+    value: &SharedString,
+    multiple: bool,
+) -> Vec<SharedString> {
+    if !multiple {
+        return vec![value.clone()];
+    }
+
+    let select = !selected.contains(value);
+
+    values
+        .iter()
+        .filter(|candidate| {
+            if *candidate == value {
+                select
+            } else {
+                selected.contains(candidate)
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::{AppContext, TestAppContext, VisualTestContext};
+
+    fn values(values: &[&'static str]) -> Vec<SharedString> {
+        values
+            .iter()
+            .map(|value| SharedString::from(*value))
+            .collect()
+    }
+
+    #[gpui::test]
+    fn test_segmented_control_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let control = SegmentedControl::new("test-segmented-control")
+                .segment(Segment::new("list").icon("list.svg"))
+                .segments([
+                    Segment::new("grid").text("Grid"),
+                    Segment::new("gallery").disabled(true),
+                ])
+                .selected(["grid"])
+                .multiple(true);
+
+            assert_eq!(control.segments.len(), 3);
+            assert!(
+                control.segments[0].text.is_none(),
+                "Segment should be icon-only"
+            );
+            assert!(control.segments[2].disabled, "Segment should be disabled");
+            assert_eq!(control.selected, values(&["grid"]));
+            assert!(control.multiple, "Control should allow multiple selection");
+        });
+    }
+
+    #[test]
+    fn test_single_selection_replaces_value() {
+        let all = values(&["a", "b", "c"]);
+        assert_eq!(
+            next_selection(&all, &values(&["a"]), &"c".into(), false),
+            values(&["c"])
+        );
+        assert_eq!(
+            next_selection(&all, &values(&["a"]), &"a".into(), false),
+            values(&["a"])
+        );
+    }
+
+    #[test]
+    fn test_multiple_selection_toggles_value_in_order() {
+        let all = values(&["a", "b", "c"]);
+        assert_eq!(
+            next_selection(&all, &values(&["c"]), &"a".into(), true),
+            values(&["a", "c"])
+        );
+        assert_eq!(
+            next_selection(&all, &values(&["a", "c"]), &"a".into(), true),
+            values(&["c"])
+        );
+    }
+
+    #[test]
+    fn test_segment_corner_radii() {
+        let radius = px(8.);
+        assert_eq!(segment_corner_radii(0, 3, radius), (radius, px(0.)));
+        assert_eq!(segment_corner_radii(1, 3, radius), (px(0.), px(0.)));
+        assert_eq!(segment_corner_radii(2, 3, radius), (px(0.), radius));
+        assert_eq!(segment_corner_radii(0, 1, radius), (radius, radius));
+    }
+
+    #[gpui::test]
+    fn test_segmented_control_renders_in_window(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |_window, cx| {
+                cx.new(|_cx| SegmentedControlTestView {
+                    selected: values(&["b"]),
+                })
+            })
+            .unwrap()
+        });
+
+        let _cx = VisualTestContext::from_window(window.into(), cx);
+    }
+
+    struct SegmentedControlTestView {
+        selected: Vec<SharedString>,
+    }
+
+    impl gpui::Render for SegmentedControlTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            _cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            SegmentedControl::new("test-segmented-control")
+                .segments([
+                    Segment::new("a").text("A"),
+                    Segment::new("b").text("B"),
+                    Segment::new("c").text("C"),
+                ])
+                .selected(self.selected.clone())
+        }
+    }
+}
@@ -1,4 +1,11 @@
-use gpui::{App, FocusHandle, Hsla, IntoElement, SharedString, Window};
+use std::cell::OnceCell;
+
+use gpui::{
+    AnyElement, App, FocusHandle, Hsla, IntoElement, ParentElement, SharedString, Styled, Window,
+    div, prelude::FluentBuilder,
+};
+
+use crate::{components::Icon, theme::ThemeExt};
 
 /// Defines how an item in a select menu is identified, accessed, and displayed.
 pub trait SelectItem {
@@ -19,6 +26,56 @@ pub trait SelectItem {
     fn display(&self, window: &mut Window, cx: &App, text_color: Hsla) -> impl IntoElement {
         self.name().into_any_element()
     }
+
+    /// Returns the group this item is listed under, shown as a header in the menu.
+    ///
+    /// Items of the same group are kept together, and groups are separated by a line.
+    fn group(&self) -> Option<SharedString> {
+        None
+    }
+
+    /// Returns true if this item is shown but can't be highlighted or selected.
+    fn disabled(&self) -> bool {
+        false
+    }
+
+    /// Returns the path of an SVG icon shown before the item, such as a provider logo.
+    fn icon(&self) -> Option<SharedString> {
+        None
+    }
+
+    /// Returns a secondary line shown below the item in the select menu.
+    fn description(&self) -> Option<SharedString> {
+        None
+    }
+
+    /// Renders an element shown at the end of the item's row in the select menu,
+    /// such as a shortcut or a badge.
+    #[allow(unused)]
+    fn trailing(&self, window: &mut Window, cx: &App) -> Option<AnyElement> {
+        None
+    }
+
+    /// Renders the item in the select's trigger while it's selected.
+    ///
+    /// Defaults to the icon followed by [`SelectItem::display`], leaving out the
+    /// description and trailing element so the trigger stays compact.
+    fn display_selected(
+        &self,
+        window: &mut Window,
+        cx: &App,
+        text_color: Hsla,
+    ) -> impl IntoElement {
+        div()
+            .min_w_0()
+            .flex()
+            .items_center()
+            .gap(cx.get_theme().layout.padding.md)
+            .when_some(self.icon(), |this, icon| {
+                this.child(Icon::new(icon).flex_shrink_0().color(text_color))
+            })
+            .child(self.display(window, cx, text_color))
+    }
 }
 
 /// Wrapper that holds a SelectItem along with its focus handle.
@@ -26,18 +83,29 @@ pub trait SelectItem {
 pub struct SelectItemEntry<I: SelectItem> {
     /// The wrapped item.
     pub item: I,
-    /// Focus handle for keyboard navigation within the menu.
-    pub focus_handle: FocusHandle,
+    /// Focus handle for keyboard navigation within the menu, created the first
+    /// time the item is rendered or highlighted so large lists stay cheap.
+    focus_handle: OnceCell<FocusHandle>,
 }
 
 impl<I: SelectItem> SelectItemEntry<I> {
-    /// Creates a new entry wrapping the given item with a fresh focus handle.
-    pub fn new(item: I, cx: &mut App) -> Self {
+    /// Creates a new entry wrapping the given item.
+    pub fn new(item: I) -> Self {
         Self {
             item,
-            focus_handle: cx.focus_handle(),
+            focus_handle: OnceCell::new(),
         }
     }
+
+    /// Returns the focus handle used for keyboard navigation, creating it on first use.
+    pub fn focus_handle(&self, cx: &App) -> &FocusHandle {
+        self.focus_handle.get_or_init(|| cx.focus_handle())
+    }
+
+    /// Returns the focus handle if it has been created.
+    pub(crate) fn created_focus_handle(&self) -> Option<&FocusHandle> {
+        self.focus_handle.get()
+    }
 }
 
 impl SelectItem for &'static str {
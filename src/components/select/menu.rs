@@ -1,33 +1,167 @@
-use std::sync::Arc;
+use std::{rc::Rc, sync::Arc};
 
 use gpui::{
-    ElementId, Entity, FocusHandle, InteractiveElement, Length, Overflow, ParentElement,
-    PointRefinement, SharedString, Styled, Subscription, WeakFocusHandle, Window, div, prelude::*,
-    px, relative,
+    AbsoluteLength, AnyElement, App, Bounds, DefiniteLength, Div, ElementId, Entity, FocusHandle,
+    FontWeight, HighlightStyle, Hsla, InteractiveElement, Length, ParentElement, Pixels,
+    SharedString, Styled, StyledText, Subscription, WeakFocusHandle, Window, canvas, div,
+    prelude::*, px, relative,
 };
 use gpui_squircle::{SquircleStyled, squircle};
 
 use crate::{
-    ElementIdExt, PositionalParentElement,
+    ElementIdExt, PositionalParentElement, TesseraeIconKind,
     components::{
-        Toggle, ToggleVariant,
-        select::{Confirm, MoveDown, MoveUp, SelectItem, SelectState},
+        Button, ButtonVariant, Icon, Input, Spinner, Toggle, ToggleVariant, VirtualList,
+        VirtualListState,
+        select::{
+            Confirm, ConfirmAndTab, Dismiss, MoveDown, MoveToFirst, MoveToLast, MoveUp, PageDown,
+            PageUp, SelectFilter, SelectItem, SelectItemMatch, SelectLoadStatus, SelectState,
+        },
     },
     extensions::{
         deferrable::{Deferrable, DeferredConfig},
         mouse_handleable::MouseHandleable,
     },
-    theme::{ThemeExt, ThemeLayerKind},
+    primitives::layer_scope,
+    theme::{ThemeExt, ThemeLayerKind, ThemeLayoutSizeKind},
     utils::PixelsExt,
-    views::Root,
+    views::RootWindowExt,
 };
 
+/// The height the list of items grows to before it scrolls, unless set with `max_h`.
+const DEFAULT_MAX_LIST_HEIGHT: Pixels = px(320.);
+
+/// Where a select menu opens relative to its trigger.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectMenuPlacement {
+    /// Below the trigger, unless it doesn't fit there and there's more room above.
+    #[default]
+    Auto,
+    /// Always below the trigger.
+    Below,
+    /// Always above the trigger.
+    Above,
+}
+
+impl SelectMenuPlacement {
+    /// Resolves where a menu `menu_height` tall opens, and how much room it has there.
+    ///
+    /// The room is the space between the trigger and the window edge, less `margin`.
+    pub(crate) fn resolve(
+        self,
+        trigger_bounds: Bounds<Pixels>,
+        window_height: Pixels,
+        menu_height: Pixels,
+        margin: Pixels,
+    ) -> (Self, Pixels) {
+        let room_below = (window_height - trigger_bounds.bottom() - margin).max(px(0.));
+        let room_above = (trigger_bounds.top() - margin).max(px(0.));
+
+        match self {
+            // A menu clamped to the room below takes all of it, so it doesn't fit either.
+            Self::Auto if menu_height >= room_below && room_above > room_below => {
+                (Self::Above, room_above)
+            }
+            Self::Auto | Self::Below => (Self::Below, room_below),
+            Self::Above => (Self::Above, room_above),
+        }
+    }
+}
+
+/// How wide a select menu is.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectMenuWidth {
+    /// As wide as the trigger.
+    #[default]
+    MatchTrigger,
+    /// As wide as its items, but at least as wide as the trigger.
+    Content,
+}
+
+/// A row of the menu's virtualized list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum SelectMenuRow {
+    /// The name of the group the following items belong to.
+    Header(SharedString),
+    /// The line between two groups.
+    Separator,
+    /// An item matching the search query.
+    Item(SelectItemMatch),
+    /// Shown after the items while the next page is loading.
+    LoadingMore,
+    /// Creates an item from the search query.
+    Create,
+}
+
+/// Everything the rows of a select menu are built from, so they're only
+/// rebuilt when one of these changes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct SelectMenuRowsKey {
+    pub(crate) query: SharedString,
+    pub(crate) items_revision: usize,
+    pub(crate) filter: Option<SelectFilter>,
+    pub(crate) loading: bool,
+    pub(crate) can_create_item: bool,
+}
+
+/// The virtualized list of a select menu, along with the rows it was laid out for.
+pub(crate) struct SelectMenuList {
+    pub(crate) list: VirtualListState,
+    rows: Vec<SelectMenuRow>,
+    /// What `rows` were built from, if they were built at all.
+    key: Option<SelectMenuRowsKey>,
+}
+
+impl Default for SelectMenuList {
+    fn default() -> Self {
+        Self {
+            list: VirtualListState::new(0),
+            rows: Vec::new(),
+            key: None,
+        }
+    }
+}
+
+impl SelectMenuList {
+    /// Returns the rows the list was last laid out for.
+    pub(crate) fn rows(&self) -> &[SelectMenuRow] {
+        &self.rows
+    }
+
+    /// Returns true if the rows were built from `key`.
+    pub(crate) fn is_built_from(&self, key: &SelectMenuRowsKey) -> bool {
+        self.key.as_ref() == Some(key)
+    }
+
+    /// Updates the list for new rows, only remeasuring the rows after the
+    /// first one that changed so appending a page keeps the scroll position.
+    pub(crate) fn sync(&mut self, rows: Vec<SelectMenuRow>, key: SelectMenuRowsKey) {
+        self.key = Some(key);
+
+        let unchanged = self
+            .rows
+            .iter()
+            .zip(&rows)
+            .take_while(|(old, new)| old == new)
+            .count();
+
+        if unchanged == self.rows.len() && unchanged == rows.len() {
+            return;
+        }
+
+        self.list
+            .splice(unchanged..self.rows.len(), rows.len() - unchanged);
+        self.rows = rows;
+    }
+}
+
 struct SelectMenuStyles {
     width: Length,
     min_width: Option<Length>,
     min_height: Option<Length>,
     max_width: Option<Length>,
     max_height: Option<Length>,
+    clamp_height: Option<Pixels>,
 }
 
 impl Default for SelectMenuStyles {
@@ -38,6 +172,7 @@ impl Default for SelectMenuStyles {
             min_height: None,
             max_width: None,
             max_height: None,
+            clamp_height: None,
         }
     }
 }
@@ -46,11 +181,12 @@ impl Default for SelectMenuStyles {
 #[derive(IntoElement)]
 pub struct SelectMenu<V: 'static, I: SelectItem<Value = V> + 'static> {
     id: ElementId,
-    layer: ThemeLayerKind,
+    layer: Option<ThemeLayerKind>,
     state: Arc<SelectState<V, I>>,
     focus_handle: Option<FocusHandle>,
     deferred_config: DeferredConfig,
     style: SelectMenuStyles,
+    no_results: Option<AnyElement>,
 }
 
 impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectMenu<V, I> {
@@ -58,11 +194,12 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectMenu<V, I> {
     pub fn new(id: impl Into<ElementId>, state: impl Into<Arc<SelectState<V, I>>>) -> Self {
         Self {
             id: id.into(),
-            layer: ThemeLayerKind::Tertiary,
+            layer: None,
             state: state.into(),
             focus_handle: None,
             deferred_config: DeferredConfig::default(),
             style: SelectMenuStyles::default(),
+            no_results: None,
         }
     }
 
@@ -138,27 +275,36 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectMenu<V, I> {
         self
     }
 
-    /// Sets the maximum height of the element. [Docs](https://tailwindcss.com/docs/max-height)
+    /// Sets the maximum height of the list of items, past which it scrolls.
+    /// Defaults to 320px.
     pub fn max_h(mut self, height: impl Into<Length>) -> Self {
         self.style.max_height = Some(height.into());
         self
     }
 
-    /// Sets the maximum height to 0. [Docs](https://tailwindcss.com/docs/max-height)
+    /// Sets the maximum height of the list of items to 0.
     pub fn max_h_0(mut self) -> Self {
         self.style.max_height = Some(px(0.).into());
         self
     }
 
-    /// Sets the maximum height to 100%. [Docs](https://tailwindcss.com/docs/max-height)
+    /// Sets the maximum height of the list of items to 100%.
     pub fn max_h_full(mut self) -> Self {
         self.style.max_height = Some(relative(100.).into());
         self
     }
 
+    /// Shrinks the list of items so the whole menu is at most this tall,
+    /// such as to keep it within the window.
+    pub fn clamp_h(mut self, height: Pixels) -> Self {
+        self.style.clamp_height = Some(height);
+        self
+    }
+
     /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
     pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
-        self.layer = layer;
+        self.layer = Some(layer);
         self
     }
 
@@ -167,6 +313,12 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectMenu<V, I> {
         self.focus_handle = Some(focus_handle);
         self
     }
+
+    /// Sets the element shown when no item matches the search query.
+    pub fn no_results(mut self, no_results: impl IntoElement) -> Self {
+        self.no_results = Some(no_results.into_any_element());
+        self
+    }
 }
 
 impl<V: 'static, I: SelectItem<Value = V> + 'static> Deferrable for SelectMenu<V, I> {
@@ -182,9 +334,11 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> Deferrable for SelectMenu<V
 }
 
 impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for SelectMenu<V, I> {
-    fn render(self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
-        let background_color = self.layer.resolve(cx);
-        let border_color = self.layer.next().resolve(cx);
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        let no_results = self.no_results.take();
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
         let corner_radius = cx.get_theme().layout.corner_radii.md;
         let line_height = cx.get_theme().layout.text.default_font.line_height;
         let text_size = cx.get_theme().layout.text.default_font.sizes.body.clone();
@@ -198,12 +352,32 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for SelectMenu<V
                 .padding_needed_for_height(window, text_size, line_height)
                 - padding;
 
+        let accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+        let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
+        let destructive_color = cx.get_theme().variants.active(cx).colors.accent.destructive;
+        let caption_size = cx
+            .get_theme()
+            .layout
+            .text
+            .default_font
+            .sizes
+            .caption
+            .clone();
+
+        let match_highlight = HighlightStyle {
+            color: Some(accent_color.into()),
+            font_weight: Some(FontWeight::SEMIBOLD),
+            ..Default::default()
+        };
+
         let menu_visible_transition = self.state.menu_visible_transition.clone();
         let menu_visible_delta = menu_visible_transition.evaluate(window, cx).value();
 
         let state_for_up = self.state.clone();
         let state_for_down = self.state.clone();
         let state_for_confirm = self.state.clone();
+        let state_for_type_ahead = self.state.clone();
+        let is_searchable = self.state.is_searchable();
 
         let focus_handle = self
             .focus_handle
@@ -233,14 +407,49 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for SelectMenu<V
             |_window, _cx| None,
         );
 
-        // Reset sync flag and hover state when menu is closed
+        // The query the highlight was last reset for, to notice when it changes
+        let last_query = window.use_keyed_state(
+            self.id.with_suffix("state:last_query"),
+            cx,
+            |_window, _cx| SharedString::default(),
+        );
+
+        // Reset sync flag and search query when menu is closed
         if menu_visible_delta == 0. && *has_synced.read(cx) {
             has_synced.update(cx, |synced, _cx| *synced = false);
+            self.state.clear_query(cx);
+        }
+
+        let query = self.state.query(cx);
+        if *last_query.read(cx) != query {
+            last_query.update(cx, |last_query, _cx| *last_query = query.clone());
+            self.state.highlight_first_match(cx);
         }
 
         let hovered_item_for_up = hovered_item.clone();
         let hovered_item_for_down = hovered_item.clone();
 
+        // Wraps a highlight movement for a key action, clearing the hover state
+        // so the keyboard highlight shows.
+        let on_move_highlight = |move_highlight: fn(&SelectState<V, I>, &mut Window, &mut App)| {
+            let state = self.state.clone();
+            let hovered_item = hovered_item.clone();
+
+            move |window: &mut Window, cx: &mut App| {
+                hovered_item.update(cx, |hovered, _cx| *hovered = None);
+                move_highlight(&state, window, cx);
+            }
+        };
+        let move_to_first = on_move_highlight(SelectState::move_highlight_to_first);
+        let move_to_last = on_move_highlight(SelectState::move_highlight_to_last);
+        let page_up = on_move_highlight(SelectState::move_highlight_page_up);
+        let page_down = on_move_highlight(SelectState::move_highlight_page_down);
+
+        let state_for_tab = self.state.clone();
+        let state_for_dismiss = self.state.clone();
+        let focus_handle_for_tab = focus_handle.clone();
+        let focus_handle_for_dismiss = focus_handle.clone();
+
         div()
             .id(self.id.clone())
             .key_context("SelectMenu")
@@ -259,37 +468,78 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for SelectMenu<V
             })
             .on_action(move |_: &Confirm, window, cx| {
                 state_for_confirm.confirm_highlight(window, cx);
-                window.blur();
+
+                if !state_for_confirm.is_multiple() {
+                    window.blur();
+                }
+            })
+            .on_action(move |_: &MoveToFirst, window, cx| move_to_first(window, cx))
+            .on_action(move |_: &MoveToLast, window, cx| move_to_last(window, cx))
+            .on_action(move |_: &PageUp, window, cx| page_up(window, cx))
+            .on_action(move |_: &PageDown, window, cx| page_down(window, cx))
+            .on_action(move |_: &ConfirmAndTab, window, cx| {
+                state_for_tab.confirm_highlight(window, cx);
+                state_for_tab.hide_menu(cx);
+
+                // Move on from the select itself, as focus may be in the search input.
+                focus_handle_for_tab.focus(window, cx);
+                window.focus_next(cx);
+            })
+            .on_action(move |_: &Dismiss, window, cx| {
+                state_for_dismiss.dismiss_menu(cx);
+                focus_handle_for_dismiss.focus(window, cx);
+            })
+            .when(!is_searchable, |this| {
+                // Jump to items by typing the start of their name.
+                this.on_key_down(move |event, window, cx| {
+                    let modifiers = &event.keystroke.modifiers;
+                    if modifiers.control || modifiers.platform || modifiers.alt {
+                        return;
+                    }
+
+                    let Some(key_char) = event.keystroke.key_char.as_ref() else {
+                        return;
+                    };
+
+                    for ch in key_char.chars().filter(|ch| !ch.is_control()) {
+                        state_for_type_ahead.type_ahead(ch, window, cx);
+                    }
+                })
             })
             .when(menu_visible_delta != 0., |this| {
                 // We only want the click event if the menu
                 // is transitioning towards the visible state.
                 if menu_visible_transition.read_goal(cx) == &true.into() {
-                    let root = window
-                        .root::<Root>()
-                        .flatten()
-                        .expect("Expected gpui_tesserae::Root to be the root view!");
+                    let state = self.state.clone();
 
-                    root.update(cx, |root, cx| {
-                        let state = self.state.clone();
-
-                        root.on_any_mouse_down(move |_event, window, cx| {
-                            if !state.any_select_focused(window, cx) {
-                                state.hide_menu(cx);
-                            }
-                        });
-
-                        cx.notify();
+                    window.on_root_mouse_down(cx, move |_event, window, cx| {
+                        if !state.any_select_focused(window, cx) {
+                            state.hide_menu(cx);
+                        }
                     });
                 }
 
                 // Only sync highlight to selection once when menu first opens
                 if !*has_synced.read(cx) {
                     self.state.sync_highlight_to_selection(cx);
-                    focus_handle.focus(window, cx);
+
+                    if is_searchable {
+                        self.state.query.read(cx).focus_handle.focus(window, cx);
+                    } else {
+                        focus_handle.focus(window, cx);
+                    }
+
                     has_synced.update(cx, |synced, _cx| *synced = true);
                 }
 
+                self.state.sync_item_source(cx);
+
+                let rows = self.state.menu_rows(cx);
+                let has_items = rows.iter().any(|row| matches!(row, SelectMenuRow::Item(_)));
+                let can_create_item = rows.last() == Some(&SelectMenuRow::Create);
+                let load_status = self.state.load_status(cx);
+                let list_state = self.state.menu_list.read(cx).list.clone();
+
                 let state = self.state.clone();
 
                 // Manage focus subscriptions via entities that update incrementally
@@ -300,16 +550,68 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for SelectMenu<V
                 );
                 item_focus_subs.update(cx, |subs, cx| subs.sync(&state, window, cx));
 
+                let row_renderer = Rc::new(SelectMenuRows {
+                    id: self.id.clone(),
+                    state: self.state.clone(),
+                    rows,
+                    hovered_item: hovered_item.clone(),
+                    query,
+                    padding,
+                    horizontal_padding,
+                    vertical_padding,
+                    corner_radius,
+                    border_color,
+                    secondary_text_color,
+                    caption_size,
+                    match_highlight,
+                });
+
+                // The height of the menu and of its list, to know how much of the
+                // menu is taken by the search input and padding.
+                let menu_height = window.use_keyed_state(
+                    self.id.with_suffix("state:menu_height"),
+                    cx,
+                    |_window, _cx| px(0.),
+                );
+                let list_height = window.use_keyed_state(
+                    self.id.with_suffix("state:list_height"),
+                    cx,
+                    |_window, _cx| px(0.),
+                );
+                if list_state.item_count() == 0 {
+                    list_height.update(cx, |this, _cx| *this = px(0.));
+                }
+
+                let max_list_height = match self.style.max_height {
+                    Some(Length::Definite(DefiniteLength::Absolute(max_height))) => {
+                        max_height.to_pixels(window.rem_size())
+                    }
+                    Some(Length::Definite(DefiniteLength::Fraction(frac))) => {
+                        window.viewport_size().height * frac
+                    }
+                    _ => DEFAULT_MAX_LIST_HEIGHT,
+                };
+                let max_list_height = match self.style.clamp_height {
+                    Some(clamp_height) => {
+                        let chrome_height =
+                            (*menu_height.read(cx) - *list_height.read(cx)).max(px(0.));
+                        max_list_height
+                            .min(clamp_height - chrome_height)
+                            .max(px(0.))
+                    }
+                    None => max_list_height,
+                };
+
                 this.opacity(menu_visible_delta)
                     .w(self.style.width)
                     .when_some(self.style.min_width, |this, v| this.min_w(v))
                     .when_some(self.style.min_height, |this, v| this.min_h(v))
                     .when_some(self.style.max_width, |this, v| this.max_w(v))
-                    .when_some(self.style.max_height, |this, v| this.max_h(v))
                     .flex()
                     .flex_col()
                     .gap(px(1.))
                     .p(padding)
+                    .child(measure_height(menu_height))
                     .child(
                         squircle()
                             .absolute_expand()
@@ -319,106 +621,355 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for SelectMenu<V
                             .border(px(1.))
                             .border_inside(),
                     )
-                    .child(
+                    .when(is_searchable, |this| {
+                        this.child(
+                            Input::new(self.id.with_suffix("query"), self.state.query.clone())
+                                .w_full()
+                                .layer(layer)
+                                .rounded(corner_radius - padding)
+                                .placeholder("Search..."),
+                        )
+                    })
+                    .map(|this| match &load_status {
+                        SelectLoadStatus::Failed(error) => {
+                            let state = self.state.clone();
+
+                            this.child(
+                                row_renderer
+                                    .status_row()
+                                    .justify_between()
+                                    .child(
+                                        div()
+                                            .min_w_0()
+                                            .text_color(destructive_color)
+                                            .child(error.clone()),
+                                    )
+                                    .child(
+                                        Button::new(self.id.with_suffix("retry"))
+                                            .variant(ButtonVariant::TertiaryGhost)
+                                            .text("Retry")
+                                            .on_click(move |_event, _window, cx| {
+                                                state.reload(cx);
+                                            }),
+                                    ),
+                            )
+                        }
+                        SelectLoadStatus::Loading if !has_items => {
+                            this.child(row_renderer.loading_row())
+                        }
+                        _ if !has_items && !can_create_item => {
+                            this.child(no_results.unwrap_or_else(|| {
+                                row_renderer
+                                    .status_row()
+                                    .child("No results")
+                                    .into_any_element()
+                            }))
+                        }
+                        _ => this,
+                    })
+                    .when(list_state.item_count() > 0, |this| {
+                        // Only the rows in view are rendered, so long lists stay cheap.
+                        this.child(
+                            div()
+                                .relative()
+                                .w_full()
+                                .child(measure_height(list_height))
+                                .child(
+                                    VirtualList::new(
+                                        self.id.with_suffix("menu_children"),
+                                        &list_state,
+                                        move |index, window, cx| {
+                                            row_renderer.render(index, window, cx)
+                                        },
+                                    )
+                                    .h_auto()
+                                    .max_h(max_list_height),
+                                ),
+                        )
+                    })
+            })
+            // The deferred content is drawn after any enclosing surface, so the
+            // layer resolved here is provided to it again.
+            .map(|this| self.apply_deferred(layer_scope(layer, this)))
+    }
+}
+
+/// Stores the height of the parent element whenever it's laid out.
+pub(crate) fn measure_height(height: Entity<Pixels>) -> impl IntoElement {
+    canvas(
+        move |bounds, _window, cx| {
+            height.update(cx, |this, cx| {
+                if *this != bounds.size.height {
+                    *this = bounds.size.height;
+                    cx.notify();
+                }
+            });
+        },
+        |_, _, _, _| {},
+    )
+    .absolute()
+    .size_full()
+}
+
+/// Renders the rows of a menu's virtualized list as they scroll into view.
+struct SelectMenuRows<V: 'static, I: SelectItem<Value = V> + 'static> {
+    id: ElementId,
+    state: Arc<SelectState<V, I>>,
+    rows: Vec<SelectMenuRow>,
+    hovered_item: Entity<Option<SharedString>>,
+    query: SharedString,
+    padding: Pixels,
+    horizontal_padding: Pixels,
+    vertical_padding: Pixels,
+    corner_radius: Pixels,
+    border_color: Hsla,
+    secondary_text_color: Hsla,
+    caption_size: AbsoluteLength,
+    match_highlight: HighlightStyle,
+}
+
+impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectMenuRows<V, I> {
+    fn status_row(&self) -> Div {
+        div()
+            .w_full()
+            .flex()
+            .items_center()
+            .gap(self.horizontal_padding)
+            .pl(self.horizontal_padding)
+            .pr(self.horizontal_padding)
+            .pt(self.vertical_padding)
+            .pb(self.vertical_padding)
+            .text_color(self.secondary_text_color)
+    }
+
+    fn loading_row(&self) -> Div {
+        self.status_row()
+            .child(
+                Spinner::new(self.id.with_suffix("spinner"))
+                    .size(ThemeLayoutSizeKind::Sm)
+                    .color(self.secondary_text_color),
+            )
+            .child("Loading...")
+    }
+
+    fn render(&self, index: usize, window: &mut Window, cx: &mut App) -> AnyElement {
+        let Some(row) = self.rows.get(index) else {
+            return div().into_any_element();
+        };
+
+        match row {
+            SelectMenuRow::Header(group) => div()
+                .w_full()
+                .pl(self.horizontal_padding)
+                .pr(self.horizontal_padding)
+                .pt(self.padding)
+                .pb(self.padding)
+                .text_size(self.caption_size)
+                .text_color(self.secondary_text_color)
+                .child(group.clone())
+                .into_any_element(),
+            SelectMenuRow::Separator => div()
+                .w_full()
+                .py(self.padding)
+                .child(div().w_full().h(px(1.)).bg(self.border_color))
+                .into_any_element(),
+            SelectMenuRow::Item(item_match) => {
+                // Load the next page once the last item scrolls into view.
+                if !self.rows[index + 1..]
+                    .iter()
+                    .any(|row| matches!(row, SelectMenuRow::Item(_)))
+                {
+                    self.state.load_more(cx);
+                }
+
+                self.render_item(item_match, window, cx)
+            }
+            SelectMenuRow::LoadingMore => self.loading_row().into_any_element(),
+            SelectMenuRow::Create => self.render_create(cx),
+        }
+    }
+
+    fn render_item(
+        &self,
+        item_match: &SelectItemMatch,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> AnyElement {
+        let item_name = &item_match.name;
+        let Some(entry) = self.state.items.read(cx).get(item_name) else {
+            return div().into_any_element();
+        };
+
+        let is_disabled = entry.item.disabled();
+        let icon = entry.item.icon();
+        let description = entry.item.description();
+        let trailing = entry.item.trailing(window, cx);
+
+        let highlighted_item = self.state.highlighted_item.read(cx).as_ref();
+        let hovered_item_exists = self.hovered_item.read(cx).is_some();
+
+        let selected = self.state.is_item_selected(cx, item_name);
+        let is_multiple = self.state.is_multiple();
+        let is_limited = !selected && self.state.is_limit_reached(cx);
+
+        let show_highlight = !hovered_item_exists && highlighted_item == Some(item_name);
+
+        let hovered_item_for_hover = self.hovered_item.clone();
+        let item_name_for_hover = item_name.clone();
+
+        let variant = if selected {
+            ToggleVariant::Secondary
+        } else {
+            ToggleVariant::Tertiary
+        };
+
+        let variant_granular = if selected {
+            ToggleVariant::Secondary.as_granular_toggle(cx).truthy
+        } else {
+            ToggleVariant::Tertiary.as_granular_toggle(cx).falsey
+        };
+
+        let display = if item_match.ranges.is_empty() {
+            entry
+                .item
+                .display(window, cx, variant_granular.text_color.into())
+                .into_any_element()
+        } else {
+            StyledText::new(item_name.clone())
+                .with_highlights(
+                    item_match
+                        .ranges
+                        .iter()
+                        .map(|range| (range.clone(), self.match_highlight)),
+                )
+                .into_any_element()
+        };
+
+        div()
+            .id(self.id.with_suffix("item_row").with_suffix(item_name))
+            .w_full()
+            .flex()
+            // Focus handles are only created for the rows that get rendered.
+            .when(!is_disabled, |this| {
+                this.track_focus(entry.focus_handle(cx))
+            })
+            .child(
+                Toggle::new(self.id.with_suffix("item").with_suffix(item_name))
+                    .w_full()
+                    .max_w(relative(1.))
+                    .checked(selected)
+                    .variant(variant)
+                    .force_hover(show_highlight)
+                    .disabled(is_limited || is_disabled)
+                    .justify_start()
+                    .rounded(self.corner_radius - self.padding)
+                    .when(is_multiple, |this| {
+                        this.child_left(div().size(px(12.)).flex_shrink_0().when(
+                            selected,
+                            |this| {
+                                this.child(
+                                    Icon::new(TesseraeIconKind::Checkmark)
+                                        .size(px(12.))
+                                        .color(variant_granular.text_color),
+                                )
+                            },
+                        ))
+                    })
+                    .child_left(
                         div()
-                            .id(self.id.with_suffix("menu_children"))
-                            .w_full()
-                            .h_full()
-                            .map(|mut this| {
-                                this.style().overflow = PointRefinement {
-                                    x: None,
-                                    y: Some(Overflow::Scroll),
-                                };
-                                this
-                            })
-                            .on_scroll_wheel(|_event, _window, cx| {
-                                cx.stop_propagation();
+                            .flex_1()
+                            .min_w_0()
+                            .flex()
+                            .items_center()
+                            .gap(self.horizontal_padding)
+                            .when_some(icon, |this, icon| {
+                                this.child(
+                                    Icon::new(icon)
+                                        .flex_shrink_0()
+                                        .color(variant_granular.text_color),
+                                )
                             })
-                            .children(state.items.read(cx).iter().map(|(item_name, entry)| {
-                                let highlighted_item =
-                                    self.state.highlighted_item.read(cx).as_ref();
-                                let hovered_item_exists = hovered_item.read(cx).is_some();
-
-                                let selected =
-                                    self.state.selected_item.read(cx).as_ref() == Some(item_name);
-
-                                let show_highlight =
-                                    !hovered_item_exists && highlighted_item == Some(item_name);
-
-                                let hovered_item_for_hover = hovered_item.clone();
-                                let item_name_for_hover = item_name.clone();
-
-                                let variant = if selected {
-                                    ToggleVariant::Secondary
-                                } else {
-                                    ToggleVariant::Tertiary
-                                };
-
-                                let variant_granular = if selected {
-                                    ToggleVariant::Secondary.as_granular_toggle(cx).truthy
-                                } else {
-                                    ToggleVariant::Tertiary.as_granular_toggle(cx).falsey
-                                };
-
-                                div()
-                                    .id(self.id.with_suffix("item_row").with_suffix(item_name))
-                                    .w_full()
-                                    .flex()
-                                    .track_focus(&entry.focus_handle)
-                                    .child(
-                                        Toggle::new(
-                                            self.id.with_suffix("item").with_suffix(item_name),
-                                        )
-                                        .w_full()
-                                        .max_w(relative(1.))
-                                        .checked(selected)
-                                        .variant(variant)
-                                        .force_hover(show_highlight)
-                                        .justify_start()
-                                        .rounded(corner_radius - padding)
-                                        .child_left(entry.item.display(
-                                            window,
-                                            cx,
-                                            variant_granular.text_color.into(),
-                                        ))
-                                        .pl(horizontal_padding)
-                                        .pr(horizontal_padding)
-                                        .pt(vertical_padding)
-                                        .pb(vertical_padding)
-                                        .on_any_mouse_down(|_event, window, _cx| {
-                                            window.prevent_default();
-                                        })
-                                        .on_hover(move |is_hovered, _window, cx| {
-                                            hovered_item_for_hover.update(cx, |this, cx| {
-                                                if *is_hovered {
-                                                    *this = Some(item_name_for_hover.clone());
-                                                } else if this.as_ref()
-                                                    == Some(&item_name_for_hover)
-                                                {
-                                                    *this = None;
-                                                }
-                                                cx.notify();
-                                            });
-                                        })
-                                        .map(|this| {
-                                            let state = self.state.clone();
-                                            let item_name = item_name.clone();
-
-                                            this.on_click(move |_event, window, cx| {
-                                                (state.on_item_click)(
-                                                    !selected,
-                                                    state.clone(),
-                                                    item_name.clone(),
-                                                    window,
-                                                    cx,
-                                                )
-                                            })
-                                        }),
+                            .child(div().min_w_0().flex().flex_col().child(display).when_some(
+                                description,
+                                |this, description| {
+                                    this.child(
+                                        div()
+                                            .text_size(self.caption_size)
+                                            .text_color(self.secondary_text_color)
+                                            .text_ellipsis()
+                                            .child(description),
                                     )
-                            })),
+                                },
+                            )),
                     )
+                    .when_some(trailing, |this, trailing| {
+                        this.child_right(
+                            div()
+                                .flex_none()
+                                .text_size(self.caption_size)
+                                .text_color(self.secondary_text_color)
+                                .child(trailing),
+                        )
+                    })
+                    .pl(self.horizontal_padding)
+                    .pr(self.horizontal_padding)
+                    .pt(self.vertical_padding)
+                    .pb(self.vertical_padding)
+                    .on_any_mouse_down(|_event, window, _cx| {
+                        window.prevent_default();
+                    })
+                    .on_hover(move |is_hovered, _window, cx| {
+                        hovered_item_for_hover.update(cx, |this, cx| {
+                            if *is_hovered {
+                                *this = Some(item_name_for_hover.clone());
+                            } else if this.as_ref() == Some(&item_name_for_hover) {
+                                *this = None;
+                            }
+                            cx.notify();
+                        });
+                    })
+                    .map(|this| {
+                        let state = self.state.clone();
+                        let item_name = item_name.clone();
+
+                        this.on_click(move |_event, window, cx| {
+                            (state.on_item_click)(
+                                !selected,
+                                state.clone(),
+                                item_name.clone(),
+                                window,
+                                cx,
+                            )
+                        })
+                    }),
+            )
+            .into_any_element()
+    }
+
+    fn render_create(&self, cx: &App) -> AnyElement {
+        let state = self.state.clone();
+        let show_highlight =
+            self.hovered_item.read(cx).is_none() && self.state.highlighted_item.read(cx).is_none();
+
+        Toggle::new(self.id.with_suffix("create_item"))
+            .w_full()
+            .max_w(relative(1.))
+            .variant(ToggleVariant::Tertiary)
+            .force_hover(show_highlight)
+            .justify_start()
+            .rounded(self.corner_radius - self.padding)
+            .text(format!("Create \"{}\"", self.query.trim()))
+            .pl(self.horizontal_padding)
+            .pr(self.horizontal_padding)
+            .pt(self.vertical_padding)
+            .pb(self.vertical_padding)
+            .on_any_mouse_down(|_event, window, _cx| {
+                window.prevent_default();
+            })
+            .on_click(move |_event, window, cx| {
+                state.create_item(window, cx);
             })
-            .map(|this| self.apply_deferred(this))
+            .into_any_element()
     }
 }
 
@@ -439,7 +990,7 @@ impl ItemFocusSubscriptions {
             .items
             .read(cx)
             .iter()
-            .map(|(_, entry)| entry.focus_handle.clone())
+            .filter_map(|(_, entry)| entry.created_focus_handle().cloned())
             .collect();
 
         // Remove subscriptions for focus handles that no longer exist
@@ -1,18 +1,21 @@
 use std::{sync::Arc, time::Duration};
 
 use gpui::{
-    App, Edges, ElementId, InteractiveElement, IntoElement, Length, MouseButton, ParentElement,
-    RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window, div, ease_out_quint,
-    prelude::FluentBuilder, px, radians, relative,
+    AnyElement, App, Bounds, Edges, ElementId, InteractiveElement, IntoElement, Length,
+    MouseButton, ParentElement, Pixels, RenderOnce, SharedString, StatefulInteractiveElement,
+    Styled, Window, canvas, div, ease_out_quint, prelude::FluentBuilder, px, radians, relative,
 };
 use gpui_squircle::{SquircleStyled, squircle};
 use gpui_transitions::Lerp;
 
 use crate::{
     ElementIdExt, TesseraeIconKind,
-    components::Icon,
+    components::{Chip, Icon},
     conitional_transition, conitional_transition_update,
-    extensions::mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+    extensions::{
+        deferrable::{Deferrable, DeferredConfig},
+        mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+    },
     primitives::FocusRing,
     theme::{ThemeExt, ThemeLayerKind},
     utils::{PixelsExt, disabled_transition},
@@ -26,7 +29,10 @@ struct SelectStyles {
     max_width: Option<Length>,
     max_height: Option<Length>,
     max_menu_height: Option<Length>,
+    menu_placement: SelectMenuPlacement,
+    menu_width: SelectMenuWidth,
     placeholder: Option<SharedString>,
+    selected_items_display: SelectedItemsDisplay,
 }
 
 impl Default for SelectStyles {
@@ -39,11 +45,24 @@ impl Default for SelectStyles {
             max_width: None,
             max_height: None,
             max_menu_height: None,
+            menu_placement: SelectMenuPlacement::default(),
+            menu_width: SelectMenuWidth::default(),
             placeholder: None,
+            selected_items_display: SelectedItemsDisplay::default(),
         }
     }
 }
 
+/// How a multi-select [`Select`] shows its selected items.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectedItemsDisplay {
+    /// A removable chip for each selected item.
+    #[default]
+    Chips,
+    /// A count of the selected items, such as "3 selected".
+    Count,
+}
+
 mod menu;
 pub use menu::*;
 
@@ -53,15 +72,20 @@ pub use item::{SelectItem, SelectItemEntry};
 mod state;
 pub use state::*;
 
+mod source;
+pub use source::*;
+
 /// A dropdown select component with keyboard navigation support.
 #[derive(IntoElement)]
 pub struct Select<V: 'static, I: SelectItem<Value = V> + 'static> {
     id: ElementId,
     disabled: bool,
-    layer: ThemeLayerKind,
+    layer: Option<ThemeLayerKind>,
     state: Arc<SelectState<V, I>>,
     mouse_behavior: MouseBehavior,
+    deferred_config: DeferredConfig,
     style: SelectStyles,
+    no_results: Option<AnyElement>,
 }
 
 impl<V: 'static, I: SelectItem<Value = V> + 'static> Select<V, I> {
@@ -72,16 +96,19 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> Select<V, I> {
         Self {
             id: id.into(),
             disabled: false,
-            layer: ThemeLayerKind::Tertiary,
+            layer: None,
             state: state.into(),
             mouse_behavior: MouseBehavior::default(),
+            deferred_config: DeferredConfig::default(),
             style: SelectStyles::default(),
+            no_results: None,
         }
     }
 
     /// Sets the layer of the select.
+    /// Defaults to the layer provided by the enclosing surface.
     pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
-        self.layer = layer;
+        self.layer = Some(layer);
         self
     }
 
@@ -91,6 +118,19 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> Select<V, I> {
         self
     }
 
+    /// Sets where the dropdown menu opens.
+    /// Defaults to below, flipping above when there's more room there.
+    pub fn menu_placement(mut self, placement: SelectMenuPlacement) -> Self {
+        self.style.menu_placement = placement;
+        self
+    }
+
+    /// Sets how wide the dropdown menu is. Defaults to the width of the select.
+    pub fn menu_width(mut self, width: SelectMenuWidth) -> Self {
+        self.style.menu_width = width;
+        self
+    }
+
     /// Sets a fixed width.
     pub fn w(mut self, width: impl Into<Length>) -> Self {
         self.style.width = width.into();
@@ -223,6 +263,18 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> Select<V, I> {
         self.style.placeholder = Some(placeholder.into());
         self
     }
+
+    /// Sets how the selected items are shown in multi-select mode. Defaults to chips.
+    pub fn selected_items_display(mut self, display: SelectedItemsDisplay) -> Self {
+        self.style.selected_items_display = display;
+        self
+    }
+
+    /// Sets the element shown in the menu when no item matches the search query.
+    pub fn no_results(mut self, no_results: impl IntoElement) -> Self {
+        self.no_results = Some(no_results.into_any_element());
+        self
+    }
 }
 
 impl<V: 'static, I: SelectItem<Value = V> + 'static> MouseBehaviorExt for Select<V, I> {
@@ -231,13 +283,27 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> MouseBehaviorExt for Select
     }
 }
 
+impl<V: 'static, I: SelectItem<Value = V> + 'static> Deferrable for Select<V, I> {
+    const DEFAULT_PRIORITY: usize = 1;
+
+    fn deferred_config_mut(&mut self) -> &mut DeferredConfig {
+        &mut self.deferred_config
+    }
+
+    fn deferred_config(&self) -> &DeferredConfig {
+        &self.deferred_config
+    }
+}
+
 impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I> {
-    fn render(self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+    fn render(mut self, window: &mut gpui::Window, cx: &mut gpui::App) -> impl IntoElement {
+        let no_results = self.no_results.take();
         let (primary_text_color, secondary_text_color) =
             cx.get_theme().variants.active(cx).colors.text.all();
         let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
-        let background_color = self.layer.resolve(cx);
-        let border_color = self.layer.next().resolve(cx);
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
         let border_hover_color = border_color.lerp(&primary_text_color, 0.07);
         let font_family = cx.get_theme().layout.text.default_font.family[0].clone();
         let line_height = cx.get_theme().layout.text.default_font.line_height;
@@ -271,7 +337,7 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
             .clone();
 
         // Register this Select's focus handle with the shared state
-        self.state.register_focus_handle(cx, focus_handle.clone());
+        self.state.register_focus_handle(cx, &focus_handle);
 
         // Use contains_focused instead of is_focused so that the menu stays open
         // when focus moves to a menu item (which is a descendant of the Select).
@@ -297,19 +363,53 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
         )
         .with_easing(ease_out_quint());
 
+        // A menu dismissed with Escape stays closed until the select loses focus
+        // or is opened again.
+        let any_select_focused = self.state.any_select_focused(window, cx);
+        if !any_select_focused && self.state.is_menu_dismissed(cx) {
+            self.state.menu.reset_dismissed(cx);
+        }
+
         let menu_visible_transition = conitional_transition_update!(
             cx,
             self
                 .state
                 .menu_visible_transition.clone(),
             {
-                self.state.any_select_focused(window, cx) => true,
+                any_select_focused && !self.state.is_menu_dismissed(cx) => true,
                 _ => false
             }
         );
 
         let menu_visible_delta = menu_visible_transition.evaluate(window, cx).value();
 
+        // Where the select is in the window and how tall its menu is, to open
+        // the menu where it fits.
+        let trigger_bounds = window.use_keyed_state(
+            self.id.with_suffix("state:trigger_bounds"),
+            cx,
+            |_window, _cx| None::<Bounds<Pixels>>,
+        );
+        let menu_height = window.use_keyed_state(
+            self.id.with_suffix("state:menu_height"),
+            cx,
+            |_window, _cx| px(0.),
+        );
+
+        let menu_gap = cx.get_theme().layout.padding.md;
+        let (menu_placement, menu_room) = match *trigger_bounds.read(cx) {
+            Some(bounds) => {
+                let (placement, room) = self.style.menu_placement.resolve(
+                    bounds,
+                    window.viewport_size().height,
+                    *menu_height.read(cx),
+                    menu_gap,
+                );
+                (placement, Some(room))
+            }
+            None => (SelectMenuPlacement::Below, None),
+        };
+
         div()
             .id(self.id.clone())
             .cursor(if is_disabled {
@@ -335,6 +435,21 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
             .flex()
             .flex_col()
             .opacity(*disabled_transition.evaluate(window, cx))
+            .child(
+                canvas(
+                    move |bounds, _window, cx| {
+                        trigger_bounds.update(cx, |this, cx| {
+                            if *this != Some(bounds) {
+                                *this = Some(bounds);
+                                cx.notify();
+                            }
+                        });
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
             .child(
                 FocusRing::new(self.id.with_suffix("focus_ring"), focus_handle.clone())
                     .rounded(corner_radius),
@@ -358,6 +473,59 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
                     .text_color(secondary_text_color)
                     .font_family(font_family.clone())
                     .map(|this| {
+                        let placeholder = self
+                            .style
+                            .placeholder
+                            .as_ref()
+                            .map(|p| p.clone())
+                            .unwrap_or_else(|| "No item selected".into());
+
+                        if self.state.is_multiple() {
+                            let items = self.state.items.read(cx);
+                            let selected: Vec<SharedString> = self
+                                .state
+                                .get_selected_item_names(cx)
+                                .into_iter()
+                                .filter(|name| items.get(name).is_some())
+                                .collect();
+
+                            if selected.is_empty() {
+                                return this.child(placeholder);
+                            }
+
+                            return match self.style.selected_items_display {
+                                SelectedItemsDisplay::Count => this.child(
+                                    div()
+                                        .w_full()
+                                        .text_color(primary_text_color)
+                                        .child(format!("{} selected", selected.len())),
+                                ),
+                                SelectedItemsDisplay::Chips => {
+                                    this.child(
+                                        div()
+                                            .w_full()
+                                            .min_w_0()
+                                            .flex()
+                                            .flex_wrap()
+                                            .gap(cx.get_theme().layout.padding.md)
+                                            .children(selected.into_iter().map(|name| {
+                                                let state = self.state.clone();
+                                                let chip_name = name.clone();
+
+                                                Chip::new(
+                                                    self.id.with_suffix("chip").with_suffix(&name),
+                                                )
+                                                .text(name)
+                                                .disabled(is_disabled)
+                                                .on_remove(move |_window, cx| {
+                                                    state.deselect_item(cx, chip_name.clone());
+                                                })
+                                            })),
+                                    )
+                                }
+                            };
+                        }
+
                         let Some(entry) = self
                             .state
                             .selected_item
@@ -365,12 +533,6 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
                             .as_ref()
                             .and_then(|this| self.state.items.read(cx).get(&this))
                         else {
-                            let placeholder = self
-                                .style
-                                .placeholder
-                                .as_ref()
-                                .map(|p| p.clone())
-                                .unwrap_or_else(|| "No item selected".into());
                             return this.child(placeholder);
                         };
 
@@ -382,7 +544,11 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
                                 .text_size(text_size)
                                 .text_color(primary_text_color)
                                 .font_family(font_family)
-                                .child(entry.item.display(window, cx, primary_text_color.into())),
+                                .child(entry.item.display_selected(
+                                    window,
+                                    cx,
+                                    primary_text_color.into(),
+                                )),
                         )
                     })
                     .child(
@@ -400,18 +566,38 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
                     ),
             )
             .when(menu_visible_delta != 0., |this| {
+                let deferred_config = self.deferred_config;
+
                 this.child(
                     div()
-                        .w_full()
                         .absolute()
-                        .top_full()
                         .left_0()
-                        .pt(cx.get_theme().layout.padding.md)
+                        .map(|this| match self.style.menu_width {
+                            SelectMenuWidth::MatchTrigger => this.w_full(),
+                            SelectMenuWidth::Content => this.min_w_full(),
+                        })
+                        .map(|this| match menu_placement {
+                            SelectMenuPlacement::Above => this.bottom(relative(1.)).pb(menu_gap),
+                            _ => this.top_full().pt(menu_gap),
+                        })
+                        .child(measure_height(menu_height))
                         .child(
                             SelectMenu::new(self.id.with_suffix("menu"), self.state.clone())
+                                .layer(layer)
                                 .focus_handle(focus_handle.clone())
                                 .when_some(self.style.max_menu_height, |this, max_menu_height| {
                                     this.max_h(max_menu_height)
+                                })
+                                // Keep the menu within the window.
+                                .when_some(menu_room, |this, menu_room| {
+                                    this.clamp_h(menu_room - menu_gap)
+                                })
+                                .when_some(no_results, |this, no_results| {
+                                    this.no_results(no_results)
+                                })
+                                .map(|mut this| {
+                                    *this.deferred_config_mut() = deferred_config;
+                                    this
                                 }),
                         ),
                 )
@@ -420,23 +606,42 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> RenderOnce for Select<V, I>
                 let behavior = self.mouse_behavior;
 
                 let focus_handle_on_mouse_down = focus_handle.clone();
-
-                this.on_hover(move |hover, _window, cx| {
-                    is_hover_state.update(cx, |this, cx| {
-                        *this = *hover;
-                        cx.notify();
-                    });
-                })
-                .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
-                    behavior.apply(window, cx);
-                    focus_handle_on_mouse_down.focus(window, cx);
-                })
-                .track_focus(&focus_handle)
+                let state_on_mouse_down = self.state.clone();
+                let state_on_open = self.state.clone();
+
+                this.key_context("Select")
+                    .on_action(move |_: &Open, _window, cx| {
+                        // Keys the open menu doesn't handle itself, such as space
+                        // for type-ahead, reach the menu's key listeners.
+                        if state_on_open
+                            .menu
+                            .is_shown(cx, &state_on_open.menu_visible_transition)
+                        {
+                            cx.propagate();
+                            return;
+                        }
+
+                        state_on_open.show_menu(cx);
+                    })
+                    .on_hover(move |hover, _window, cx| {
+                        is_hover_state.update(cx, |this, cx| {
+                            *this = *hover;
+                            cx.notify();
+                        });
+                    })
+                    .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                        behavior.apply(window, cx);
+                        focus_handle_on_mouse_down.focus(window, cx);
+                        state_on_mouse_down.show_menu(cx);
+                    })
+                    .track_focus(&focus_handle)
             })
     }
 }
 
-/// Default click handler that selects or deselects the clicked item and closes the menu.
+/// Default click handler that selects or deselects the clicked item.
+///
+/// Closes the menu in single-select mode.
 pub fn default_on_item_click<V: 'static, I: SelectItem<Value = V> + 'static>(
     checked: bool,
     state: Arc<SelectState<V, I>>,
@@ -444,6 +649,17 @@ pub fn default_on_item_click<V: 'static, I: SelectItem<Value = V> + 'static>(
     _window: &mut Window,
     cx: &mut App,
 ) {
+    if state.is_multiple() {
+        // Multi-select keeps the menu open so several items can be picked in a row.
+        if checked {
+            let _ = state.select_item(cx, item_name);
+        } else {
+            state.deselect_item(cx, item_name);
+        }
+
+        return;
+    }
+
     if checked {
         let _ = state.select_item(cx, item_name.clone());
     } else {
@@ -456,7 +672,11 @@ pub fn default_on_item_click<V: 'static, I: SelectItem<Value = V> + 'static>(
 #[cfg(all(test, feature = "test-support"))]
 mod tests {
     use super::*;
-    use gpui::{App, AppContext, Hsla, SharedString, TestAppContext, VisualTestContext, Window};
+    use std::{cell::RefCell, rc::Rc};
+
+    use gpui::{
+        App, AppContext, Hsla, SharedString, Task, TestAppContext, VisualTestContext, Window,
+    };
     use gpui_transitions::{BoolLerp, TransitionState};
 
     /// Helper to create select state entities for tests
@@ -482,6 +702,9 @@ mod tests {
     struct TestSelectItem {
         name: SharedString,
         value: String,
+        group: Option<SharedString>,
+        disabled: bool,
+        description: Option<SharedString>,
     }
 
     impl TestSelectItem {
@@ -489,8 +712,26 @@ mod tests {
             Self {
                 name: name.into(),
                 value: value.into(),
+                group: None,
+                disabled: false,
+                description: None,
             }
         }
+
+        fn description(mut self, description: impl Into<SharedString>) -> Self {
+            self.description = Some(description.into());
+            self
+        }
+
+        fn group(mut self, group: impl Into<SharedString>) -> Self {
+            self.group = Some(group.into());
+            self
+        }
+
+        fn disabled(mut self) -> Self {
+            self.disabled = true;
+            self
+        }
     }
 
     impl SelectItem for TestSelectItem {
@@ -507,6 +748,24 @@ mod tests {
         fn display(&self, _window: &mut Window, _cx: &App, _text_color: Hsla) -> impl IntoElement {
             gpui::div().child(self.name.clone()).into_any_element()
         }
+
+        fn group(&self) -> Option<SharedString> {
+            self.group.clone()
+        }
+
+        fn disabled(&self) -> bool {
+            self.disabled
+        }
+
+        fn description(&self) -> Option<SharedString> {
+            self.description.clone()
+        }
+
+        fn trailing(&self, _window: &mut Window, _cx: &App) -> Option<AnyElement> {
+            self.description
+                .as_ref()
+                .map(|_| gpui::div().child("New").into_any_element())
+        }
     }
 
     #[gpui::test]
@@ -655,6 +914,7 @@ mod tests {
     }
 
     #[gpui::test]
+    #[allow(deprecated)]
     fn test_select_items_map(cx: &mut TestAppContext) {
         cx.update(|cx| {
             let mut items = SelectItemsMap::<String, TestSelectItem>::new();
@@ -693,8 +953,12 @@ mod tests {
             );
             let select = Select::new("test-select", state);
             assert!(
-                matches!(select.layer, ThemeLayerKind::Tertiary),
-                "Select should default to tertiary layer"
+                select.layer.is_none(),
+                "Select should inherit its layer by default"
+            );
+            assert!(
+                matches!(ThemeLayerKind::current(cx), ThemeLayerKind::Tertiary),
+                "Select should fall back to tertiary layer outside a surface"
             );
         });
     }
@@ -733,6 +997,80 @@ mod tests {
         // The window creation itself validates rendering works
     }
 
+    #[gpui::test]
+    fn test_select_menu_inside_card_inherits_card_layer(cx: &mut TestAppContext) {
+        use crate::components::Card;
+        use crate::theme::{Theme, ThemeExt};
+        use crate::views::Root;
+        use std::cell::Cell;
+
+        /// Records the layer a component would inherit inside the menu.
+        #[derive(IntoElement)]
+        struct LayerProbe(Rc<Cell<Option<ThemeLayerKind>>>);
+
+        impl RenderOnce for LayerProbe {
+            fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+                self.0.set(Some(ThemeLayerKind::current(cx)));
+                gpui::div()
+            }
+        }
+
+        struct CardTestView {
+            state: Arc<SelectState<String, TestSelectItem>>,
+            probe: Rc<Cell<Option<ThemeLayerKind>>>,
+        }
+
+        impl gpui::Render for CardTestView {
+            fn render(
+                &mut self,
+                _window: &mut gpui::Window,
+                _cx: &mut gpui::Context<Self>,
+            ) -> impl IntoElement {
+                gpui::div().size_full().child(
+                    Card::new().layer(ThemeLayerKind::Primary).child(
+                        SelectMenu::new("test-menu", self.state.clone())
+                            .no_results(LayerProbe(self.probe.clone())),
+                    ),
+                )
+            }
+        }
+
+        cx.update(|cx| cx.set_theme(Theme::DEFAULT));
+
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            Arc::new(SelectState::new(
+                cx,
+                items,
+                selected,
+                highlighted,
+                visible,
+                focus_handles,
+            ))
+        });
+        let probe = Rc::new(Cell::new(None));
+
+        let window = cx
+            .update(|cx| {
+                cx.open_window(Default::default(), |window, cx| {
+                    let test_view = cx.new(|_cx| CardTestView {
+                        state: state.clone(),
+                        probe: probe.clone(),
+                    });
+                    cx.new(|cx| Root::new(test_view, window, cx))
+                })
+            })
+            .unwrap();
+
+        let mut vcx = VisualTestContext::from_window(window.into(), cx);
+        vcx.run_until_parked();
+
+        assert!(
+            matches!(probe.get(), Some(ThemeLayerKind::Secondary)),
+            "The deferred menu should see the layer the card provides"
+        );
+    }
+
     #[gpui::test]
     fn test_move_highlight_down_from_none(cx: &mut TestAppContext) {
         let (highlighted, state) = cx.update(|cx| {
@@ -1225,13 +1563,13 @@ mod tests {
 
     #[gpui::test]
     fn test_items_map_preserves_insertion_order(cx: &mut TestAppContext) {
-        cx.update(|cx| {
+        cx.update(|_cx| {
             let mut items = SelectItemsMap::<String, TestSelectItem>::new();
 
             // Insert items in specific order
-            items.push_item(cx, TestSelectItem::new("charlie", "value_c"));
-            items.push_item(cx, TestSelectItem::new("alpha", "value_a"));
-            items.push_item(cx, TestSelectItem::new("bravo", "value_b"));
+            items.push(TestSelectItem::new("charlie", "value_c"));
+            items.push(TestSelectItem::new("alpha", "value_a"));
+            items.push(TestSelectItem::new("bravo", "value_b"));
 
             // Verify iteration order matches insertion order (not alphabetical)
             let names: Vec<_> = items.iter().map(|(name, _)| name.clone()).collect();
@@ -1249,12 +1587,12 @@ mod tests {
 
     #[gpui::test]
     fn test_items_map_index_methods(cx: &mut TestAppContext) {
-        cx.update(|cx| {
+        cx.update(|_cx| {
             let mut items = SelectItemsMap::<String, TestSelectItem>::new();
 
-            items.push_item(cx, TestSelectItem::new("first", "value1"));
-            items.push_item(cx, TestSelectItem::new("second", "value2"));
-            items.push_item(cx, TestSelectItem::new("third", "value3"));
+            items.push(TestSelectItem::new("first", "value1"));
+            items.push(TestSelectItem::new("second", "value2"));
+            items.push(TestSelectItem::new("third", "value3"));
 
             assert_eq!(items.len(), 3, "Should have 3 items");
             assert!(!items.is_empty(), "Should not be empty");
@@ -1700,6 +2038,72 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    fn test_menu_renders_rich_items(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+        use crate::views::Root;
+
+        cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+            super::state::init(cx);
+        });
+
+        let (highlighted, state) = cx.update(|cx| {
+            let (items, selected, highlighted, visible, focus_handles) = (
+                cx.new(|_cx| SelectItemsMap::<String, TestSelectItem>::new()),
+                cx.new(|_cx| None::<SharedString>),
+                cx.new(|_cx| None::<SharedString>),
+                cx.new(|_cx| TransitionState::new(BoolLerp::truthy())),
+                cx.new(|_cx| Vec::new()),
+            );
+
+            let state = Arc::new(SelectState::new(
+                cx,
+                items,
+                selected,
+                highlighted.clone(),
+                visible,
+                focus_handles,
+            ));
+            state.push_item(
+                cx,
+                TestSelectItem::new("gpt", "gpt").description("128k context"),
+            );
+            state.push_item(cx, TestSelectItem::new("llama", "llama"));
+            state.show_menu(cx);
+            (highlighted, state)
+        });
+
+        let window = cx
+            .update(|cx| {
+                cx.open_window(Default::default(), |window, cx| {
+                    let test_view = cx.new(|_cx| SelectMenuTestView {
+                        state: state.clone(),
+                    });
+                    cx.new(|cx| Root::new(test_view, window, cx))
+                })
+            })
+            .unwrap();
+
+        let mut vcx = VisualTestContext::from_window(window.into(), cx);
+        vcx.run_until_parked();
+
+        vcx.update(|window, cx| {
+            window.dispatch_action(Box::new(MoveToLast), cx);
+        });
+
+        highlighted.read_with(&vcx, |h, _| {
+            assert_eq!(*h, Some("llama".into()));
+        });
+
+        vcx.update(|_window, cx| {
+            let items = state.items.read(cx);
+            let gpt = &items.get(&"gpt".into()).unwrap().item;
+            assert_eq!(SelectItem::description(gpt), Some("128k context".into()));
+            assert_eq!(SelectItem::icon(gpt), None, "Items have no icon by default");
+        });
+    }
+
     #[gpui::test]
     fn test_arrow_up_action_moves_highlight(cx: &mut TestAppContext) {
         use crate::theme::{Theme, ThemeExt};
@@ -2035,6 +2439,949 @@ mod tests {
         }
     }
 
+    #[gpui::test]
+    fn test_multi_select_order_and_limit(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let mut state = SelectState::new(
+                cx,
+                items,
+                selected.clone(),
+                highlighted,
+                visible,
+                focus_handles,
+            );
+            state.set_selection_mode(SelectionMode::Multiple { max: Some(2) });
+
+            state.push_item(cx, TestSelectItem::new("item1", "value1"));
+            state.push_item(cx, TestSelectItem::new("item2", "value2"));
+            state.push_item(cx, TestSelectItem::new("item3", "value3"));
+            state
+        });
+
+        cx.update(|cx| {
+            assert!(state.select_item(cx, "item3").is_ok());
+            assert!(state.select_item(cx, "item1").is_ok());
+            assert!(
+                state.select_item(cx, "item1").is_ok(),
+                "Reselecting a selected item should succeed"
+            );
+            assert!(
+                matches!(
+                    state.select_item(cx, "item2"),
+                    Err(SelectItemError::LimitReached)
+                ),
+                "Selecting past the limit should fail"
+            );
+            assert!(state.is_limit_reached(cx));
+
+            assert_eq!(
+                state.get_selected_item_names(cx),
+                vec![SharedString::from("item3"), SharedString::from("item1")],
+                "Names should be in the order they were selected"
+            );
+            assert_eq!(state.selected_items(cx), vec!["value3", "value1"]);
+            assert_eq!(state.get_selected_item_name(cx), Some("item3".into()));
+
+            state.deselect_item(cx, "item3");
+            assert!(state.select_item(cx, "item2").is_ok());
+            assert_eq!(state.selected_items(cx), vec!["value1", "value2"]);
+        });
+
+        selected.read_with(cx, |selected, _| {
+            assert!(
+                selected.is_none(),
+                "Single selection should be untouched in multi-select mode"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_multi_select_click_keeps_menu_open(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let mut state =
+                SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.set_selection_mode(SelectionMode::Multiple { max: None });
+
+            state.push_item(cx, TestSelectItem::new("item1", "value1"));
+            state.push_item(cx, TestSelectItem::new("item2", "value2"));
+            state.show_menu(cx);
+            Arc::new(state)
+        });
+
+        let window = cx
+            .update(|cx| cx.open_window(Default::default(), |_window, cx| cx.new(|_| gpui::Empty)))
+            .unwrap();
+
+        cx.update_window(window.into(), |_view, window, cx| {
+            default_on_item_click(true, state.clone(), "item2".into(), window, cx);
+            default_on_item_click(true, state.clone(), "item1".into(), window, cx);
+            default_on_item_click(false, state.clone(), "item2".into(), window, cx);
+        })
+        .unwrap();
+
+        cx.update(|cx| {
+            assert_eq!(
+                state.get_selected_item_names(cx),
+                vec![SharedString::from("item1")]
+            );
+            assert!(
+                state.menu_visible_transition.read_goal(cx) == &true.into(),
+                "Menu should stay open in multi-select mode"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_select_selected_items_display(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let state = Arc::new(SelectState::new(
+                cx,
+                items,
+                selected,
+                highlighted,
+                visible,
+                focus_handles,
+            ));
+
+            let select = Select::new("test-select", state.clone());
+            assert_eq!(
+                select.style.selected_items_display,
+                SelectedItemsDisplay::Chips
+            );
+
+            let select = Select::new("test-select", state)
+                .selected_items_display(SelectedItemsDisplay::Count);
+            assert_eq!(
+                select.style.selected_items_display,
+                SelectedItemsDisplay::Count
+            );
+        });
+    }
+
+    #[test]
+    fn test_menu_placement_flips_when_clipped() {
+        let window_height = px(600.);
+        let margin = px(4.);
+        let near_top = Bounds::new(gpui::point(px(0.), px(40.)), gpui::size(px(200.), px(32.)));
+        let near_bottom = Bounds::new(gpui::point(px(0.), px(500.)), gpui::size(px(200.), px(32.)));
+
+        assert_eq!(
+            SelectMenuPlacement::Auto.resolve(near_top, window_height, px(200.), margin),
+            (SelectMenuPlacement::Below, px(524.))
+        );
+        assert_eq!(
+            SelectMenuPlacement::Auto.resolve(near_bottom, window_height, px(200.), margin),
+            (SelectMenuPlacement::Above, px(496.)),
+            "A menu that doesn't fit below should open above"
+        );
+        assert_eq!(
+            SelectMenuPlacement::Auto.resolve(near_bottom, window_height, px(40.), margin),
+            (SelectMenuPlacement::Below, px(64.)),
+            "A menu that fits below should stay there"
+        );
+        assert_eq!(
+            SelectMenuPlacement::Below.resolve(near_bottom, window_height, px(200.), margin),
+            (SelectMenuPlacement::Below, px(64.))
+        );
+        assert_eq!(
+            SelectMenuPlacement::Above.resolve(near_top, window_height, px(40.), margin),
+            (SelectMenuPlacement::Above, px(36.))
+        );
+    }
+
+    #[test]
+    fn test_match_item_names() {
+        let names: Vec<SharedString> = vec!["Apple".into(), "Banana".into(), "apricot".into()];
+
+        let prefix = match_item_names("AP", names.iter(), SelectFilter::Prefix);
+        assert_eq!(
+            prefix,
+            vec![
+                SelectItemMatch {
+                    name: "Apple".into(),
+                    ranges: vec![0..2],
+                },
+                SelectItemMatch {
+                    name: "apricot".into(),
+                    ranges: vec![0..2],
+                },
+            ]
+        );
+
+        let substring = match_item_names("an", names.iter(), SelectFilter::Substring);
+        assert_eq!(
+            substring,
+            vec![SelectItemMatch {
+                name: "Banana".into(),
+                ranges: vec![1..3],
+            }],
+            "Only the first occurrence should be highlighted"
+        );
+
+        let fuzzy = match_item_names("apt", names.iter(), SelectFilter::Fuzzy);
+        assert_eq!(fuzzy.len(), 1);
+        assert_eq!(fuzzy[0].name, SharedString::from("apricot"));
+    }
+
+    #[gpui::test]
+    fn test_searchable_select_filters_items(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let mut state =
+                SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.push_item(cx, TestSelectItem::new("Apple", "apple"));
+            state.push_item(cx, TestSelectItem::new("Banana", "banana"));
+            state.push_item(cx, TestSelectItem::new("Cherry", "cherry"));
+
+            let set_query =
+                |state: &SelectState<String, TestSelectItem>, cx: &mut App, query: &'static str| {
+                    state
+                        .query
+                        .update(cx, |this, _cx| this.value = Some(SharedString::from(query)));
+                };
+
+            set_query(&state, cx, "an");
+            assert_eq!(
+                state.matching_items(cx).len(),
+                3,
+                "The query should be ignored when the select is not searchable"
+            );
+
+            state.set_searchable(SelectFilter::Substring);
+            let names = |state: &SelectState<String, TestSelectItem>, cx: &App| {
+                state
+                    .matching_items(cx)
+                    .into_iter()
+                    .map(|item_match| item_match.name)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(names(&state, cx), vec![SharedString::from("Banana")]);
+
+            state.highlight_first_match(cx);
+            assert_eq!(
+                state.highlighted_item.read(cx).clone(),
+                Some("Banana".into())
+            );
+
+            set_query(&state, cx, "xyz");
+            assert!(names(&state, cx).is_empty());
+            state.highlight_first_match(cx);
+            assert_eq!(state.highlighted_item.read(cx).clone(), None);
+
+            state.clear_query(cx);
+            assert_eq!(names(&state, cx).len(), 3);
+        });
+    }
+
+    #[gpui::test]
+    fn test_searchable_select_creates_item(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let mut state = SelectState::new(
+                cx,
+                items,
+                selected.clone(),
+                highlighted,
+                visible,
+                focus_handles,
+            );
+            state.set_searchable(SelectFilter::Fuzzy);
+            state.push_item(cx, TestSelectItem::new("Apple", "apple"));
+
+            state
+                .query
+                .update(cx, |this, _cx| this.value = Some("Durian ".into()));
+            assert!(
+                !state.can_create_item(cx),
+                "Items can only be created with an on_create callback"
+            );
+
+            state.on_create(|name, _window, _cx| {
+                TestSelectItem::new(name.clone(), name.to_lowercase())
+            });
+            assert!(state.can_create_item(cx));
+
+            state
+                .query
+                .update(cx, |this, _cx| this.value = Some("apple".into()));
+            assert!(
+                !state.can_create_item(cx),
+                "An item with the same name should not be created twice"
+            );
+
+            state
+                .query
+                .update(cx, |this, _cx| this.value = Some("Durian ".into()));
+            Arc::new(state)
+        });
+
+        let window = cx
+            .update(|cx| cx.open_window(Default::default(), |_window, cx| cx.new(|_| gpui::Empty)))
+            .unwrap();
+
+        cx.update_window(window.into(), |_view, window, cx| {
+            state.confirm_highlight(window, cx);
+        })
+        .unwrap();
+
+        cx.update(|cx| {
+            assert_eq!(state.items.read(cx).len(), 2);
+            assert_eq!(state.query(cx), SharedString::from(""));
+            assert_eq!(state.get_selected_item_name(cx), Some("Durian".into()));
+        });
+        selected.read_with(cx, |selected, _| {
+            assert_eq!(selected.as_ref(), Some(&SharedString::from("Durian")));
+        });
+    }
+
+    #[gpui::test]
+    fn test_type_ahead_highlights_matching_item(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let state = SelectState::new(
+                cx,
+                items,
+                selected,
+                highlighted.clone(),
+                visible,
+                focus_handles,
+            );
+            state.push_item(cx, TestSelectItem::new("Banana", "banana"));
+            state.push_item(cx, TestSelectItem::new("Blueberry", "blueberry"));
+            state.push_item(cx, TestSelectItem::new("Cherry", "cherry"));
+            state
+        });
+
+        let window = cx
+            .update(|cx| cx.open_window(Default::default(), |_window, cx| cx.new(|_| gpui::Empty)))
+            .unwrap();
+
+        cx.update_window(window.into(), |_view, window, cx| {
+            state.type_ahead('c', window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("Cherry".into()));
+
+            // Typing before the timeout extends the text, so "c" then "b" matches nothing.
+            state.type_ahead('b', window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("Cherry".into()));
+
+            state.typed_prefix.update(cx, |this, _cx| *this = None);
+            state.type_ahead('B', window, cx);
+            state.type_ahead('l', window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("Blueberry".into()));
+        })
+        .unwrap();
+    }
+
+    #[gpui::test]
+    fn test_item_source_loads_pages(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let mut state =
+                SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.set_item_source(|request, _cx| {
+                let items = (0..2).map(|index| {
+                    let name = format!("item{}", request.page * 2 + index);
+                    TestSelectItem::new(name.clone(), name)
+                });
+                Task::ready(Ok(SelectItemsPage::new(items).has_more(request.page == 0)))
+            });
+            state
+        });
+
+        cx.update(|cx| {
+            state.reload(cx);
+            assert_eq!(state.load_status(cx), SelectLoadStatus::Loading);
+        });
+        cx.run_until_parked();
+
+        cx.update(|cx| {
+            assert_eq!(state.load_status(cx), SelectLoadStatus::Idle);
+            assert_eq!(state.items.read(cx).len(), 2);
+            assert!(state.has_more_items(cx));
+
+            state.load_more(cx);
+        });
+        cx.run_until_parked();
+
+        cx.update(|cx| {
+            let names = state
+                .items
+                .read(cx)
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["item0", "item1", "item2", "item3"]);
+            assert!(!state.has_more_items(cx));
+
+            state.load_more(cx);
+            assert_eq!(
+                state.load_status(cx),
+                SelectLoadStatus::Idle,
+                "Nothing should load past the last page"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_item_source_reload_keeps_selected_items(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let mut state =
+                SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.set_searchable(SelectFilter::Substring);
+            state.set_item_source(|request: SelectItemsRequest, _cx| {
+                let items = ["apple", "banana", "cherry"]
+                    .into_iter()
+                    .filter(|name| name.contains(request.query.as_ref()))
+                    .map(|name| TestSelectItem::new(name, name.to_string()));
+                Task::ready(Ok(SelectItemsPage::new(items)))
+            });
+            state
+        });
+
+        cx.update(|cx| state.reload(cx));
+        cx.run_until_parked();
+
+        cx.update(|cx| {
+            state.select_item(cx, "banana").unwrap();
+            state
+                .query
+                .update(cx, |this, _cx| this.value = Some("ch".into()));
+            state.reload(cx);
+        });
+        cx.run_until_parked();
+
+        cx.update(|cx| {
+            let names = state
+                .items
+                .read(cx)
+                .iter()
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            assert_eq!(names, vec!["banana", "cherry"]);
+            assert_eq!(state.selected_item.read(cx).clone(), Some("banana".into()));
+        });
+    }
+
+    #[gpui::test]
+    fn test_item_source_reload_is_debounced(cx: &mut TestAppContext) {
+        let requests = Rc::new(RefCell::new(Vec::new()));
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let mut state =
+                SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.set_searchable(SelectFilter::Substring);
+
+            let requests = requests.clone();
+            state.set_item_source(move |request: SelectItemsRequest, _cx| {
+                let failed = request.query == "fail";
+                requests.borrow_mut().push(request.query.clone());
+
+                Task::ready(if failed {
+                    Err("Service unavailable".into())
+                } else {
+                    Ok(SelectItemsPage::new([TestSelectItem::new(
+                        request.query.clone(),
+                        request.query.to_string(),
+                    )]))
+                })
+            });
+            state
+        });
+
+        let set_query = |cx: &mut TestAppContext, query: &'static str| {
+            cx.update(|cx| {
+                state
+                    .query
+                    .update(cx, |this, _cx| this.value = Some(query.into()));
+                state.sync_item_source(cx);
+            });
+        };
+
+        set_query(cx, "");
+        cx.run_until_parked();
+        assert_eq!(
+            *requests.borrow(),
+            vec![SharedString::from("")],
+            "The first load should not wait"
+        );
+
+        set_query(cx, "a");
+        set_query(cx, "ab");
+        cx.executor().advance_clock(DEFAULT_RELOAD_DEBOUNCE);
+        cx.run_until_parked();
+        assert_eq!(
+            *requests.borrow(),
+            vec![SharedString::from(""), SharedString::from("ab")],
+            "Only the settled query should be loaded"
+        );
+        cx.update(|cx| {
+            assert!(state.items.read(cx).get(&"ab".into()).is_some());
+            assert_eq!(state.matching_items(cx)[0].ranges, vec![0..2]);
+        });
+
+        set_query(cx, "fail");
+        cx.executor().advance_clock(DEFAULT_RELOAD_DEBOUNCE);
+        cx.run_until_parked();
+        cx.update(|cx| {
+            assert_eq!(
+                state.load_status(cx),
+                SelectLoadStatus::Failed("Service unavailable".into())
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_grouped_items_stay_together(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let mut state =
+                SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.push_item(
+                cx,
+                TestSelectItem::new("llama", "llama").group("Local models"),
+            );
+            state.push_item(
+                cx,
+                TestSelectItem::new("claude", "claude").group("Remote models"),
+            );
+            state.push_item(
+                cx,
+                TestSelectItem::new("mistral", "mistral").group("Local models"),
+            );
+            state.push_item(
+                cx,
+                TestSelectItem::new("gemini", "gemini").group("Remote models"),
+            );
+
+            let names = |state: &SelectState<String, TestSelectItem>, cx: &App| {
+                state
+                    .matching_items(cx)
+                    .into_iter()
+                    .map(|item_match| item_match.name)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(
+                names(&state, cx),
+                vec!["llama", "mistral", "claude", "gemini"],
+                "Items should be kept together with the rest of their group"
+            );
+
+            state.set_searchable(SelectFilter::Fuzzy);
+            state
+                .query
+                .update(cx, |this, _cx| this.value = Some("mi".into()));
+            assert_eq!(
+                names(&state, cx),
+                vec!["mistral", "gemini"],
+                "Matches should stay in group order"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_move_highlight_skips_disabled_items(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let state = Arc::new(SelectState::new(
+                cx,
+                items,
+                selected.clone(),
+                highlighted.clone(),
+                visible,
+                focus_handles,
+            ));
+            state.push_item(cx, TestSelectItem::new("item1", "value1"));
+            state.push_item(cx, TestSelectItem::new("item2", "value2").disabled());
+            state.push_item(cx, TestSelectItem::new("item3", "value3"));
+            state
+        });
+
+        let window = cx
+            .update(|cx| cx.open_window(Default::default(), |_window, cx| cx.new(|_| gpui::Empty)))
+            .unwrap();
+
+        cx.update_window(window.into(), |_view, window, cx| {
+            state.move_highlight_down(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item1".into()));
+
+            state.move_highlight_down(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item3".into()));
+
+            state.move_highlight_up(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item1".into()));
+
+            // A disabled item highlighted from outside can't be confirmed.
+            highlighted.update(cx, |this, _cx| *this = Some("item2".into()));
+            state.confirm_highlight(window, cx);
+            assert_eq!(selected.read(cx).clone(), None);
+        })
+        .unwrap();
+    }
+
+    #[test]
+    fn test_page_indexes() {
+        assert_eq!(page_next_index(None, 25, 10), 0);
+        assert_eq!(page_next_index(Some(3), 25, 10), 13);
+        assert_eq!(page_next_index(Some(20), 25, 10), 24);
+
+        assert_eq!(page_prev_index(None, 25, 10), 24);
+        assert_eq!(page_prev_index(Some(13), 25, 10), 3);
+        assert_eq!(page_prev_index(Some(4), 25, 10), 0);
+    }
+
+    #[test]
+    fn test_type_ahead_index_cycles_repeated_letter() {
+        let names = ["Banana", "Blueberry", "Cherry", "Blackberry"];
+        let index = |current, typed| {
+            type_ahead_index(names.len(), current, typed, |index, text| {
+                names[index]
+                    .to_lowercase()
+                    .starts_with(&text.to_lowercase())
+            })
+        };
+
+        assert_eq!(index(None, "b"), Some(0));
+        assert_eq!(index(Some(0), "b"), Some(1));
+        assert_eq!(index(Some(1), "bb"), Some(3));
+        assert_eq!(index(Some(3), "bbb"), Some(0), "Cycling should wrap around");
+        assert_eq!(
+            index(Some(1), "bl"),
+            Some(1),
+            "A longer prefix should keep a match"
+        );
+        assert_eq!(index(Some(0), "bla"), Some(3));
+        assert_eq!(index(Some(0), "x"), None);
+    }
+
+    #[gpui::test]
+    fn test_move_highlight_to_first_last_and_by_page(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let state = SelectState::new(
+                cx,
+                items,
+                selected,
+                highlighted.clone(),
+                visible,
+                focus_handles,
+            );
+            for index in 0..25 {
+                state.push_item(
+                    cx,
+                    TestSelectItem::new(format!("item{index}"), format!("value{index}")),
+                );
+            }
+            state
+        });
+
+        let window = cx
+            .update(|cx| cx.open_window(Default::default(), |_window, cx| cx.new(|_| gpui::Empty)))
+            .unwrap();
+
+        cx.update_window(window.into(), |_view, window, cx| {
+            state.move_highlight_to_last(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item24".into()));
+
+            state.move_highlight_page_up(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item14".into()));
+
+            state.move_highlight_to_first(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item0".into()));
+
+            state.move_highlight_page_down(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item10".into()));
+
+            // Paging stops at the ends rather than wrapping around.
+            state.move_highlight_page_up(window, cx);
+            state.move_highlight_page_up(window, cx);
+            assert_eq!(highlighted.read(cx).clone(), Some("item0".into()));
+        })
+        .unwrap();
+    }
+
+    #[gpui::test]
+    fn test_dismissed_menu_stays_closed_until_shown(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let state = SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.push_item(cx, TestSelectItem::new("item1", "value1"));
+            assert!(!state.is_menu_dismissed(cx));
+
+            state.dismiss_menu(cx);
+            assert!(state.is_menu_dismissed(cx));
+            assert!(
+                state.menu_visible_transition.read_goal(cx) == &false.into(),
+                "Dismissing should close the menu"
+            );
+
+            state.show_menu(cx);
+            assert!(
+                !state.is_menu_dismissed(cx),
+                "Showing the menu should let it open on focus again"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_selection_changed_events(cx: &mut TestAppContext) {
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+        let state = cx.update(|cx| {
+            let state = SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.push_item(cx, TestSelectItem::new("item1", "value1"));
+            state.push_item(cx, TestSelectItem::new("item2", "value2"));
+
+            let events = events.clone();
+            let state_events = state.events(cx);
+            cx.subscribe(
+                &state_events,
+                move |_events, event: &SelectionChanged<String>, _cx| {
+                    events.borrow_mut().push(event.clone());
+                },
+            )
+            .detach();
+
+            state
+        });
+
+        cx.update(|cx| {
+            state.select_item(cx, "item1").unwrap();
+            state.select_item(cx, "item1").unwrap();
+            assert_eq!(state.selected_value(cx), Some(&"value1".to_string()));
+        });
+        assert_eq!(
+            *events.borrow(),
+            vec![SelectionChanged {
+                old: vec![],
+                new: vec!["value1".to_string()],
+            }],
+            "Reselecting the same item should not emit an event"
+        );
+
+        cx.update(|cx| {
+            state.set_items(
+                cx,
+                [
+                    TestSelectItem::new("item1", "value1b"),
+                    TestSelectItem::new("item3", "value3"),
+                ],
+            );
+            assert_eq!(state.get_selected_item_name(cx), Some("item1".into()));
+            assert_eq!(state.selected_value(cx), Some(&"value1b".to_string()));
+        });
+        assert_eq!(
+            events.borrow().len(),
+            1,
+            "Keeping the selection should not emit an event"
+        );
+
+        cx.update(|cx| {
+            state.set_items(cx, [TestSelectItem::new("item3", "value3")]);
+            assert_eq!(state.selected_value(cx), None);
+        });
+        assert_eq!(
+            events.borrow().last(),
+            Some(&SelectionChanged {
+                old: vec!["value1b".to_string()],
+                new: vec![],
+            })
+        );
+    }
+
+    #[gpui::test]
+    fn test_select_state_with_non_clone_values(cx: &mut TestAppContext) {
+        struct NonCloneValue(usize);
+
+        struct NonCloneItem {
+            name: SharedString,
+            value: NonCloneValue,
+        }
+
+        impl SelectItem for NonCloneItem {
+            type Value = NonCloneValue;
+
+            fn name(&self) -> SharedString {
+                self.name.clone()
+            }
+
+            fn value(&self) -> &Self::Value {
+                &self.value
+            }
+        }
+
+        cx.update(|cx| {
+            let mut items = SelectItemsMap::new();
+            for (index, name) in ["item1", "item2"].into_iter().enumerate() {
+                items.push(NonCloneItem {
+                    name: name.into(),
+                    value: NonCloneValue(index),
+                });
+            }
+
+            let state = SelectState::from_cx(cx, items);
+            state.select_item(cx, "item2").unwrap();
+            assert_eq!(state.selected_value(cx).map(|value| value.0), Some(1));
+
+            state.remove_selection(cx);
+            assert!(state.selected_value(cx).is_none());
+
+            let _select = Select::new("test-select", state).into_any_element();
+        });
+    }
+
+    #[gpui::test]
+    fn test_menu_rows_separate_groups(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let state = SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.push_item(cx, TestSelectItem::new("auto", "auto"));
+            state.push_item(
+                cx,
+                TestSelectItem::new("llama", "llama").group("Local models"),
+            );
+            state.push_item(
+                cx,
+                TestSelectItem::new("claude", "claude").group("Remote models"),
+            );
+
+            let item = |name: &'static str| {
+                SelectMenuRow::Item(SelectItemMatch {
+                    name: name.into(),
+                    ranges: Vec::new(),
+                })
+            };
+
+            assert_eq!(
+                state.menu_rows(cx),
+                vec![
+                    item("auto"),
+                    SelectMenuRow::Separator,
+                    SelectMenuRow::Header("Local models".into()),
+                    item("llama"),
+                    SelectMenuRow::Separator,
+                    SelectMenuRow::Header("Remote models".into()),
+                    item("claude"),
+                ]
+            );
+            assert_eq!(
+                state.menu_list.read(cx).list.item_count(),
+                7,
+                "The virtualized list should have a row for every menu row"
+            );
+
+            state.push_item(
+                cx,
+                TestSelectItem::new("mistral", "mistral").group("Remote models"),
+            );
+            assert_eq!(state.menu_rows(cx).len(), 8);
+            assert_eq!(state.menu_list.read(cx).list.item_count(), 8);
+        });
+    }
+
+    #[gpui::test]
+    fn test_menu_rows_rebuild_when_query_or_items_change(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let mut state =
+                SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            state.set_searchable(SelectFilter::Substring);
+            state.push_item(cx, TestSelectItem::new("apple", "apple"));
+            state.push_item(cx, TestSelectItem::new("banana", "banana"));
+
+            let names = |state: &SelectState<String, TestSelectItem>, cx: &mut App| {
+                state
+                    .menu_rows(cx)
+                    .into_iter()
+                    .filter_map(|row| match row {
+                        SelectMenuRow::Item(item_match) => Some(item_match.name),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(names(&state, cx), vec!["apple", "banana"]);
+
+            state
+                .query
+                .update(cx, |this, _cx| this.value = Some("ban".into()));
+            assert_eq!(names(&state, cx), vec!["banana"]);
+
+            state.set_items(
+                cx,
+                [
+                    TestSelectItem::new("bandana", "bandana"),
+                    TestSelectItem::new("cherry", "cherry"),
+                ],
+            );
+            assert_eq!(
+                names(&state, cx),
+                vec!["bandana"],
+                "Replacing the items should rebuild the rows for the same query"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_item_focus_handles_are_created_lazily(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let state = SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            for index in 0..1000 {
+                state.push_item(cx, TestSelectItem::new(format!("item{index}"), "value"));
+            }
+
+            let created = |state: &SelectState<String, TestSelectItem>, cx: &App| {
+                state
+                    .items
+                    .read(cx)
+                    .iter()
+                    .filter(|(_, entry)| entry.created_focus_handle().is_some())
+                    .count()
+            };
+            assert_eq!(created(&state, cx), 0, "No focus handle should exist yet");
+
+            let items = state.items.read(cx);
+            let entry = items.get(&"item10".into()).unwrap();
+            let focus_handle = entry.focus_handle(cx).clone();
+            assert!(
+                entry.focus_handle(cx) == &focus_handle,
+                "The focus handle should be created once"
+            );
+            assert_eq!(created(&state, cx), 1);
+        });
+    }
+
+    #[gpui::test]
+    fn test_sync_highlight_to_selection_scrolls_to_item(cx: &mut TestAppContext) {
+        let (items, selected, highlighted, visible, focus_handles) = create_test_state_entities(cx);
+
+        cx.update(|cx| {
+            let state = SelectState::new(cx, items, selected, highlighted, visible, focus_handles);
+            for index in 0..100 {
+                state.push_item(cx, TestSelectItem::new(format!("item{index}"), "value"));
+            }
+            state.select_item(cx, "item80").unwrap();
+
+            state.sync_highlight_to_selection(cx);
+            assert_eq!(state.highlighted_item.read(cx).as_deref(), Some("item80"));
+            assert_eq!(
+                state.menu_list.read(cx).list.first_visible_item(),
+                80,
+                "The highlighted item should be scrolled into view"
+            );
+        });
+    }
+
     #[gpui::test]
     fn test_stale_focus_handles_are_cleaned_up(cx: &mut TestAppContext) {
         cx.update(|cx| {
@@ -2060,13 +3407,13 @@ mod tests {
             let handle2 = cx.focus_handle();
             let handle3 = cx.focus_handle();
 
-            state.register_focus_handle(cx, handle1.clone());
-            state.register_focus_handle(cx, handle2.clone());
-            state.register_focus_handle(cx, handle3.clone());
+            state.register_focus_handle(cx, &handle1);
+            state.register_focus_handle(cx, &handle2);
+            state.register_focus_handle(cx, &handle3);
 
             // Verify we have 3 handles registered while they're still alive
             assert_eq!(
-                state.select_focus_handles.read(cx).len(),
+                state.menu.focus_handles.read(cx).len(),
                 3,
                 "Should have 3 focus handles registered"
             );
@@ -2079,11 +3426,11 @@ mod tests {
 
         cx.update(|cx| {
             let handle4 = cx.focus_handle();
-            state.register_focus_handle(cx, handle4.clone());
+            state.register_focus_handle(cx, &handle4);
 
             // Should have 3 handles: handle2, handle3, handle4 (handle1 was cleaned up)
             assert_eq!(
-                state.select_focus_handles.read(cx).len(),
+                state.menu.focus_handles.read(cx).len(),
                 3,
                 "Stale handle should have been cleaned up"
             );
@@ -0,0 +1,135 @@
+use std::{rc::Rc, time::Duration};
+
+use gpui::{App, AppContext, Entity, SharedString, Task};
+
+use crate::components::select::{SelectItem, SelectItemsMap};
+
+/// How long the search query has to stay unchanged before items are reloaded for it.
+pub(crate) const DEFAULT_RELOAD_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Type alias for the callback that loads a page of items from an async item source.
+pub type LoadItemsFn<I> =
+    Rc<dyn Fn(SelectItemsRequest, &mut App) -> Task<Result<SelectItemsPage<I>, SharedString>>>;
+
+/// A request for a page of items from an async item source.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectItemsRequest {
+    /// The search query, which is empty unless the select is searchable.
+    pub query: SharedString,
+    /// The zero-based index of the page to load.
+    pub page: usize,
+}
+
+/// A page of items loaded by an async item source.
+pub struct SelectItemsPage<I> {
+    /// The items of this page, appended after the items of earlier pages.
+    pub items: Vec<I>,
+    /// Whether another page can be loaded after this one.
+    pub has_more: bool,
+}
+
+impl<I> SelectItemsPage<I> {
+    /// Creates the last page, holding the given items.
+    pub fn new(items: impl IntoIterator<Item = I>) -> Self {
+        Self {
+            items: items.into_iter().collect(),
+            has_more: false,
+        }
+    }
+
+    /// Sets whether another page can be loaded after this one.
+    pub fn has_more(mut self, has_more: bool) -> Self {
+        self.has_more = has_more;
+        self
+    }
+}
+
+/// Whether items are being loaded from an async item source.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum SelectLoadStatus {
+    /// No load is in flight.
+    #[default]
+    Idle,
+    /// A page of items is being loaded.
+    Loading,
+    /// The last load failed with the given message.
+    Failed(SharedString),
+}
+
+/// Tracks the pages loaded from an async item source.
+#[derive(Default)]
+pub(crate) struct SelectItemsLoader {
+    pub(crate) status: SelectLoadStatus,
+    /// The query of the loaded items, or of the load in flight.
+    pub(crate) query: Option<SharedString>,
+    pub(crate) next_page: usize,
+    pub(crate) has_more: bool,
+    /// Replacing the task cancels the load in flight.
+    task: Option<Task<()>>,
+}
+
+/// Loads a page of items after `delay`, replacing the items for the first page
+/// and appending them otherwise.
+///
+/// Items for which `is_selected` returns true survive the first page, so the
+/// selection keeps its entries when the query no longer matches them.
+pub(crate) fn load_page<V: 'static, I: SelectItem<Value = V> + 'static>(
+    loader: &Entity<SelectItemsLoader>,
+    items: Entity<SelectItemsMap<V, I>>,
+    load_items: LoadItemsFn<I>,
+    request: SelectItemsRequest,
+    delay: Duration,
+    is_selected: impl Fn(&SharedString, &App) -> bool + 'static,
+    cx: &mut App,
+) {
+    loader.update(cx, |loader, cx| {
+        loader.status = SelectLoadStatus::Loading;
+        loader.query = Some(request.query.clone());
+
+        loader.task = Some(cx.spawn(async move |loader, cx| {
+            if !delay.is_zero() {
+                cx.background_executor().timer(delay).await;
+            }
+
+            let page = request.page;
+            let result = cx.update(|cx| (load_items)(request, cx)).await;
+
+            let Some(loader) = loader.upgrade() else {
+                return;
+            };
+
+            match result {
+                Ok(loaded) => {
+                    let has_more = loaded.has_more;
+
+                    items.update(cx, |items, cx| {
+                        if page == 0 {
+                            items.retain(|name| is_selected(name, cx));
+                        }
+
+                        for item in loaded.items {
+                            items.push(item);
+                        }
+
+                        cx.notify();
+                    });
+
+                    loader.update(cx, |loader, cx| {
+                        loader.status = SelectLoadStatus::Idle;
+                        loader.next_page = page + 1;
+                        loader.has_more = has_more;
+                        cx.notify();
+                    });
+                }
+                Err(error) => {
+                    loader.update(cx, |loader, cx| {
+                        loader.status = SelectLoadStatus::Failed(error);
+                        cx.notify();
+                    });
+                }
+            }
+        }));
+
+        cx.notify();
+    });
+}
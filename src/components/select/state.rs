@@ -1,10 +1,18 @@
 #![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
 
-use std::{rc::Rc, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    iter,
+    ops::Range,
+    rc::Rc,
+    sync::Arc,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
 
 use gpui::{
-    App, AppContext, Context, ElementId, Entity, FocusHandle, KeyBinding, SharedString,
-    WeakFocusHandle, Window, actions, ease_out_quint,
+    App, AppContext, Context, ElementId, Entity, EventEmitter, FocusHandle, KeyBinding,
+    SharedString, Task, WeakFocusHandle, Window, actions,
 };
 use gpui_transitions::{BoolLerp, Transition, TransitionState};
 use indexmap::IndexMap;
@@ -12,27 +20,148 @@ use thiserror::Error;
 
 use crate::{
     ElementIdExt,
-    components::select::{SelectItem, SelectItemEntry, default_on_item_click},
+    components::select::{
+        DEFAULT_RELOAD_DEBOUNCE, LoadItemsFn, SelectItem, SelectItemEntry, SelectItemsLoader,
+        SelectItemsPage, SelectItemsRequest, SelectLoadStatus, SelectMenuList, SelectMenuRow,
+        SelectMenuRowsKey, default_on_item_click, load_page,
+    },
+    primitives::input::InputState,
+    utils::{PopoverState, fuzzy_match, wrapping_next_index, wrapping_prev_index},
 };
 
-actions!(select_menu, [MoveUp, MoveDown, Confirm]);
+actions!(
+    select_menu,
+    [
+        MoveUp,
+        MoveDown,
+        MoveToFirst,
+        MoveToLast,
+        PageUp,
+        PageDown,
+        Confirm,
+        ConfirmAndTab,
+        Dismiss,
+        Open
+    ]
+);
 
 /// Type alias for the item click callback function.
 pub type OnItemClickFn<V, I> =
     Rc<dyn Fn(bool, Arc<SelectState<V, I>>, SharedString, &mut Window, &mut App)>;
 
+/// Type alias for the callback that creates an item from the search query.
+pub type OnCreateItemFn<I> = Rc<dyn Fn(&SharedString, &mut Window, &mut App) -> I>;
+
+/// How long a pause in typing ends a type-ahead search.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// How many items `PageUp` and `PageDown` move the highlight by.
+const HIGHLIGHT_PAGE_SIZE: usize = 10;
+
+/// How the search query of a searchable select is matched against item names.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectFilter {
+    /// Names starting with the query.
+    Prefix,
+    /// Names containing the query anywhere.
+    Substring,
+    /// Names containing the query's chars in order, best matches first.
+    #[default]
+    Fuzzy,
+}
+
+/// An item whose name matched the search query.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectItemMatch {
+    /// The name of the matched item.
+    pub name: SharedString,
+    /// Byte ranges of the name that matched the query, for highlighting.
+    pub ranges: Vec<Range<usize>>,
+}
+
+/// Emitted by [`SelectState::events`] when the selection changes.
+///
+/// Values are cloned from the items, so values of items removed along with
+/// the change are still available.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SelectionChanged<V> {
+    /// The values selected before the change, in the order they were selected.
+    pub old: Vec<V>,
+    /// The values selected after the change, in the order they were selected.
+    pub new: Vec<V>,
+}
+
+impl<V> SelectionChanged<V> {
+    /// Returns the value selected before the change, or the first one in multi-select mode.
+    pub fn old_value(&self) -> Option<&V> {
+        self.old.first()
+    }
+
+    /// Returns the value selected after the change, or the first one in multi-select mode.
+    pub fn new_value(&self) -> Option<&V> {
+        self.new.first()
+    }
+}
+
+/// Emits [`SelectionChanged`] events for a [`SelectState`].
+///
+/// Subscribe to it with `cx.subscribe(&state.events(cx), ...)`.
+pub struct SelectStateEvents<V> {
+    /// Copies values into the events. Set by [`SelectState::events`], so events
+    /// are only emitted once something can subscribe to them.
+    clone_value: Option<fn(&V) -> V>,
+}
+
+impl<V> SelectStateEvents<V> {
+    fn new() -> Self {
+        Self { clone_value: None }
+    }
+}
+
+impl<V: 'static> EventEmitter<SelectionChanged<V>> for SelectStateEvents<V> {}
+
+/// Whether a select allows one or several items to be selected at once.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Selecting an item replaces the previous selection and closes the menu.
+    #[default]
+    Single,
+    /// Items are toggled independently and the menu stays open while picking.
+    Multiple {
+        /// The maximum number of selected items, if limited.
+        max: Option<usize>,
+    },
+}
+
 /// Shared state for a Select component, managing items, selection, and menu visibility.
+///
+/// Only subscribing to [`SelectionChanged`] events requires `V: Clone`, since
+/// they carry copies of the selected values.
 pub struct SelectState<V: 'static, I: SelectItem<Value = V> + 'static> {
     pub items: Entity<SelectItemsMap<V, I>>,
     pub(crate) selected_item: Entity<Option<SharedString>>,
+    /// Names of the selected items in multi-select mode, in the order they were selected.
+    pub(crate) selected_items: Entity<Vec<SharedString>>,
+    pub(crate) selection_mode: SelectionMode,
+    /// The search query typed into the menu of a searchable select.
+    pub(crate) query: Entity<InputState>,
+    pub(crate) filter: Option<SelectFilter>,
+    pub(crate) on_create: Option<OnCreateItemFn<I>>,
+    /// Text typed so far for type-ahead jumping, and when it was last typed.
+    pub(crate) typed_prefix: Entity<Option<(String, Instant)>>,
+    pub(crate) item_source: Option<LoadItemsFn<I>>,
+    pub(crate) reload_debounce: Duration,
+    pub(crate) loader: Entity<SelectItemsLoader>,
+    pub(crate) events: Entity<SelectStateEvents<V>>,
     pub(crate) highlighted_item: Entity<Option<SharedString>>,
+    /// The virtualized list of the menu, kept here so the highlight can scroll it.
+    pub(crate) menu_list: Entity<SelectMenuList>,
     /// Animated transition for menu visibility.
     pub menu_visible_transition: Transition<BoolLerp<f32>>,
+    /// Dismissal and focus state of the menu, shared by all Select components
+    /// using this state.
+    pub(crate) menu: PopoverState,
     pub(crate) on_item_click: OnItemClickFn<V, I>,
-    /// Weak focus handles from all Select components using this state.
-    /// Used to determine if any associated Select has focus.
-    /// Stored as weak references so stale handles can be cleaned up.
-    pub(crate) select_focus_handles: Entity<Vec<WeakFocusHandle>>,
 }
 
 impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
@@ -48,14 +177,24 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
         let state = Self {
             items,
             selected_item,
+            selected_items: cx.new(|_cx| Vec::new()),
+            selection_mode: SelectionMode::default(),
+            query: cx.new(|cx| InputState::new(cx)),
+            filter: None,
+            on_create: None,
+            typed_prefix: cx.new(|_cx| None),
+            item_source: None,
+            reload_debounce: DEFAULT_RELOAD_DEBOUNCE,
+            loader: cx.new(|_cx| SelectItemsLoader::default()),
+            events: cx.new(|_cx| SelectStateEvents::new()),
             highlighted_item,
-            menu_visible_transition: Transition::new(menu_visible, Duration::from_millis(275))
-                .with_easing(ease_out_quint()),
+            menu_list: cx.new(|_cx| SelectMenuList::default()),
+            menu_visible_transition: PopoverState::visible_transition(menu_visible),
+            menu: PopoverState::new(cx, select_focus_handles),
             on_item_click: Rc::new(default_on_item_click),
-            select_focus_handles,
         };
 
-        state.cleanup_stale_focus_handles(cx);
+        state.menu.cleanup_stale_focus_handles(cx);
 
         state
     }
@@ -79,27 +218,46 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
                 cx,
                 |_window, _cx| None,
             ),
+            selected_items: window.use_keyed_state(
+                id.with_suffix("state:selected_items"),
+                cx,
+                |_window, _cx| Vec::new(),
+            ),
+            selection_mode: SelectionMode::default(),
+            query: window.use_keyed_state(id.with_suffix("state:query"), cx, |_window, cx| {
+                InputState::new(cx)
+            }),
+            filter: None,
+            on_create: None,
+            typed_prefix: window.use_keyed_state(
+                id.with_suffix("state:type_ahead"),
+                cx,
+                |_window, _cx| None,
+            ),
+            item_source: None,
+            reload_debounce: DEFAULT_RELOAD_DEBOUNCE,
+            loader: window.use_keyed_state(id.with_suffix("state:loader"), cx, |_window, _cx| {
+                SelectItemsLoader::default()
+            }),
+            events: window.use_keyed_state(id.with_suffix("state:events"), cx, |_window, _cx| {
+                SelectStateEvents::new()
+            }),
             highlighted_item: window.use_keyed_state(
                 id.with_suffix("state:highlighted_item"),
                 cx,
                 |_window, _cx| None,
             ),
-            menu_visible_transition: Transition::new(
-                window.use_keyed_state(id.with_suffix("state:menu_visible"), cx, |_window, _cx| {
-                    TransitionState::new(BoolLerp::falsey())
-                }),
-                Duration::from_millis(275),
-            )
-            .with_easing(ease_out_quint()),
-            on_item_click: Rc::new(default_on_item_click),
-            select_focus_handles: window.use_keyed_state(
-                id.with_suffix("state:focus_handles"),
+            menu_list: window.use_keyed_state(
+                id.with_suffix("state:menu_list"),
                 cx,
-                |_window, _cx| vec![],
+                |_window, _cx| SelectMenuList::default(),
             ),
+            menu_visible_transition: PopoverState::visible_transition_from_window(&id, window, cx),
+            menu: PopoverState::from_window(&id, window, cx),
+            on_item_click: Rc::new(default_on_item_click),
         };
 
-        state.cleanup_stale_focus_handles(cx);
+        state.menu.cleanup_stale_focus_handles(cx);
 
         state
     }
@@ -109,17 +267,24 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
         let state = Self {
             items: cx.new(|_cx| items),
             selected_item: cx.new(|_cx| None),
+            selected_items: cx.new(|_cx| Vec::new()),
+            selection_mode: SelectionMode::default(),
+            query: cx.new(|cx| InputState::new(cx)),
+            filter: None,
+            on_create: None,
+            typed_prefix: cx.new(|_cx| None),
+            item_source: None,
+            reload_debounce: DEFAULT_RELOAD_DEBOUNCE,
+            loader: cx.new(|_cx| SelectItemsLoader::default()),
+            events: cx.new(|_cx| SelectStateEvents::new()),
             highlighted_item: cx.new(|_cx| None),
-            menu_visible_transition: Transition::new(
-                cx.new(|_cx| TransitionState::new(BoolLerp::falsey())),
-                Duration::from_millis(275),
-            )
-            .with_easing(ease_out_quint()),
+            menu_list: cx.new(|_cx| SelectMenuList::default()),
+            menu_visible_transition: PopoverState::visible_transition_from_cx(cx),
+            menu: PopoverState::from_cx(cx),
             on_item_click: Rc::new(default_on_item_click),
-            select_focus_handles: cx.new(|_cx| vec![]),
         };
 
-        state.cleanup_stale_focus_handles(cx);
+        state.menu.cleanup_stale_focus_handles(cx);
 
         state
     }
@@ -133,75 +298,245 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
         self.on_item_click = Rc::new(on_item_click);
     }
 
-    /// Removes focus handles that are no longer valid (i.e., their associated
-    /// component has been removed).
-    pub(crate) fn cleanup_stale_focus_handles(&self, cx: &mut App) {
-        self.select_focus_handles.update(cx, |handles, _cx| {
-            let mut i = 0;
-            while i < handles.len() {
-                if handles[i].upgrade().is_none() {
-                    handles.swap_remove(i);
-                } else {
-                    i += 1;
-                }
-            }
-        });
+    /// Sets whether one or several items can be selected. Defaults to single.
+    pub fn set_selection_mode(&mut self, selection_mode: SelectionMode) {
+        self.selection_mode = selection_mode;
+    }
+
+    /// Returns true if several items can be selected at once.
+    pub fn is_multiple(&self) -> bool {
+        matches!(self.selection_mode, SelectionMode::Multiple { .. })
+    }
+
+    /// Turns the select into a combobox, showing a search input in the menu
+    /// that filters items by name using the given filter.
+    pub fn set_searchable(&mut self, filter: SelectFilter) {
+        self.filter = Some(filter);
+    }
+
+    /// Returns true if the menu shows a search input.
+    pub fn is_searchable(&self) -> bool {
+        self.filter.is_some()
+    }
+
+    /// Offers to create a new item from the search query when no item is named
+    /// exactly like it. The created item is added to the items and selected.
+    pub fn on_create(
+        &mut self,
+        on_create: impl Fn(&SharedString, &mut Window, &mut App) -> I + 'static,
+    ) {
+        self.on_create = Some(Rc::new(on_create));
+    }
+
+    /// Loads items page by page from an async source instead of a fixed list.
+    ///
+    /// Items are loaded when the menu first opens, reloaded when the search
+    /// query changes and the next page is loaded when the menu is scrolled to
+    /// the bottom. The source is expected to filter by the query itself.
+    /// Selected items are kept across reloads so the selection stays visible.
+    pub fn set_item_source(
+        &mut self,
+        load_items: impl Fn(
+            SelectItemsRequest,
+            &mut App,
+        ) -> Task<Result<SelectItemsPage<I>, SharedString>>
+        + 'static,
+    ) {
+        self.item_source = Some(Rc::new(load_items));
+    }
+
+    /// Sets how long the search query has to stay unchanged before items are
+    /// reloaded for it. Defaults to 250ms.
+    pub fn set_reload_debounce(&mut self, debounce: Duration) {
+        self.reload_debounce = debounce;
+    }
+
+    /// Returns true if items are loaded from an async source.
+    pub fn has_item_source(&self) -> bool {
+        self.item_source.is_some()
+    }
+
+    /// Returns whether items are being loaded from the async source.
+    pub fn load_status(&self, cx: &App) -> SelectLoadStatus {
+        self.loader.read(cx).status.clone()
+    }
+
+    /// Returns true if the async source has another page of items to load.
+    pub fn has_more_items(&self, cx: &App) -> bool {
+        self.loader.read(cx).has_more
+    }
+
+    /// Reloads the items for the current search query from the first page.
+    pub fn reload(&self, cx: &mut App) {
+        let query = self.query(cx);
+        self.request_page(query, 0, Duration::ZERO, cx);
+    }
+
+    /// Loads the next page of items, unless a load is in flight or there are no more pages.
+    pub fn load_more(&self, cx: &mut App) {
+        let loader = self.loader.read(cx);
+        if !loader.has_more || loader.status == SelectLoadStatus::Loading {
+            return;
+        }
+
+        let query = loader.query.clone().unwrap_or_default();
+        let page = loader.next_page;
+        self.request_page(query, page, Duration::ZERO, cx);
+    }
+
+    /// Loads items for the current search query if they haven't been loaded yet,
+    /// waiting for the query to settle unless nothing has been loaded so far.
+    pub(crate) fn sync_item_source(&self, cx: &mut App) {
+        let query = self.query(cx);
+        let delay = match &self.loader.read(cx).query {
+            Some(loaded_query) if *loaded_query == query => return,
+            Some(_) => self.reload_debounce,
+            None => Duration::ZERO,
+        };
+
+        self.request_page(query, 0, delay, cx);
+    }
+
+    fn request_page(&self, query: SharedString, page: usize, delay: Duration, cx: &mut App) {
+        let Some(load_items) = self.item_source.clone() else {
+            return;
+        };
+
+        let selected_item = self.selected_item.clone();
+        let selected_items = self.selected_items.clone();
+
+        load_page(
+            &self.loader,
+            self.items.clone(),
+            load_items,
+            SelectItemsRequest { query, page },
+            delay,
+            move |name, cx| {
+                selected_item.read(cx).as_ref() == Some(name)
+                    || selected_items.read(cx).contains(name)
+            },
+            cx,
+        );
     }
 
     /// Registers a focus handle from a Select component using this state.
     /// Called automatically when a Select component renders.
-    pub(crate) fn register_focus_handle(&self, cx: &mut App, focus_handle: FocusHandle) {
-        self.select_focus_handles.update(cx, |handles, _cx| {
-            let weak = focus_handle.downgrade();
-
-            // Clean up stale handles
-            let mut i = 0;
-            while i < handles.len() {
-                if handles[i].upgrade().is_none() {
-                    handles.swap_remove(i);
-                } else {
-                    i += 1;
-                }
-            }
-
-            // Only add if not already present
-            if !handles.contains(&weak) {
-                handles.push(weak);
-            }
-        });
+    pub(crate) fn register_focus_handle(&self, cx: &mut App, focus_handle: &FocusHandle) {
+        self.menu.register_focus_handle(cx, focus_handle);
     }
 
     /// Checks if any Select component using this state has focus.
     pub fn any_select_focused(&self, window: &Window, cx: &mut App) -> bool {
-        self.select_focus_handles
-            .read(cx)
-            .iter()
-            .filter_map(|handle| handle.upgrade())
-            .any(|handle| handle.contains_focused(window, cx))
+        self.menu.any_focused(window, cx)
+    }
+
+    /// Returns the entity that emits [`SelectionChanged`] events.
+    pub fn events(&self, cx: &mut App) -> Entity<SelectStateEvents<V>>
+    where
+        V: Clone,
+    {
+        self.events.update(cx, |this, _cx| {
+            this.clone_value.get_or_insert(V::clone);
+        });
+
+        self.events.clone()
     }
 
     /// Adds an item to the select's item list.
     pub fn push_item(&self, cx: &mut App, item: impl Into<I>) {
         self.items.update(cx, |this, cx| {
-            this.push_item(cx, item);
+            this.push(item);
             cx.notify()
         });
     }
 
-    /// Selects an item by name, returning an error if the item doesn't exist.
+    /// Replaces all items, keeping the selected items that are still present.
+    pub fn set_items(&self, cx: &mut App, items: impl IntoIterator<Item = impl Into<I>>) {
+        self.track_selection_change(cx, |this, cx| {
+            this.items.update(cx, |this, cx| {
+                let mut new_items = SelectItemsMap::new();
+                for item in items {
+                    new_items.push(item);
+                }
+
+                *this = new_items;
+                cx.notify();
+            });
+
+            let items = this.items.read(cx);
+            let selected_item = this
+                .selected_item
+                .read(cx)
+                .clone()
+                .filter(|name| items.get(name).is_some());
+            let selected_items = this
+                .selected_items
+                .read(cx)
+                .iter()
+                .filter(|name| items.get(name).is_some())
+                .cloned()
+                .collect::<Vec<_>>();
+
+            this.selected_item.update(cx, |this, cx| {
+                if *this != selected_item {
+                    *this = selected_item;
+                    cx.notify();
+                }
+            });
+
+            this.selected_items.update(cx, |this, cx| {
+                if *this != selected_items {
+                    *this = selected_items;
+                    cx.notify();
+                }
+            });
+        });
+    }
+
+    /// Selects an item by name, returning an error if the item doesn't exist
+    /// or if the multi-select limit has been reached.
+    ///
+    /// In multi-select mode the item is added to the current selection.
     pub fn select_item<'a>(
         &'a self,
         cx: &'a mut App,
         item_name: impl Into<SharedString>,
     ) -> Result<(), SelectItemError> {
         let item_name = item_name.into();
+        self.track_selection_change(cx, |this, cx| this.select_item_untracked(cx, item_name))
+    }
 
+    /// Selects an item without emitting a [`SelectionChanged`] event.
+    fn select_item_untracked(
+        &self,
+        cx: &mut App,
+        item_name: SharedString,
+    ) -> Result<(), SelectItemError> {
         let _item = self
             .items
             .read(cx)
             .get(&item_name)
             .ok_or_else(|| SelectItemError::InvalidName)?;
 
+        if let SelectionMode::Multiple { max } = self.selection_mode {
+            let selected_items = self.selected_items.read(cx);
+
+            if selected_items.contains(&item_name) {
+                return Ok(());
+            }
+
+            if max.is_some_and(|max| selected_items.len() >= max) {
+                return Err(SelectItemError::LimitReached);
+            }
+
+            self.selected_items.update(cx, |this, cx| {
+                this.push(item_name);
+                cx.notify();
+            });
+
+            return Ok(());
+        }
+
         self.selected_item.update(cx, |this, cx| {
             if this.as_ref() == Some(&item_name) {
                 return;
@@ -214,13 +549,122 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
         Ok(())
     }
 
+    /// Removes a single item from the selection, if it is selected.
+    pub fn deselect_item(&self, cx: &mut App, item_name: impl Into<SharedString>) {
+        let item_name = item_name.into();
+
+        self.track_selection_change(cx, |this, cx| {
+            if this.is_multiple() {
+                this.selected_items.update(cx, |this, cx| {
+                    let len = this.len();
+                    this.retain(|name| name != &item_name);
+
+                    if this.len() != len {
+                        cx.notify();
+                    }
+                });
+            } else if this.selected_item.read(cx).as_ref() == Some(&item_name) {
+                this.clear_selection(cx);
+            }
+        });
+    }
+
+    /// Returns true if the item with the given name is selected.
+    pub fn is_item_selected(&self, cx: &App, item_name: &SharedString) -> bool {
+        if self.is_multiple() {
+            self.selected_items.read(cx).contains(item_name)
+        } else {
+            self.selected_item.read(cx).as_ref() == Some(item_name)
+        }
+    }
+
+    /// Returns true if no more items can be selected in multi-select mode.
+    pub fn is_limit_reached(&self, cx: &App) -> bool {
+        match self.selection_mode {
+            SelectionMode::Multiple { max: Some(max) } => self.selected_items.read(cx).len() >= max,
+            _ => false,
+        }
+    }
+
     /// Returns the name of the currently selected item, if any.
+    ///
+    /// In multi-select mode this is the first selected item.
     pub fn get_selected_item_name(&self, cx: &App) -> Option<SharedString> {
-        self.selected_item.read(cx).clone()
+        if self.is_multiple() {
+            self.selected_items.read(cx).first().cloned()
+        } else {
+            self.selected_item.read(cx).clone()
+        }
+    }
+
+    /// Returns the names of the selected items in the order they were selected.
+    pub fn get_selected_item_names(&self, cx: &App) -> Vec<SharedString> {
+        if self.is_multiple() {
+            self.selected_items.read(cx).clone()
+        } else {
+            self.selected_item.read(cx).iter().cloned().collect()
+        }
+    }
+
+    /// Returns the values of the selected items in the order they were selected,
+    /// skipping any that have since been removed from the items.
+    pub fn selected_items<'a>(&self, cx: &'a App) -> Vec<&'a V> {
+        let items = self.items.read(cx);
+        let names: &'a [SharedString] = if self.is_multiple() {
+            self.selected_items.read(cx)
+        } else {
+            self.selected_item.read(cx).as_slice()
+        };
+
+        names
+            .iter()
+            .filter_map(|name| items.get(name))
+            .map(|entry| entry.item.value())
+            .collect()
+    }
+
+    /// Returns the value of the selected item, or of the first selected item in multi-select mode.
+    pub fn selected_value<'a>(&self, cx: &'a App) -> Option<&'a V> {
+        self.selected_items(cx).into_iter().next()
     }
 
     /// Clears the current selection.
     pub fn remove_selection(&self, cx: &mut App) {
+        self.track_selection_change(cx, |this, cx| this.clear_selection(cx));
+    }
+
+    /// Runs `f` and emits a [`SelectionChanged`] event if it changed the selected items,
+    /// once the events were requested through [`Self::events`].
+    fn track_selection_change<R>(&self, cx: &mut App, f: impl FnOnce(&Self, &mut App) -> R) -> R {
+        let Some(clone_value) = self.events.read(cx).clone_value else {
+            return f(self, cx);
+        };
+
+        let old_names = self.get_selected_item_names(cx);
+        let old = self
+            .selected_items(cx)
+            .into_iter()
+            .map(clone_value)
+            .collect();
+
+        let result = f(self, cx);
+
+        if self.get_selected_item_names(cx) != old_names {
+            let new = self
+                .selected_items(cx)
+                .into_iter()
+                .map(clone_value)
+                .collect();
+            self.events.update(cx, |_this, cx| {
+                cx.emit(SelectionChanged { old, new });
+            });
+        }
+
+        result
+    }
+
+    /// Clears the selection without emitting a [`SelectionChanged`] event.
+    fn clear_selection(&self, cx: &mut App) {
         self.selected_item.update(cx, |this, cx| {
             if this == &None {
                 return;
@@ -229,6 +673,15 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
             *this = None;
             cx.notify();
         });
+
+        self.selected_items.update(cx, |this, cx| {
+            if this.is_empty() {
+                return;
+            }
+
+            this.clear();
+            cx.notify();
+        });
     }
 
     /// Toggles the dropdown menu visibility.
@@ -241,117 +694,395 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
 
     /// Hides the dropdown menu.
     pub fn hide_menu(&self, cx: &mut App) {
-        self.menu_visible_transition.update(cx, |this, cx| {
-            if this.value() == 0. {
-                return;
-            }
+        self.menu.hide(cx, &self.menu_visible_transition);
+    }
 
-            *this = false.into();
-            cx.notify();
-        });
+    /// Hides the dropdown menu and keeps it closed while the select stays focused,
+    /// until it's opened again with [`SelectState::show_menu`].
+    pub fn dismiss_menu(&self, cx: &mut App) {
+        self.menu.dismiss(cx);
+        self.hide_menu(cx);
+    }
+
+    /// Returns true if the menu was dismissed while the select kept focus.
+    pub fn is_menu_dismissed(&self, cx: &App) -> bool {
+        self.menu.is_dismissed(cx)
     }
 
     /// Shows the dropdown menu.
     pub fn show_menu(&self, cx: &mut App) {
-        self.menu_visible_transition.update(cx, |this, cx| {
-            if this.value() == 1. {
-                return;
-            }
+        self.menu.show(cx, &self.menu_visible_transition);
+    }
 
-            *this = true.into();
-            cx.notify();
+    /// Returns the current search query, which is empty unless the select is searchable.
+    pub fn query(&self, cx: &App) -> SharedString {
+        if !self.is_searchable() {
+            return SharedString::default();
+        }
+
+        self.query.read(cx).value()
+    }
+
+    /// Clears the search query.
+    pub fn clear_query(&self, cx: &mut App) {
+        self.query.update(cx, |this, cx| {
+            if this.clear().is_some() {
+                cx.notify();
+            }
         });
     }
 
-    /// Moves the highlight to the previous item, wrapping to the last item if at the beginning.
-    pub fn move_highlight_up(&self, window: &mut Window, cx: &mut App) {
-        let items = self.items.read(cx);
-        if items.is_empty() {
+    /// Returns the items matching the search query, or every item in
+    /// insertion order when there is no query.
+    pub fn matching_items(&self, cx: &App) -> Vec<SelectItemMatch> {
+        let query = self.query(cx);
+        let names = self.items.read(cx).iter().map(|(name, _)| name);
+
+        match self.filter {
+            // The item source already filters by the query, so matches are only highlighted.
+            Some(filter) if !query.trim().is_empty() && self.has_item_source() => names
+                .map(|name| SelectItemMatch {
+                    name: name.clone(),
+                    ranges: match_item_names(&query, iter::once(name), filter)
+                        .pop()
+                        .map_or_else(Vec::new, |item_match| item_match.ranges),
+                })
+                .collect(),
+            Some(filter) if !query.trim().is_empty() => {
+                let mut matches = match_item_names(&query, names, filter);
+
+                // Fuzzy ranking can interleave groups, so put the matches back in
+                // group order, keeping the ranking within each group.
+                let items = self.items.read(cx);
+                let mut group_order = HashMap::new();
+                for (_, entry) in items.iter() {
+                    let next_order = group_order.len();
+                    group_order.entry(entry.item.group()).or_insert(next_order);
+                }
+
+                if group_order.len() > 1 {
+                    matches.sort_by_key(|item_match| {
+                        items
+                            .get(&item_match.name)
+                            .and_then(|entry| group_order.get(&entry.item.group()).copied())
+                    });
+                }
+
+                matches
+            }
+            _ => names
+                .map(|name| SelectItemMatch {
+                    name: name.clone(),
+                    ranges: Vec::new(),
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns true if a new item can be created from the current search query.
+    pub fn can_create_item(&self, cx: &App) -> bool {
+        let query = self.query(cx);
+        let query = query.trim();
+
+        self.on_create.is_some()
+            && !query.is_empty()
+            && !self
+                .items
+                .read(cx)
+                .iter()
+                .any(|(name, _)| name.eq_ignore_ascii_case(query))
+    }
+
+    /// Creates an item from the search query, adds it and selects it as if it were clicked.
+    pub fn create_item(self: &Arc<Self>, window: &mut Window, cx: &mut App) {
+        if !self.can_create_item(cx) {
             return;
         }
 
-        let current = self.highlighted_item.read(cx).clone();
-        let new_index = match current {
-            None => Some(items.len() - 1),
-            Some(ref name) => {
-                let current_index = items.get_index_of(name);
-                match current_index {
-                    Some(0) => Some(items.len() - 1),
-                    Some(idx) => Some(idx - 1),
-                    None => Some(0),
+        let Some(on_create) = self.on_create.clone() else {
+            return;
+        };
+        let query: SharedString = self.query(cx).trim().to_string().into();
+
+        let item = (on_create)(&query, window, cx);
+        let item_name = item.name();
+
+        self.push_item(cx, item);
+        self.clear_query(cx);
+        (self.on_item_click)(true, self.clone(), item_name, window, cx);
+    }
+
+    /// Returns the rows of the menu, updating its virtualized list to match.
+    ///
+    /// Groups start with a header and are separated by a line.
+    pub(crate) fn menu_rows(&self, cx: &mut App) -> Vec<SelectMenuRow> {
+        self.sync_menu_rows(cx);
+        self.menu_list.read(cx).rows().to_vec()
+    }
+
+    /// Rebuilds the menu rows if the query, items or load status changed since
+    /// they were last built.
+    fn sync_menu_rows(&self, cx: &mut App) {
+        let key = SelectMenuRowsKey {
+            query: self.query(cx),
+            items_revision: self.items.read(cx).revision,
+            filter: self.filter,
+            loading: self.load_status(cx) == SelectLoadStatus::Loading,
+            can_create_item: self.can_create_item(cx),
+        };
+
+        if self.menu_list.read(cx).is_built_from(&key) {
+            return;
+        }
+
+        let mut rows = Vec::new();
+        let items = self.items.read(cx);
+
+        // The group of the previous item, to know where a new group starts.
+        let mut previous_group: Option<Option<SharedString>> = None;
+
+        for item_match in self.matching_items(cx) {
+            let Some(entry) = items.get(&item_match.name) else {
+                continue;
+            };
+
+            let group = entry.item.group();
+            if previous_group.as_ref() != Some(&group) {
+                if previous_group.is_some() {
+                    rows.push(SelectMenuRow::Separator);
                 }
+
+                if let Some(group) = &group {
+                    rows.push(SelectMenuRow::Header(group.clone()));
+                }
+
+                previous_group = Some(group);
             }
-        };
 
-        let target = new_index.and_then(|idx| {
-            items
-                .get_index(idx)
-                .map(|(name, entry)| (name.clone(), entry.focus_handle.clone()))
-        });
+            rows.push(SelectMenuRow::Item(item_match));
+        }
 
-        if let Some((new_highlight, focus_handle)) = target {
-            focus_handle.focus(window, cx);
+        if !rows.is_empty() && key.loading {
+            rows.push(SelectMenuRow::LoadingMore);
+        }
 
-            self.highlighted_item.update(cx, |this, cx| {
-                *this = Some(new_highlight);
+        if key.can_create_item {
+            rows.push(SelectMenuRow::Create);
+        }
+
+        self.menu_list.update(cx, |this, _cx| this.sync(rows, key));
+    }
+
+    /// Returns the index of the highlighted item among the menu rows.
+    fn highlighted_row(&self, cx: &mut App) -> Option<usize> {
+        let highlighted = self.highlighted_item.read(cx).clone()?;
+
+        self.sync_menu_rows(cx);
+        self.menu_list.read(cx).rows().iter().position(
+            |row| matches!(row, SelectMenuRow::Item(item_match) if item_match.name == highlighted),
+        )
+    }
+
+    /// Scrolls the menu the minimum distance needed to show the highlighted item.
+    fn scroll_to_highlight(&self, cx: &mut App) {
+        if let Some(index) = self.highlighted_row(cx) {
+            self.menu_list.read(cx).list.scroll_to_item(index);
+        }
+    }
+
+    /// Returns the items matching the search query that can be highlighted.
+    fn enabled_matching_items(&self, cx: &App) -> Vec<SelectItemMatch> {
+        let items = self.items.read(cx);
+
+        self.matching_items(cx)
+            .into_iter()
+            .filter(|item_match| !items.is_disabled(&item_match.name))
+            .collect()
+    }
+
+    /// Highlights the best match for the search query, or nothing if no item matches.
+    pub(crate) fn highlight_first_match(&self, cx: &mut App) {
+        let first_match = self
+            .enabled_matching_items(cx)
+            .into_iter()
+            .next()
+            .map(|m| m.name);
+
+        self.highlighted_item.update(cx, |this, cx| {
+            if *this != first_match {
+                *this = first_match;
                 cx.notify();
-            });
+            }
+        });
+
+        // The rows changed along with the query, so start from the top again.
+        self.sync_menu_rows(cx);
+        self.menu_list.read(cx).list.jump_to_item(0);
+    }
+
+    /// Adds a typed char to the type-ahead text and highlights the next item
+    /// whose name starts with it. Typing the same letter again cycles through
+    /// the items starting with it, and typing after a pause starts over.
+    pub fn type_ahead(&self, ch: char, window: &mut Window, cx: &mut App) {
+        let now = Instant::now();
+        let prefix = self.typed_prefix.update(cx, |this, _cx| {
+            let typed = match this.take() {
+                Some((typed, last)) if now.duration_since(last) < TYPE_AHEAD_TIMEOUT => typed,
+                _ => String::new(),
+            };
+
+            let typed = type_ahead_text(typed, ch);
+            *this = (!typed.is_empty()).then(|| (typed.clone(), now));
+            typed
+        });
+
+        if prefix.is_empty() {
+            return;
         }
+
+        let items = self.items.read(cx);
+        let current = self
+            .highlighted_item
+            .read(cx)
+            .as_ref()
+            .and_then(|name| items.get_index_of(name));
+
+        let target = type_ahead_index(items.len(), current, &prefix, |index, text| {
+            items.get_index(index).is_some_and(|(name, entry)| {
+                !entry.item.disabled() && starts_with_ignore_case(name, text)
+            })
+        })
+        .and_then(|index| items.get_index(index))
+        .map(|(name, _)| name.clone());
+
+        if let Some(item_name) = target {
+            self.set_highlight(item_name, window, cx);
+        }
+    }
+
+    /// Highlights the given item, moving focus to it unless focus has to stay
+    /// in the search input.
+    fn set_highlight(&self, item_name: SharedString, window: &mut Window, cx: &mut App) {
+        if !self.is_searchable() {
+            let focus_handle = self
+                .items
+                .read(cx)
+                .get(&item_name)
+                .map(|entry| entry.focus_handle(cx).clone());
+
+            if let Some(focus_handle) = focus_handle {
+                focus_handle.focus(window, cx);
+            }
+        }
+
+        self.highlighted_item.update(cx, |this, cx| {
+            *this = Some(item_name);
+            cx.notify();
+        });
+
+        self.scroll_to_highlight(cx);
+    }
+
+    /// Moves the highlight to the previous item, wrapping to the last item if at the beginning.
+    pub fn move_highlight_up(&self, window: &mut Window, cx: &mut App) {
+        self.move_highlight(false, window, cx);
     }
 
     /// Moves the highlight to the next item, wrapping to the first item if at the end.
     pub fn move_highlight_down(&self, window: &mut Window, cx: &mut App) {
-        let items = self.items.read(cx);
-        if items.is_empty() {
+        self.move_highlight(true, window, cx);
+    }
+
+    /// Highlights the first item.
+    pub fn move_highlight_to_first(&self, window: &mut Window, cx: &mut App) {
+        self.move_highlight_to(|_current, _len| 0, window, cx);
+    }
+
+    /// Highlights the last item, loading the next page of an item source.
+    pub fn move_highlight_to_last(&self, window: &mut Window, cx: &mut App) {
+        self.move_highlight_to(|_current, len| len - 1, window, cx);
+    }
+
+    /// Moves the highlight a page of items up, stopping at the first item.
+    pub fn move_highlight_page_up(&self, window: &mut Window, cx: &mut App) {
+        self.move_highlight_to(
+            |current, len| page_prev_index(current, len, HIGHLIGHT_PAGE_SIZE),
+            window,
+            cx,
+        );
+    }
+
+    /// Moves the highlight a page of items down, stopping at the last item.
+    pub fn move_highlight_page_down(&self, window: &mut Window, cx: &mut App) {
+        self.move_highlight_to(
+            |current, len| page_next_index(current, len, HIGHLIGHT_PAGE_SIZE),
+            window,
+            cx,
+        );
+    }
+
+    /// Moves the highlight between the items matching the search query, skipping disabled items.
+    fn move_highlight(&self, forward: bool, window: &mut Window, cx: &mut App) {
+        if forward {
+            self.move_highlight_to(wrapping_next_index, window, cx);
+        } else {
+            self.move_highlight_to(wrapping_prev_index, window, cx);
+        }
+    }
+
+    /// Highlights the item at the index returned by `new_index`, given the index
+    /// of the highlighted item among the items that can be highlighted.
+    fn move_highlight_to(
+        &self,
+        new_index: impl FnOnce(Option<usize>, usize) -> usize,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let visible = self.enabled_matching_items(cx);
+        if visible.is_empty() {
             return;
         }
 
         let current = self.highlighted_item.read(cx).clone();
-        let len = items.len();
-        let new_index = match current {
-            None => Some(0),
-            Some(ref name) => {
-                let current_index = items.get_index_of(name);
-                match current_index {
-                    Some(idx) if idx + 1 >= len => Some(0),
-                    Some(idx) => Some(idx + 1),
-                    None => Some(0),
-                }
-            }
+        let new_index = match current.map(|name| visible.iter().position(|m| m.name == name)) {
+            // The highlighted item no longer exists, so start over from the top.
+            Some(None) => 0,
+            current => new_index(current.flatten(), visible.len()),
         };
 
-        let target = new_index.and_then(|idx| {
-            items
-                .get_index(idx)
-                .map(|(name, entry)| (name.clone(), entry.focus_handle.clone()))
-        });
-
-        if let Some((new_highlight, focus_handle)) = target {
-            focus_handle.focus(window, cx);
+        self.set_highlight(visible[new_index].name.clone(), window, cx);
 
-            self.highlighted_item.update(cx, |this, cx| {
-                *this = Some(new_highlight);
-                cx.notify();
-            });
+        // Reaching the last item loads the next page of an item source.
+        if new_index + 1 == visible.len() {
+            self.load_more(cx);
         }
     }
 
     /// Confirms the currently highlighted item as the selection.
+    ///
+    /// Creates an item from the search query instead when nothing is highlighted.
     pub fn confirm_highlight(self: &Arc<Self>, window: &mut Window, cx: &mut App) {
         let highlighted = self.highlighted_item.read(cx).clone();
         if let Some(item_name) = highlighted {
-            let selected = self.selected_item.read(cx).as_ref() == Some(&item_name);
+            if self.items.read(cx).is_disabled(&item_name) {
+                return;
+            }
+
+            let selected = self.is_item_selected(cx, &item_name);
             (self.on_item_click)(!selected, self.clone(), item_name, window, cx);
+        } else if self.can_create_item(cx) {
+            self.create_item(window, cx);
         }
     }
 
-    /// Sets the highlight to match the current selection.
+    /// Sets the highlight to match the current selection and scrolls the menu
+    /// so the highlighted item is at the top.
     pub fn sync_highlight_to_selection(&self, cx: &mut App) {
+        let selected = self.get_selected_item_names(cx);
         let items = self.items.read(cx);
-        let selected = self.selected_item.read(cx).clone();
 
-        // If the selected item exists in items, use it; otherwise default to None
-        let new_highlight = selected.filter(|name| items.get(name).is_some());
+        // Highlight the first selected item that still exists; otherwise default to None
+        let new_highlight = selected.into_iter().find(|name| items.get(name).is_some());
 
         self.highlighted_item.update(cx, |this, cx| {
             if *this != new_highlight {
@@ -359,6 +1090,11 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
                 cx.notify();
             }
         });
+
+        // Rows may not be measured yet when the menu opens, so jump rather
+        // than scrolling by the distance to the item.
+        let index = self.highlighted_row(cx).unwrap_or(0);
+        self.menu_list.read(cx).list.jump_to_item(index);
     }
 
     /// Sets the highlight to the item that currently has focus.
@@ -368,7 +1104,7 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
         // Find the item whose focus handle is currently focused.
         let focused_item = items
             .iter()
-            .find(|(_, entry)| &entry.focus_handle == focus_handle)
+            .find(|(_, entry)| entry.created_focus_handle() == Some(focus_handle))
             .map(|(name, _)| name);
 
         if let Some(item_name) = focused_item {
@@ -384,70 +1120,283 @@ impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectState<V, I> {
     }
 }
 
+/// Returns the index a page before `current`, stopping at the first index.
+/// Starts from the last index when nothing is highlighted yet.
+///
+/// `len` must be non-zero.
+pub(crate) fn page_prev_index(current: Option<usize>, len: usize, page_size: usize) -> usize {
+    match current {
+        Some(idx) if idx < len => idx.saturating_sub(page_size),
+        _ => len - 1,
+    }
+}
+
+/// Returns the index a page after `current`, stopping at the last index.
+/// Starts from the first index when nothing is highlighted yet.
+///
+/// `len` must be non-zero.
+pub(crate) fn page_next_index(current: Option<usize>, len: usize, page_size: usize) -> usize {
+    match current {
+        Some(idx) => (idx + page_size).min(len - 1),
+        None => 0,
+    }
+}
+
+/// Returns the index of the item that typing `typed` jumps to, searching
+/// from `current` and wrapping around.
+///
+/// A single letter, or the same letter typed repeatedly, moves past `current`
+/// to the next item starting with that letter, so repeating it cycles through them.
+fn type_ahead_index(
+    len: usize,
+    current: Option<usize>,
+    typed: &str,
+    starts_with: impl Fn(usize, &str) -> bool,
+) -> Option<usize> {
+    let first = typed.chars().next()?;
+    let repeated = typed
+        .chars()
+        .all(|ch| ch.to_lowercase().eq(first.to_lowercase()));
+
+    let (prefix, start) = if repeated {
+        (&typed[..first.len_utf8()], current.map_or(0, |idx| idx + 1))
+    } else {
+        (typed, current.unwrap_or(0))
+    };
+    let start = start.min(len);
+
+    (start..len)
+        .chain(0..start)
+        .find(|&idx| starts_with(idx, prefix))
+}
+
+/// Matches item names against a search query, keeping insertion order for
+/// prefix and substring matches and putting the best fuzzy matches first.
+pub(crate) fn match_item_names<'a>(
+    query: &str,
+    names: impl Iterator<Item = &'a SharedString>,
+    filter: SelectFilter,
+) -> Vec<SelectItemMatch> {
+    let query = query.trim();
+
+    match filter {
+        SelectFilter::Prefix => names
+            .filter(|name| starts_with_ignore_case(name, query))
+            .map(|name| SelectItemMatch {
+                name: name.clone(),
+                ranges: vec![0..prefix_len(name, query)],
+            })
+            .collect(),
+        SelectFilter::Substring => names
+            .filter_map(|name| {
+                let range = find_ignore_case(name, query)?;
+                Some(SelectItemMatch {
+                    name: name.clone(),
+                    ranges: vec![range],
+                })
+            })
+            .collect(),
+        SelectFilter::Fuzzy => {
+            let mut matches = names
+                .filter_map(|name| {
+                    let fuzzy = fuzzy_match(query, name)?;
+                    let item_match = SelectItemMatch {
+                        name: name.clone(),
+                        ranges: fuzzy.ranges(name),
+                    };
+                    Some((fuzzy.score, item_match))
+                })
+                .collect::<Vec<_>>();
+
+            matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+            matches
+                .into_iter()
+                .map(|(_, item_match)| item_match)
+                .collect()
+        }
+    }
+}
+
+/// Returns true if `text` starts with `prefix`, ignoring case.
+fn starts_with_ignore_case(text: &str, prefix: &str) -> bool {
+    find_ignore_case(text, prefix).is_some_and(|range| range.start == 0)
+}
+
+/// Returns the byte length of the start of `text` matched by `prefix`.
+fn prefix_len(text: &str, prefix: &str) -> usize {
+    find_ignore_case(text, prefix).map_or(0, |range| range.end)
+}
+
+/// Returns the byte range of the first occurrence of `query` in `text`, ignoring case.
+fn find_ignore_case(text: &str, query: &str) -> Option<Range<usize>> {
+    let chars_match = |a: char, b: char| a.to_lowercase().eq(b.to_lowercase());
+
+    text.char_indices()
+        .map(|(start, _)| start)
+        .find_map(|start| {
+            let mut text_chars = text[start..].char_indices();
+            let mut end = start;
+
+            for query_char in query.chars() {
+                let (offset, text_char) = text_chars.next()?;
+
+                if !chars_match(text_char, query_char) {
+                    return None;
+                }
+
+                end = start + offset + text_char.len_utf8();
+            }
+
+            Some(start..end)
+        })
+}
+
+/// Appends a typed char to the type-ahead text, ignoring leading whitespace.
+fn type_ahead_text(mut typed: String, ch: char) -> String {
+    if !(typed.is_empty() && ch.is_whitespace()) {
+        typed.push(ch);
+    }
+
+    typed
+}
+
 /// Registers key bindings for select menu navigation.
+///
+/// Must be called after `gpui_primitives::init` so the bindings scoped to the
+/// search input take precedence over the input's own up, down and enter bindings.
 pub fn init(cx: &mut App) {
     cx.bind_keys([
+        KeyBinding::new("down", Open, Some("Select")),
+        KeyBinding::new("enter", Open, Some("Select")),
+        KeyBinding::new("space", Open, Some("Select")),
         KeyBinding::new("up", MoveUp, Some("SelectMenu")),
         KeyBinding::new("down", MoveDown, Some("SelectMenu")),
+        KeyBinding::new("home", MoveToFirst, Some("SelectMenu")),
+        KeyBinding::new("end", MoveToLast, Some("SelectMenu")),
+        KeyBinding::new("pageup", PageUp, Some("SelectMenu")),
+        KeyBinding::new("pagedown", PageDown, Some("SelectMenu")),
         KeyBinding::new("enter", Confirm, Some("SelectMenu")),
+        KeyBinding::new("tab", ConfirmAndTab, Some("SelectMenu")),
+        KeyBinding::new("escape", Dismiss, Some("SelectMenu")),
+        // Home and end keep moving the cursor of the search input.
+        KeyBinding::new("up", MoveUp, Some("SelectMenu > TextInput")),
+        KeyBinding::new("down", MoveDown, Some("SelectMenu > TextInput")),
+        KeyBinding::new("pageup", PageUp, Some("SelectMenu > TextInput")),
+        KeyBinding::new("pagedown", PageDown, Some("SelectMenu > TextInput")),
+        KeyBinding::new("enter", Confirm, Some("SelectMenu > TextInput")),
+        KeyBinding::new("tab", ConfirmAndTab, Some("SelectMenu > TextInput")),
+        KeyBinding::new("escape", Dismiss, Some("SelectMenu > TextInput")),
     ]);
 }
 
+/// Hands out [`SelectItemsMap`] revisions. Shared between maps so a map that
+/// replaces another never reuses one of its revisions.
+static NEXT_ITEMS_REVISION: AtomicUsize = AtomicUsize::new(0);
+
+fn next_items_revision() -> usize {
+    NEXT_ITEMS_REVISION.fetch_add(1, Ordering::Relaxed)
+}
+
 /// An ordered map of select items that preserves insertion order.
-pub struct SelectItemsMap<V: 'static, I: SelectItem<Value = V> + 'static>(
-    IndexMap<SharedString, SelectItemEntry<I>>,
-);
+pub struct SelectItemsMap<V: 'static, I: SelectItem<Value = V> + 'static> {
+    items: IndexMap<SharedString, SelectItemEntry<I>>,
+    /// Changes whenever items are added or removed, so the menu knows to rebuild its rows.
+    pub(crate) revision: usize,
+}
 
 impl<V: 'static, I: SelectItem<Value = V> + 'static> SelectItemsMap<V, I> {
     /// Creates an empty items map.
     pub fn new() -> Self {
-        Self(IndexMap::new())
+        Self {
+            items: IndexMap::new(),
+            revision: next_items_revision(),
+        }
     }
 
     /// Adds an item to the map.
-    pub fn push_item(&mut self, cx: &mut App, item: impl Into<I>) {
-        let entry = SelectItemEntry::new(item.into(), cx);
-        self.0.insert(entry.item.name(), entry);
+    #[deprecated(note = "items no longer need an App context, use `SelectItemsMap::push`")]
+    pub fn push_item(&mut self, _cx: &mut App, item: impl Into<I>) {
+        self.push(item);
+    }
+
+    /// Adds an item to the map, after the last item of its group if it has one.
+    pub fn push(&mut self, item: impl Into<I>) {
+        let entry = SelectItemEntry::new(item.into());
+        let name = entry.item.name();
+        let group = entry.item.group();
+
+        // Keep the items of a group together so the group is listed under a single header.
+        let group_end = group
+            .is_some()
+            .then(|| {
+                self.items
+                    .values()
+                    .rposition(|other| other.item.group() == group)
+            })
+            .flatten();
+
+        match group_end {
+            Some(index) if !self.items.contains_key(&name) => {
+                self.items.shift_insert(index + 1, name, entry);
+            }
+            _ => {
+                self.items.insert(name, entry);
+            }
+        }
+
+        self.revision = next_items_revision();
+    }
+
+    /// Keeps only the items whose names satisfy `keep`, preserving their order.
+    pub(crate) fn retain(&mut self, mut keep: impl FnMut(&SharedString) -> bool) {
+        self.items.retain(|name, _| keep(name));
+        self.revision = next_items_revision();
     }
 
     /// Gets an item by name.
     pub fn get(&self, item_name: &SharedString) -> Option<&SelectItemEntry<I>> {
-        self.0.get(item_name)
+        self.items.get(item_name)
     }
 
     /// Returns the index of an item by name.
     pub fn get_index_of(&self, item_name: &SharedString) -> Option<usize> {
-        self.0.get_index_of(item_name)
+        self.items.get_index_of(item_name)
     }
 
     /// Gets an item by index.
     pub fn get_index(&self, index: usize) -> Option<(&SharedString, &SelectItemEntry<I>)> {
-        self.0.get_index(index)
+        self.items.get_index(index)
     }
 
     /// Returns the first item.
     pub fn first(&self) -> Option<(&SharedString, &SelectItemEntry<I>)> {
-        self.0.first()
+        self.items.first()
     }
 
     /// Returns the last item.
     pub fn last(&self) -> Option<(&SharedString, &SelectItemEntry<I>)> {
-        self.0.last()
+        self.items.last()
+    }
+
+    /// Returns true if the named item exists and is disabled.
+    pub fn is_disabled(&self, item_name: &SharedString) -> bool {
+        self.get(item_name)
+            .is_some_and(|entry| entry.item.disabled())
     }
 
     /// Returns the number of items.
     pub fn len(&self) -> usize {
-        self.0.len()
+        self.items.len()
     }
 
     /// Returns true if the map contains no items.
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.items.is_empty()
     }
 
     /// Iterates over all items in insertion order.
     pub fn iter(&self) -> impl Iterator<Item = (&SharedString, &SelectItemEntry<I>)> {
-        self.0.iter()
+        self.items.iter()
     }
 }
 
@@ -0,0 +1,1036 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    AnyElement, App, Edges, ElementId, FocusHandle, InteractiveElement, IntoElement, KeyBinding,
+    Length, ParentElement, Pixels, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
+    Window, actions, div, ease_out_quint, prelude::FluentBuilder, px, radians, relative,
+};
+
+use crate::{
+    ElementIdExt, TesseraeIconKind,
+    components::{Button, ButtonVariant, Icon},
+    conitional_transition,
+    extensions::{
+        mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+        mouse_handleable::MouseHandleable,
+    },
+    primitives::min_w0_wrapper,
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{PositionalParentElement, RovingNavigation, chevron_rotation, navigation_target},
+};
+
+actions!(
+    sidebar,
+    [
+        FocusPrev,
+        FocusNext,
+        FocusFirst,
+        FocusLast,
+        ActivateItem,
+        ExpandGroup,
+        CollapseGroup
+    ]
+);
+
+/// Registers key bindings for moving between, activating and expanding sidebar items.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", FocusPrev, Some("Sidebar")),
+        KeyBinding::new("down", FocusNext, Some("Sidebar")),
+        KeyBinding::new("home", FocusFirst, Some("Sidebar")),
+        KeyBinding::new("end", FocusLast, Some("Sidebar")),
+        KeyBinding::new("enter", ActivateItem, Some("Sidebar")),
+        KeyBinding::new("space", ActivateItem, Some("Sidebar")),
+        KeyBinding::new("right", ExpandGroup, Some("Sidebar")),
+        KeyBinding::new("left", CollapseGroup, Some("Sidebar")),
+    ]);
+}
+
+/// Type alias for the sidebar selection callback.
+pub type OnSidebarSelectFn = Rc<dyn Fn(&SharedString, &mut Window, &mut App)>;
+
+/// Width of the active item indicator.
+const INDICATOR_WIDTH: f32 = 3.;
+
+/// Height of the active item indicator.
+const INDICATOR_HEIGHT: f32 = 16.;
+
+/// Size of nav item icons.
+const ICON_SIZE: f32 = 14.;
+
+/// A navigation item within a [`SidebarSection`].
+///
+/// Items with nested items act as groups: activating them expands or
+/// collapses their children instead of selecting them.
+pub struct SidebarItem {
+    value: SharedString,
+    label: Option<SharedString>,
+    icon: Option<SharedString>,
+    disabled: bool,
+    items: Vec<SidebarItem>,
+}
+
+impl SidebarItem {
+    /// Creates a new item identified by `value`.
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: None,
+            icon: None,
+            disabled: false,
+            items: Vec::new(),
+        }
+    }
+
+    /// Sets the text shown next to the icon.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the icon, which is all that is shown while the sidebar is collapsed.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the disabled state, preventing this item from being activated.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Adds a nested item, turning this item into a group.
+    pub fn item(mut self, item: SidebarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Adds multiple nested items, turning this item into a group.
+    pub fn items(mut self, items: impl IntoIterator<Item = SidebarItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+
+    fn is_group(&self) -> bool {
+        !self.items.is_empty()
+    }
+
+    /// Returns true if `value` is this item or one of its descendants.
+    fn contains(&self, value: &str) -> bool {
+        self.value == value || self.items.iter().any(|item| item.contains(value))
+    }
+
+    /// Returns the first enabled item that isn't a group, searching depth first.
+    fn first_enabled_leaf(&self) -> Option<&SharedString> {
+        if self.disabled {
+            return None;
+        }
+
+        if !self.is_group() {
+            return Some(&self.value);
+        }
+
+        self.items.iter().find_map(SidebarItem::first_enabled_leaf)
+    }
+}
+
+/// A titled group of items within a [`Sidebar`].
+#[derive(Default)]
+pub struct SidebarSection {
+    title: Option<SharedString>,
+    items: Vec<SidebarItem>,
+}
+
+impl SidebarSection {
+    /// Creates a new, untitled section.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the header text shown above the section's items.
+    pub fn title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Adds an item.
+    pub fn item(mut self, item: SidebarItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// Adds multiple items.
+    pub fn items(mut self, items: impl IntoIterator<Item = SidebarItem>) -> Self {
+        self.items.extend(items);
+        self
+    }
+}
+
+/// A navigation panel of sections and nested groups that can collapse to its icons.
+#[derive(IntoElement)]
+pub struct Sidebar {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    sections: Vec<SidebarSection>,
+    selected: Option<SharedString>,
+    collapsed: bool,
+    focus_handle: Option<FocusHandle>,
+    on_select: Option<OnSidebarSelectFn>,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+    width: Pixels,
+    height: Length,
+}
+
+impl Sidebar {
+    /// Creates a new sidebar with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            sections: Vec::new(),
+            selected: None,
+            collapsed: false,
+            focus_handle: None,
+            on_select: None,
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+            width: px(240.),
+            height: relative(1.).into(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width while expanded. Defaults to 240px.
+    pub fn w(mut self, width: impl Into<Pixels>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height. Defaults to the full height of the parent.
+    pub fn h(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Adds a section.
+    pub fn section(mut self, section: SidebarSection) -> Self {
+        self.sections.push(section);
+        self
+    }
+
+    /// Adds multiple sections.
+    pub fn sections(mut self, sections: impl IntoIterator<Item = SidebarSection>) -> Self {
+        self.sections.extend(sections);
+        self
+    }
+
+    /// Controls which item is active. When unset, the sidebar tracks its own
+    /// selection, starting with no active item.
+    pub fn selected(mut self, value: impl Into<SharedString>) -> Self {
+        self.selected = Some(value.into());
+        self
+    }
+
+    /// Collapses the sidebar to its icons, animating its width. Nested items
+    /// are hidden while collapsed and groups select their first item instead.
+    pub fn collapsed(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
+        self
+    }
+
+    /// Sets the focus handle for the sidebar itself, letting the arrow keys
+    /// move into its items while it is focused.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
+    /// Sets a callback invoked with the value of the item that was selected.
+    pub fn on_select(
+        mut self,
+        on_select: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(on_select));
+        self
+    }
+}
+
+impl MouseBehaviorExt for Sidebar {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for Sidebar {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let padding = cx.get_theme().layout.padding.md;
+        let item_padding = cx.get_theme().layout.padding.lg;
+        let caption_size = cx
+            .get_theme()
+            .layout
+            .text
+            .default_font
+            .sizes
+            .caption
+            .clone();
+        let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
+        let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+
+        let selected_state =
+            window.use_keyed_state(self.id.with_suffix("state:selected"), cx, |_cx, _window| {
+                None::<SharedString>
+            });
+        let is_controlled = self.selected.is_some();
+        let selected = self
+            .selected
+            .clone()
+            .or_else(|| selected_state.read(cx).clone());
+
+        // Groups holding the initial selection start expanded so it is visible.
+        let expanded_state = {
+            let sections = &self.sections;
+            let selected = selected.clone();
+
+            window.use_keyed_state(
+                self.id.with_suffix("state:expanded"),
+                cx,
+                move |_cx, _window| {
+                    selected
+                        .map(|selected| expanded_groups_for(sections, &selected))
+                        .unwrap_or_default()
+                },
+            )
+        };
+        let expanded = expanded_state.read(cx).clone();
+
+        let focus_handle = self
+            .focus_handle
+            .as_ref()
+            .unwrap_or_else(|| {
+                window
+                    .use_keyed_state(
+                        self.id.with_suffix("state:focus_handle"),
+                        cx,
+                        |_window, cx| cx.focus_handle(),
+                    )
+                    .read(cx)
+            })
+            .clone();
+
+        let expand_delta = *conitional_transition!(
+            self.id.with_suffix("state:transition:expand"),
+            window,
+            cx,
+            Duration::from_millis(285),
+            {
+                self.collapsed => 0.,
+                _ => 1.
+            }
+        )
+        .with_easing(ease_out_quint())
+        .evaluate(window, cx);
+
+        let collapsed_width = px(ICON_SIZE) + item_padding * 2. + padding * 2.;
+        let width = collapsed_width + (self.width - collapsed_width) * expand_delta;
+        let show_labels = expand_delta > 0.;
+
+        let entries: Rc<[SidebarEntry]> = visible_entries(
+            &self.sections,
+            &expanded,
+            self.collapsed,
+            selected.as_deref(),
+        )
+        .into();
+
+        let focus_handles: Rc<[FocusHandle]> = entries
+            .iter()
+            .map(|entry| {
+                window
+                    .use_keyed_state(
+                        self.id
+                            .with_suffix(format!("item:{}", entry.value))
+                            .with_suffix("state:focus_handle"),
+                        cx,
+                        |_window, cx| cx.focus_handle().tab_stop(true),
+                    )
+                    .read(cx)
+                    .clone()
+            })
+            .collect();
+
+        let disabled: Rc<[bool]> = entries.iter().map(|entry| entry.disabled).collect();
+
+        let select: Rc<dyn Fn(&SharedString, &mut Window, &mut App)> = {
+            let on_select = self.on_select.clone();
+
+            Rc::new(move |value, window, cx| {
+                if !is_controlled {
+                    selected_state.update(cx, |this, cx| {
+                        *this = Some(value.clone());
+                        cx.notify();
+                    });
+                }
+
+                if let Some(on_select) = on_select.as_ref() {
+                    (on_select)(value, window, cx);
+                }
+            })
+        };
+
+        let set_expanded: Rc<dyn Fn(&SharedString, bool, &mut App)> = {
+            let expanded_state = expanded_state.clone();
+
+            Rc::new(move |value, expand, cx| {
+                expanded_state.update(cx, |this, cx| {
+                    let is_expanded = this.contains(value);
+
+                    if expand && !is_expanded {
+                        this.push(value.clone());
+                    } else if !expand && is_expanded {
+                        this.retain(|expanded| expanded != value);
+                    } else {
+                        return;
+                    }
+
+                    cx.notify();
+                });
+            })
+        };
+
+        let activate: Rc<dyn Fn(usize, &mut Window, &mut App)> = {
+            let entries = entries.clone();
+            let set_expanded = set_expanded.clone();
+            let is_collapsed = self.collapsed;
+
+            Rc::new(move |idx, window, cx| {
+                let entry = &entries[idx];
+
+                if entry.disabled {
+                    return;
+                }
+
+                if !entry.is_group {
+                    (select)(&entry.value, window, cx);
+                } else if is_collapsed {
+                    if let Some(leaf) = entry.first_leaf.as_ref() {
+                        (select)(leaf, window, cx);
+                    }
+                } else {
+                    (set_expanded)(&entry.value, !entry.is_expanded, cx);
+                }
+            })
+        };
+
+        let focused_index = {
+            let focus_handles = focus_handles.clone();
+
+            move |window: &Window| {
+                focus_handles
+                    .iter()
+                    .position(|focus_handle| focus_handle.is_focused(window))
+            }
+        };
+
+        let navigate = {
+            let focus_handles = focus_handles.clone();
+            let disabled = disabled.clone();
+            let focused_index = focused_index.clone();
+
+            Rc::new(
+                move |navigation: RovingNavigation, window: &mut Window, cx: &mut App| {
+                    let focused = focused_index(window);
+
+                    // Moving into the sidebar from outside its items starts at either end.
+                    let navigation = match (focused, navigation) {
+                        (None, RovingNavigation::Next) => RovingNavigation::First,
+                        (None, RovingNavigation::Prev) => RovingNavigation::Last,
+                        _ => navigation,
+                    };
+
+                    if let Some(idx) = navigation_target(navigation, focused, &disabled) {
+                        focus_handles[idx].focus(window, cx);
+                    }
+                },
+            )
+        };
+        let (navigate_prev, navigate_next, navigate_first, navigate_last) = (
+            navigate.clone(),
+            navigate.clone(),
+            navigate.clone(),
+            navigate,
+        );
+
+        let activate_focused = {
+            let activate = activate.clone();
+            let focused_index = focused_index.clone();
+
+            move |_: &ActivateItem, window: &mut Window, cx: &mut App| {
+                if let Some(idx) = focused_index(window) {
+                    (activate)(idx, window, cx);
+                }
+            }
+        };
+
+        let expand_focused = {
+            let entries = entries.clone();
+            let focus_handles = focus_handles.clone();
+            let set_expanded = set_expanded.clone();
+            let focused_index = focused_index.clone();
+
+            move |_: &ExpandGroup, window: &mut Window, cx: &mut App| {
+                let Some(idx) = focused_index(window) else {
+                    return;
+                };
+                let entry = &entries[idx];
+
+                if !entry.is_group || entry.disabled {
+                    return;
+                }
+
+                if !entry.is_expanded {
+                    (set_expanded)(&entry.value, true, cx);
+                } else if let Some(child) = (idx + 1..entries.len())
+                    .find(|&child| entries[child].parent == Some(idx) && !entries[child].disabled)
+                {
+                    focus_handles[child].focus(window, cx);
+                }
+            }
+        };
+
+        let collapse_focused = {
+            let entries = entries.clone();
+            let focus_handles = focus_handles.clone();
+
+            move |_: &CollapseGroup, window: &mut Window, cx: &mut App| {
+                let Some(idx) = focused_index(window) else {
+                    return;
+                };
+                let entry = &entries[idx];
+
+                if entry.is_group && entry.is_expanded {
+                    (set_expanded)(&entry.value, false, cx);
+                } else if let Some(parent) = entry.parent {
+                    focus_handles[parent].focus(window, cx);
+                }
+            }
+        };
+
+        let behavior = self.mouse_behavior;
+        let section_titles: Vec<Option<SharedString>> = self
+            .sections
+            .into_iter()
+            .map(|section| section.title)
+            .collect();
+
+        div()
+            .id(self.id.clone())
+            .key_context("Sidebar")
+            .track_focus(&focus_handle)
+            .w(width)
+            .h(self.height)
+            .flex_shrink_0()
+            .flex()
+            .flex_col()
+            .overflow_hidden()
+            .bg(background_color)
+            .border_r_1()
+            .border_color(border_color)
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .on_action(move |_: &FocusPrev, window, cx| {
+                (navigate_prev)(RovingNavigation::Prev, window, cx)
+            })
+            .on_action(move |_: &FocusNext, window, cx| {
+                (navigate_next)(RovingNavigation::Next, window, cx)
+            })
+            .on_action(move |_: &FocusFirst, window, cx| {
+                (navigate_first)(RovingNavigation::First, window, cx)
+            })
+            .on_action(move |_: &FocusLast, window, cx| {
+                (navigate_last)(RovingNavigation::Last, window, cx)
+            })
+            .on_action(activate_focused)
+            .on_action(expand_focused)
+            .on_action(collapse_focused)
+            .child(
+                div()
+                    .id(self.id.with_suffix("items"))
+                    .flex_1()
+                    .min_h_0()
+                    .overflow_y_scroll()
+                    .flex()
+                    .flex_col()
+                    .gap(px(2.))
+                    .p(padding)
+                    .children(section_titles.into_iter().enumerate().map(
+                        |(section_idx, title)| {
+                            let mut children: Vec<AnyElement> = Vec::new();
+
+                            if self.collapsed && section_idx > 0 {
+                                children.push(
+                                    div()
+                                        .mx(item_padding)
+                                        .my(padding)
+                                        .h(px(1.))
+                                        .bg(border_color)
+                                        .into_any_element(),
+                                );
+                            } else if let Some(title) = title.filter(|_| !self.collapsed) {
+                                children.push(
+                                    min_w0_wrapper()
+                                        .text_ellipsis()
+                                        .px(item_padding)
+                                        .pt(if section_idx > 0 {
+                                            padding * 2.
+                                        } else {
+                                            padding
+                                        })
+                                        .pb(padding)
+                                        .text_size(caption_size.clone())
+                                        .text_color(secondary_text_color)
+                                        .opacity(expand_delta)
+                                        .child(title)
+                                        .into_any_element(),
+                                );
+                            }
+
+                            children.extend(
+                                entries
+                                    .iter()
+                                    .enumerate()
+                                    .filter(|(_, entry)| entry.section == section_idx)
+                                    .map(|(idx, entry)| {
+                                        let item_id =
+                                            self.id.with_suffix(format!("item:{}", entry.value));
+                                        let is_active = selected.as_ref() == Some(&entry.value)
+                                            || entry.holds_hidden_selection;
+
+                                        let indicator_delta = *conitional_transition!(
+                                            item_id.with_suffix("state:transition:indicator"),
+                                            window,
+                                            cx,
+                                            Duration::from_millis(250),
+                                            {
+                                                is_active => 1.,
+                                                _ => 0.
+                                            }
+                                        )
+                                        .with_easing(ease_out_quint())
+                                        .evaluate(window, cx);
+
+                                        let expanded_delta = *conitional_transition!(
+                                            item_id.with_suffix("state:transition:expanded"),
+                                            window,
+                                            cx,
+                                            Duration::from_millis(285),
+                                            {
+                                                entry.is_expanded => 1.,
+                                                _ => 0.
+                                            }
+                                        )
+                                        .with_easing(ease_out_quint())
+                                        .evaluate(window, cx);
+
+                                        let activate = activate.clone();
+
+                                        let button = Button::new(item_id)
+                                            .variant(ButtonVariant::TertiaryGhost)
+                                            .focus_handle(focus_handles[idx].clone())
+                                            .disabled(entry.disabled)
+                                            .force_hover(is_active)
+                                            .w_full()
+                                            .icon_size(px(ICON_SIZE))
+                                            .pl(item_padding * (entry.depth + 1) as f32)
+                                            .map(|this| match entry.icon.clone() {
+                                                Some(icon) => this.icon(icon),
+                                                None if !show_labels => {
+                                                    this.text(entry.initial().unwrap_or_default())
+                                                }
+                                                None => this,
+                                            })
+                                            .when_some(
+                                                entry.label.clone().filter(|_| show_labels),
+                                                |this, label| this.text(label),
+                                            )
+                                            .map(|this| {
+                                                if entry.is_group && show_labels {
+                                                    this.justify_between().child_right(
+                                                        Icon::new(TesseraeIconKind::ArrowDown)
+                                                            .size(px(11.))
+                                                            .color(secondary_text_color)
+                                                            .rotate(radians(chevron_rotation(
+                                                                expanded_delta,
+                                                            ))),
+                                                    )
+                                                } else {
+                                                    this.justify_start()
+                                                }
+                                            })
+                                            .on_click(move |_event, window, cx| {
+                                                behavior.apply(window, cx);
+                                                (activate)(idx, window, cx);
+                                            });
+
+                                        div()
+                                            .relative()
+                                            .w_full()
+                                            .child(button)
+                                            .child(
+                                                div()
+                                                    .absolute()
+                                                    .top_0()
+                                                    .bottom_0()
+                                                    .left(-padding)
+                                                    .flex()
+                                                    .items_center()
+                                                    .child(
+                                                        div()
+                                                            .w(px(INDICATOR_WIDTH))
+                                                            .h(px(INDICATOR_HEIGHT)
+                                                                * indicator_delta)
+                                                            .rounded_r(px(INDICATOR_WIDTH))
+                                                            .bg(primary_accent_color)
+                                                            .opacity(indicator_delta),
+                                                    ),
+                                            )
+                                            .into_any_element()
+                                    }),
+                            );
+
+                            div().flex().flex_col().gap(px(2.)).children(children)
+                        },
+                    )),
+            )
+    }
+}
+
+/// An item currently shown in the sidebar, in render order.
+#[derive(Clone, Debug, PartialEq)]
+struct SidebarEntry {
+    value: SharedString,
+    label: Option<SharedString>,
+    icon: Option<SharedString>,
+    disabled: bool,
+    section: usize,
+    depth: usize,
+    parent: Option<usize>,
+    is_group: bool,
+    is_expanded: bool,
+    /// The item a group selects while the sidebar is collapsed.
+    first_leaf: Option<SharedString>,
+    /// Whether the selected item is nested somewhere out of sight below this group.
+    holds_hidden_selection: bool,
+}
+
+impl SidebarEntry {
+    /// Returns the first letter of the label, shown in place of a missing icon
+    /// while collapsed.
+    fn initial(&self) -> Option<SharedString> {
+        let initial = self.label.as_ref()?.chars().next()?;
+        Some(initial.to_uppercase().collect::<String>().into())
+    }
+}
+
+/// Flattens the items that are currently visible, skipping the children of
+/// collapsed groups and every nested item while the sidebar itself is collapsed.
+fn visible_entries(
+    sections: &[SidebarSection],
+    expanded: &[SharedString],
+    collapsed: bool,
+    selected: Option<&str>,
+) -> Vec<SidebarEntry> {
+    let mut entries = Vec::new();
+
+    for (section_idx, section) in sections.iter().enumerate() {
+        // Walks the section depth first, keeping the items in render order.
+        let mut stack: Vec<(&SidebarItem, usize, Option<usize>)> = section
+            .items
+            .iter()
+            .rev()
+            .map(|item| (item, 0, None))
+            .collect();
+
+        while let Some((item, depth, parent)) = stack.pop() {
+            let is_group = item.is_group();
+            let is_expanded = is_group && !collapsed && expanded.contains(&item.value);
+            let holds_hidden_selection = is_group
+                && !is_expanded
+                && selected.is_some_and(|selected| item.contains(selected));
+
+            let idx = entries.len();
+            entries.push(SidebarEntry {
+                value: item.value.clone(),
+                label: item.label.clone(),
+                icon: item.icon.clone(),
+                disabled: item.disabled,
+                section: section_idx,
+                depth,
+                parent,
+                is_group,
+                is_expanded,
+                first_leaf: item.first_enabled_leaf().cloned(),
+                holds_hidden_selection,
+            });
+
+            if is_expanded {
+                stack.extend(
+                    item.items
+                        .iter()
+                        .rev()
+                        .map(|child| (child, depth + 1, Some(idx))),
+                );
+            }
+        }
+    }
+
+    entries
+}
+
+/// Returns the groups that have to be expanded for `selected` to be visible.
+fn expanded_groups_for(sections: &[SidebarSection], selected: &str) -> Vec<SharedString> {
+    fn collect(items: &[SidebarItem], selected: &str, groups: &mut Vec<SharedString>) {
+        for item in items {
+            if item.is_group() && item.value != selected && item.contains(selected) {
+                groups.push(item.value.clone());
+                collect(&item.items, selected, groups);
+            }
+        }
+    }
+
+    let mut groups = Vec::new();
+    for section in sections {
+        collect(&section.items, selected, &mut groups);
+    }
+
+    groups
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use crate::theme::Theme;
+    use gpui::{Render, TestAppContext, VisualTestContext};
+
+    fn sections() -> Vec<SidebarSection> {
+        vec![
+            SidebarSection::new()
+                .title("Workspace")
+                .item(SidebarItem::new("inbox").label("Inbox"))
+                .item(
+                    SidebarItem::new("projects")
+                        .label("Projects")
+                        .item(SidebarItem::new("alpha").label("Alpha").disabled(true))
+                        .item(
+                            SidebarItem::new("beta")
+                                .label("Beta")
+                                .item(SidebarItem::new("beta-docs").label("Docs")),
+                        ),
+                ),
+            SidebarSection::new().item(SidebarItem::new("settings").label("Settings")),
+        ]
+    }
+
+    fn values(entries: &[SidebarEntry]) -> Vec<&str> {
+        entries.iter().map(|entry| entry.value.as_ref()).collect()
+    }
+
+    #[gpui::test]
+    fn test_sidebar_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let sidebar = Sidebar::new("test-sidebar")
+                .sections(sections())
+                .section(SidebarSection::new().title("Other"))
+                .selected("inbox")
+                .collapsed(true)
+                .w(px(200.))
+                .on_select(|_value, _window, _cx| {});
+
+            assert_eq!(sidebar.sections.len(), 3);
+            assert_eq!(sidebar.sections[0].items.len(), 2);
+            assert!(
+                sidebar.sections[0].items[1].is_group(),
+                "Projects should be a group"
+            );
+            assert_eq!(sidebar.selected, Some("inbox".into()));
+            assert!(sidebar.collapsed, "Sidebar should be collapsed");
+            assert_eq!(sidebar.width, px(200.));
+            assert!(sidebar.on_select.is_some());
+            assert!(sidebar.layer.is_none());
+        });
+    }
+
+    #[test]
+    fn test_visible_entries() {
+        let sections = sections();
+
+        let entries = visible_entries(&sections, &[], false, None);
+        assert_eq!(values(&entries), vec!["inbox", "projects", "settings"]);
+        assert_eq!(entries[2].section, 1);
+
+        let expanded = ["projects".into(), "beta".into()];
+        let entries = visible_entries(&sections, &expanded, false, None);
+        assert_eq!(
+            values(&entries),
+            vec![
+                "inbox",
+                "projects",
+                "alpha",
+                "beta",
+                "beta-docs",
+                "settings"
+            ]
+        );
+        assert_eq!(entries[3].depth, 1);
+        assert_eq!(entries[3].parent, Some(1));
+        assert_eq!(entries[4].depth, 2);
+        assert_eq!(entries[4].parent, Some(3));
+
+        let entries = visible_entries(&sections, &expanded, true, Some("beta-docs"));
+        assert_eq!(values(&entries), vec!["inbox", "projects", "settings"]);
+        assert!(
+            entries[1].holds_hidden_selection,
+            "Collapsed sidebar should mark the group holding the selection"
+        );
+        assert_eq!(entries[1].first_leaf, Some("beta-docs".into()));
+    }
+
+    #[test]
+    fn test_expanded_groups_for() {
+        let sections = sections();
+
+        assert_eq!(
+            expanded_groups_for(&sections, "beta-docs"),
+            vec![SharedString::from("projects"), SharedString::from("beta")]
+        );
+        assert_eq!(
+            expanded_groups_for(&sections, "beta"),
+            vec![SharedString::from("projects")]
+        );
+        assert!(expanded_groups_for(&sections, "inbox").is_empty());
+    }
+
+    #[gpui::test]
+    fn test_sidebar_renders_in_window(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |window, cx| {
+                let focus_handle = cx.focus_handle();
+                focus_handle.focus(window, cx);
+                cx.new(|_cx| SidebarTestView {
+                    focus_handle,
+                    selections: Vec::new(),
+                })
+            })
+            .unwrap()
+        });
+
+        let _cx = VisualTestContext::from_window(window.into(), cx);
+    }
+
+    #[gpui::test]
+    fn test_sidebar_keyboard_navigation(cx: &mut TestAppContext) {
+        let (view, cx) = cx.add_window_view(|window, cx| {
+            cx.set_theme(Theme::DEFAULT);
+            init(cx);
+
+            let focus_handle = cx.focus_handle();
+            focus_handle.focus(window, cx);
+
+            SidebarTestView {
+                focus_handle,
+                selections: Vec::new(),
+            }
+        });
+
+        // Inbox, then into Projects past the disabled Alpha to Beta, then on to Settings.
+        cx.simulate_keystrokes("down");
+        cx.simulate_keystrokes("enter");
+        cx.simulate_keystrokes("down");
+        cx.simulate_keystrokes("right");
+        cx.simulate_keystrokes("right");
+        cx.simulate_keystrokes("down");
+        cx.simulate_keystrokes("space");
+
+        cx.update(|_window, cx| {
+            assert_eq!(
+                view.read(cx).selections,
+                vec![SharedString::from("inbox"), SharedString::from("settings")]
+            );
+        });
+    }
+
+    struct SidebarTestView {
+        focus_handle: FocusHandle,
+        selections: Vec<SharedString>,
+    }
+
+    impl Render for SidebarTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            let entity = cx.entity();
+
+            div().size_full().child(
+                Sidebar::new("test-sidebar")
+                    .sections(sections())
+                    .focus_handle(self.focus_handle.clone())
+                    .on_select(move |value, _window, cx| {
+                        let value = value.clone();
+                        entity.update(cx, |this, cx| {
+                            this.selections.push(value);
+                            cx.notify();
+                        });
+                    }),
+            )
+        }
+    }
+}
@@ -0,0 +1,1123 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::{rc::Rc, time::Duration};
+
+use gpui::{
+    App, Bounds, CursorStyle, Edges, ElementId, FocusHandle, InteractiveElement, IntoElement,
+    KeyBinding, Length, ParentElement, Pixels, RenderOnce, SharedString,
+    StatefulInteractiveElement, Styled, Window, actions, canvas, div, ease_out_quint,
+    prelude::FluentBuilder, px, relative,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+use gpui_transitions::Lerp;
+
+use crate::{
+    ElementIdExt,
+    components::remap,
+    conitional_transition,
+    extensions::mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+    primitives::FocusRing,
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{RgbaExt, SquircleExt, disabled_transition},
+    views::RootWindowExt,
+};
+
+actions!(
+    slider,
+    [
+        Increment,
+        Decrement,
+        IncrementPage,
+        DecrementPage,
+        SetToMin,
+        SetToMax
+    ]
+);
+
+/// Registers key bindings for slider keyboard control.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("right", Increment, Some("Slider")),
+        KeyBinding::new("up", Increment, Some("Slider")),
+        KeyBinding::new("left", Decrement, Some("Slider")),
+        KeyBinding::new("down", Decrement, Some("Slider")),
+        KeyBinding::new("pageup", IncrementPage, Some("Slider")),
+        KeyBinding::new("pagedown", DecrementPage, Some("Slider")),
+        KeyBinding::new("home", SetToMin, Some("Slider")),
+        KeyBinding::new("end", SetToMax, Some("Slider")),
+    ]);
+}
+
+/// Tick marks are skipped when a step would produce more than this many.
+const MAX_TICKS: usize = 50;
+
+/// The value of a [`Slider`], either a single value or a two-thumb range.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SliderValue {
+    /// A single thumb.
+    Single(f32),
+    /// Two thumbs selecting the range between `start` and `end`.
+    Range(f32, f32),
+}
+
+impl SliderValue {
+    /// Returns the value of the first thumb.
+    pub fn start(&self) -> f32 {
+        match self {
+            SliderValue::Single(value) => *value,
+            SliderValue::Range(start, _) => *start,
+        }
+    }
+
+    /// Returns the value of the last thumb. Equal to [`Self::start`] for a single value.
+    pub fn end(&self) -> f32 {
+        match self {
+            SliderValue::Single(value) => *value,
+            SliderValue::Range(_, end) => *end,
+        }
+    }
+
+    /// Returns true if this is a two-thumb range.
+    pub fn is_range(&self) -> bool {
+        matches!(self, SliderValue::Range(..))
+    }
+
+    fn thumb(&self, thumb: SliderThumb) -> f32 {
+        match thumb {
+            SliderThumb::Start => self.start(),
+            SliderThumb::End => self.end(),
+        }
+    }
+
+    /// Moves one thumb, keeping range thumbs from crossing each other.
+    fn with_thumb(self, thumb: SliderThumb, value: f32) -> Self {
+        match (self, thumb) {
+            (SliderValue::Single(_), _) => SliderValue::Single(value),
+            (SliderValue::Range(_, end), SliderThumb::Start) => {
+                SliderValue::Range(value.min(end), end)
+            }
+            (SliderValue::Range(start, _), SliderThumb::End) => {
+                SliderValue::Range(start, value.max(start))
+            }
+        }
+    }
+
+    fn snapped(self, min: f32, max: f32, step: Option<f32>) -> Self {
+        match self {
+            SliderValue::Single(value) => SliderValue::Single(snap_value(value, min, max, step)),
+            SliderValue::Range(start, end) => {
+                let start = snap_value(start, min, max, step);
+                let end = snap_value(end, min, max, step);
+                SliderValue::Range(start.min(end), end.max(start))
+            }
+        }
+    }
+}
+
+impl From<f32> for SliderValue {
+    fn from(value: f32) -> Self {
+        SliderValue::Single(value)
+    }
+}
+
+impl From<(f32, f32)> for SliderValue {
+    fn from((start, end): (f32, f32)) -> Self {
+        SliderValue::Range(start, end)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SliderThumb {
+    Start,
+    End,
+}
+
+impl SliderThumb {
+    fn suffix(&self) -> &'static str {
+        match self {
+            SliderThumb::Start => "thumb:start",
+            SliderThumb::End => "thumb:end",
+        }
+    }
+}
+
+/// A draggable slider for picking a value, or a range of values, between a min and max.
+#[derive(IntoElement)]
+pub struct Slider {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    value: SliderValue,
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+    ticks: bool,
+    value_tooltip: bool,
+    format_value: Option<Box<dyn Fn(f32) -> SharedString + 'static>>,
+    disabled: bool,
+    force_hover: bool,
+    focus_handle: Option<FocusHandle>,
+    end_focus_handle: Option<FocusHandle>,
+    on_change: Option<Rc<dyn Fn(&SliderValue, &mut Window, &mut App) + 'static>>,
+    on_hover: Option<Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>>,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+    width: Length,
+}
+
+impl Slider {
+    /// Creates a new slider with the given element ID, ranging from 0 to 1.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            value: SliderValue::Single(0.),
+            min: 0.,
+            max: 1.,
+            step: None,
+            ticks: false,
+            value_tooltip: false,
+            format_value: None,
+            disabled: false,
+            force_hover: false,
+            focus_handle: None,
+            end_focus_handle: None,
+            on_change: None,
+            on_hover: None,
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+            width: relative(1.).into(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the focus handle of the first thumb for keyboard navigation.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
+    /// Sets the focus handle of the second thumb in range mode.
+    pub fn end_focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.end_focus_handle = Some(focus_handle);
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the current value. Pass a tuple for a two-thumb range.
+    pub fn value(mut self, value: impl Into<SliderValue>) -> Self {
+        self.value = value.into();
+        self
+    }
+
+    /// Sets the minimum value.
+    pub fn min(mut self, min: f32) -> Self {
+        self.min = min;
+        self
+    }
+
+    /// Sets the maximum value.
+    pub fn max(mut self, max: f32) -> Self {
+        self.max = max;
+        self
+    }
+
+    /// Snaps values to multiples of `step` from the minimum.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = (step > 0.).then_some(step);
+        self
+    }
+
+    /// Shows a tick mark at every step. Has no effect on continuous sliders.
+    pub fn ticks(mut self, ticks: bool) -> Self {
+        self.ticks = ticks;
+        self
+    }
+
+    /// Shows the value above each thumb while hovered, focused or dragged.
+    pub fn value_tooltip(mut self, value_tooltip: bool) -> Self {
+        self.value_tooltip = value_tooltip;
+        self
+    }
+
+    /// Sets how values are formatted in the value tooltip.
+    pub fn format_value(mut self, format_value: impl Fn(f32) -> SharedString + 'static) -> Self {
+        self.format_value = Some(Box::new(format_value));
+        self
+    }
+
+    /// Sets the disabled state, preventing interaction.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Forces the hover visual state regardless of actual hover.
+    pub fn force_hover(mut self, force_hover: bool) -> Self {
+        self.force_hover = force_hover;
+        self
+    }
+
+    /// Sets a callback invoked with the new value when a thumb is moved.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&SliderValue, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Sets a callback invoked when hover state changes.
+    pub fn on_hover(mut self, on_hover: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_hover = Some(Box::new(on_hover));
+        self
+    }
+}
+
+impl MouseBehaviorExt for Slider {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for Slider {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        const THUMB_SIZE_FOCUS_MULT: f32 = 1.25;
+
+        let thumb_size = cx.get_theme().layout.size.md;
+        let padding = cx.get_theme().layout.padding.md;
+        let height = thumb_size + (padding * 2);
+        let track_height = (padding * 1.5).round();
+        let track_top = (height - track_height) / 2.;
+        let half_thumb = thumb_size / 2.;
+        let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+        let primary_text_color = cx.get_theme().variants.active(cx).colors.text.primary;
+        let caption_size = cx.get_theme().layout.text.default_font.sizes.caption;
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let border_hover_color = border_color.lerp(&primary_text_color, 0.07);
+        let border_click_down_color = border_color.lerp(&primary_text_color, 0.16);
+
+        let (min, max, step) = (self.min, self.max, self.step);
+        let value = self.value.snapped(min, max, step);
+        let is_disabled = self.disabled;
+
+        let is_hover_state =
+            window.use_keyed_state(self.id.with_suffix("state:hover"), cx, |_cx, _window| false);
+        let is_hover = self.force_hover || *is_hover_state.read(cx);
+
+        // The thumb currently being dragged, if any.
+        let dragging_state =
+            window.use_keyed_state(self.id.with_suffix("state:dragging"), cx, |_cx, _window| {
+                None::<SliderThumb>
+            });
+        let dragging = *dragging_state.read(cx);
+
+        // Bounds of the rail the thumb centers travel along, captured during prepaint.
+        let rail_bounds_state = window.use_keyed_state(
+            self.id.with_suffix("state:rail_bounds"),
+            cx,
+            |_cx, _window| Bounds::<Pixels>::default(),
+        );
+
+        let thumbs: &[SliderThumb] = if value.is_range() {
+            &[SliderThumb::Start, SliderThumb::End]
+        } else {
+            &[SliderThumb::Start]
+        };
+
+        let focus_handles: Vec<(SliderThumb, FocusHandle)> = thumbs
+            .iter()
+            .map(|thumb| {
+                let provided = match thumb {
+                    SliderThumb::Start => self.focus_handle.as_ref(),
+                    SliderThumb::End => self.end_focus_handle.as_ref(),
+                };
+
+                let focus_handle = provided
+                    .unwrap_or_else(|| {
+                        window
+                            .use_keyed_state(
+                                self.id
+                                    .with_suffix(thumb.suffix())
+                                    .with_suffix("state:focus_handle"),
+                                cx,
+                                |_window, cx| cx.focus_handle().tab_stop(true),
+                            )
+                            .read(cx)
+                    })
+                    .clone();
+
+                (*thumb, focus_handle)
+            })
+            .collect();
+
+        let is_focus = focus_handles
+            .iter()
+            .any(|(_, focus_handle)| focus_handle.is_focused(window));
+
+        let disabled_transition = disabled_transition(self.id.clone(), window, cx, is_disabled);
+
+        if is_focus && is_disabled {
+            window.blur();
+        }
+
+        let on_change = self.on_change.clone();
+
+        // Keep tracking the pointer after it leaves the slider, until it is released.
+        if let Some(thumb) = dragging.filter(|_| !is_disabled) {
+            let rail_bounds_state = rail_bounds_state.clone();
+            let on_change = on_change.clone();
+
+            window.on_root_mouse_move(cx, move |event, window, cx| {
+                let bounds = *rail_bounds_state.read(cx);
+                let target = value_from_position(
+                    event.position.x.to_f64() as f32,
+                    bounds.origin.x.to_f64() as f32,
+                    bounds.size.width.to_f64() as f32,
+                    min,
+                    max,
+                    step,
+                );
+
+                emit_change(
+                    on_change.as_ref(),
+                    value,
+                    value.with_thumb(thumb, target),
+                    window,
+                    cx,
+                );
+            });
+
+            let dragging_state = dragging_state.clone();
+            window.on_root_mouse_up(cx, move |_event, _window, cx| {
+                dragging_state.update(cx, |this, cx| {
+                    *this = None;
+                    cx.notify();
+                });
+            });
+        }
+
+        let border_color_transition = conitional_transition!(
+            self.id.with_suffix("state:transition:border_color"),
+            window,
+            cx,
+            Duration::from_millis(365),
+            {
+                is_focus => primary_accent_color,
+                dragging.is_some() => border_click_down_color,
+                is_hover => border_hover_color,
+                _ => border_color
+            }
+        )
+        .with_easing(ease_out_quint());
+
+        let (fill_start, fill_end) = (
+            value_fraction(value.start(), min, max),
+            value_fraction(value.end(), min, max),
+        );
+
+        let tick_color = primary_text_color.alpha(0.35);
+        let tick_size = px(3.);
+        let ticks = if self.ticks {
+            tick_fractions(min, max, step)
+        } else {
+            Vec::new()
+        };
+
+        let format_value = self.format_value;
+        let behavior = self.mouse_behavior;
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .cursor(if is_disabled {
+                CursorStyle::OperationNotAllowed
+            } else {
+                CursorStyle::PointingHand
+            })
+            .w(self.width)
+            .min_w(thumb_size * 4.)
+            .h(height)
+            .min_h(height)
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .opacity(*disabled_transition.evaluate(window, cx))
+            .child(
+                div()
+                    .absolute()
+                    .left_0()
+                    .right_0()
+                    .top(track_top)
+                    .h(track_height)
+                    .child(
+                        squircle()
+                            .absolute_expand()
+                            .rounded(px(100.))
+                            .bg(background_color)
+                            .border(px(1.))
+                            .border_inside()
+                            .border_color(*border_color_transition.evaluate(window, cx)),
+                    ),
+            )
+            .child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .left(half_thumb)
+                    .right(half_thumb)
+                    .child({
+                        let rail_bounds_state = rail_bounds_state.clone();
+
+                        canvas(
+                            move |bounds, _window, cx| {
+                                rail_bounds_state.update(cx, |this, _cx| *this = bounds);
+                            },
+                            |_, _, _, _| {},
+                        )
+                        .absolute()
+                        .size_full()
+                    })
+                    .child(
+                        div()
+                            .absolute()
+                            .top(track_top)
+                            .h(track_height)
+                            .map(|this| match value {
+                                // A single thumb fills from the very start of the track.
+                                SliderValue::Single(_) => this.left(-half_thumb),
+                                SliderValue::Range(..) => this.left(relative(fill_start)),
+                            })
+                            .right(relative(1. - fill_end))
+                            .rounded(px(100.))
+                            .bg(primary_accent_color),
+                    )
+                    .children(ticks.into_iter().map(|fraction| {
+                        div()
+                            .absolute()
+                            .left(relative(fraction))
+                            .ml(-tick_size / 2.)
+                            .top(track_top + (track_height - tick_size) / 2.)
+                            .size(tick_size)
+                            .rounded(px(100.))
+                            .bg(tick_color)
+                    }))
+                    .children(focus_handles.iter().map(|(thumb, focus_handle)| {
+                        let thumb = *thumb;
+                        let thumb_id = self.id.with_suffix(thumb.suffix());
+                        let is_thumb_focus = focus_handle.is_focused(window);
+                        let is_thumb_dragging = dragging == Some(thumb);
+                        let thumb_value = value.thumb(thumb);
+
+                        // Like the switch, the thumb widens slightly when focused or dragged.
+                        let thumb_width_transition = conitional_transition!(
+                            thumb_id.with_suffix("state:transition:width"),
+                            window,
+                            cx,
+                            Duration::from_millis(185),
+                            {
+                                is_thumb_focus | is_thumb_dragging => px((thumb_size.to_f64() as f32 * THUMB_SIZE_FOCUS_MULT).floor()),
+                                _ => thumb_size
+                            }
+                        )
+                        .with_easing(ease_out_quint());
+                        let thumb_width = *thumb_width_transition.evaluate(window, cx);
+
+                        let show_tooltip = self.value_tooltip
+                            && !is_disabled
+                            && (is_hover || is_thumb_focus || is_thumb_dragging);
+                        let tooltip_label = match format_value.as_ref() {
+                            Some(format_value) => (format_value)(thumb_value),
+                            None => format_slider_value(thumb_value, step),
+                        };
+
+                        div()
+                            .id(thumb_id.clone())
+                            .key_context("Slider")
+                            .absolute()
+                            .top(padding)
+                            .left(relative(value_fraction(thumb_value, min, max)))
+                            .ml(-thumb_width / 2.)
+                            .w(thumb_width)
+                            .h(thumb_size)
+                            .child(
+                                FocusRing::new(thumb_id.with_suffix("focus_ring"), focus_handle.clone())
+                                    .rounded(px(100.)),
+                            )
+                            .child(
+                                squircle()
+                                    .absolute_expand()
+                                    .rounded(px(100.))
+                                    .bg(primary_text_color)
+                                    .border_inside()
+                                    .border(px(1.))
+                                    .border_highlight(0.15),
+                            )
+                            .when(show_tooltip, |this| {
+                                this.child(
+                                    div()
+                                        .absolute()
+                                        .left_0()
+                                        .right_0()
+                                        .bottom(thumb_size + padding)
+                                        .flex()
+                                        .justify_center()
+                                        .child(
+                                            div()
+                                                .relative()
+                                                .px(padding * 1.5)
+                                                .py(px(2.))
+                                                .whitespace_nowrap()
+                                                .text_size(caption_size)
+                                                .text_color(primary_text_color)
+                                                .child(
+                                                    squircle()
+                                                        .absolute_expand()
+                                                        .rounded(px(6.))
+                                                        .bg(border_color)
+                                                        .border_inside()
+                                                        .border(px(1.))
+                                                        .border_highlight(0.15),
+                                                )
+                                                .child(div().relative().child(tooltip_label)),
+                                        ),
+                                )
+                            })
+                            .when(!is_disabled, |this| {
+                                let on_change = on_change.clone();
+                                let step_size = keyboard_step(min, max, step);
+                                let page_size = page_step(min, max, step);
+
+                                let nudge = move |delta: f32| {
+                                    let on_change = on_change.clone();
+                                    move |window: &mut Window, cx: &mut App| {
+                                        let target =
+                                            snap_value(thumb_value + delta, min, max, step);
+                                        emit_change(
+                                            on_change.as_ref(),
+                                            value,
+                                            value.with_thumb(thumb, target),
+                                            window,
+                                            cx,
+                                        );
+                                    }
+                                };
+
+                                let increment = nudge(step_size);
+                                let decrement = nudge(-step_size);
+                                let increment_page = nudge(page_size);
+                                let decrement_page = nudge(-page_size);
+                                let set_to_min = nudge(min - thumb_value);
+                                let set_to_max = nudge(max - thumb_value);
+
+                                this.track_focus(focus_handle)
+                                    .on_action(move |_: &Increment, window, cx| increment(window, cx))
+                                    .on_action(move |_: &Decrement, window, cx| decrement(window, cx))
+                                    .on_action(move |_: &IncrementPage, window, cx| {
+                                        increment_page(window, cx)
+                                    })
+                                    .on_action(move |_: &DecrementPage, window, cx| {
+                                        decrement_page(window, cx)
+                                    })
+                                    .on_action(move |_: &SetToMin, window, cx| set_to_min(window, cx))
+                                    .on_action(move |_: &SetToMax, window, cx| set_to_max(window, cx))
+                            })
+                    })),
+            )
+            .when(!is_disabled, |this| {
+                let is_hover_state_on_hover = is_hover_state.clone();
+                let dragging_state_on_mouse_down = dragging_state.clone();
+                let rail_bounds_state_on_mouse_down = rail_bounds_state.clone();
+                let on_change_on_mouse_down = on_change.clone();
+
+                this.on_hover(move |hover, window, cx| {
+                    is_hover_state_on_hover.update(cx, |this, cx| {
+                        *this = *hover;
+                        cx.notify();
+                    });
+
+                    if let Some(callback) = self.on_hover.as_ref() {
+                        (callback)(hover, window, cx);
+                    }
+                })
+                .on_mouse_down(gpui::MouseButton::Left, move |event, window, cx| {
+                    behavior.apply(window, cx);
+
+                    let bounds = *rail_bounds_state_on_mouse_down.read(cx);
+                    let target = value_from_position(
+                        event.position.x.to_f64() as f32,
+                        bounds.origin.x.to_f64() as f32,
+                        bounds.size.width.to_f64() as f32,
+                        min,
+                        max,
+                        step,
+                    );
+                    let thumb = nearest_thumb(&value, target);
+
+                    if let Some((_, focus_handle)) = focus_handles.iter().find(|(t, _)| *t == thumb)
+                    {
+                        focus_handle.focus(window, cx);
+                    }
+
+                    dragging_state_on_mouse_down.update(cx, |this, cx| {
+                        *this = Some(thumb);
+                        cx.notify();
+                    });
+
+                    emit_change(
+                        on_change_on_mouse_down.as_ref(),
+                        value,
+                        value.with_thumb(thumb, target),
+                        window,
+                        cx,
+                    );
+                })
+            })
+    }
+}
+
+/// Invokes the change callback if the value actually changed.
+fn emit_change(
+    on_change: Option<&Rc<dyn Fn(&SliderValue, &mut Window, &mut App) + 'static>>,
+    old_value: SliderValue,
+    new_value: SliderValue,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    if new_value == old_value {
+        return;
+    }
+
+    if let Some(on_change) = on_change {
+        (on_change)(&new_value, window, cx);
+    }
+}
+
+/// Clamps a value to `min..=max` and rounds it to the nearest step, if any.
+fn snap_value(value: f32, min: f32, max: f32, step: Option<f32>) -> f32 {
+    let value = value.clamp(min, max.max(min));
+
+    match step {
+        Some(step) if step > 0. => {
+            let steps = ((value - min) / step).round();
+            (min + steps * step).min(max).max(min)
+        }
+        _ => value,
+    }
+}
+
+/// Returns where a value sits between `min` and `max`, from 0 to 1.
+fn value_fraction(value: f32, min: f32, max: f32) -> f32 {
+    if max <= min {
+        return 0.;
+    }
+
+    ((value - min) / (max - min)).clamp(0., 1.)
+}
+
+/// Converts a pointer x position over the rail into a snapped slider value.
+fn value_from_position(
+    x: f32,
+    rail_left: f32,
+    rail_width: f32,
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+) -> f32 {
+    let fraction = if rail_width <= 0. {
+        0.
+    } else {
+        ((x - rail_left) / rail_width).clamp(0., 1.)
+    };
+
+    snap_value(remap(fraction, 0., 1., min, max), min, max, step)
+}
+
+/// Picks the thumb that should follow a press at `target`.
+fn nearest_thumb(value: &SliderValue, target: f32) -> SliderThumb {
+    match *value {
+        SliderValue::Single(_) => SliderThumb::Start,
+        SliderValue::Range(start, end) => {
+            // When the thumbs overlap, pick whichever can move towards the target.
+            if target < start || (target - start).abs() < (target - end).abs() {
+                SliderThumb::Start
+            } else {
+                SliderThumb::End
+            }
+        }
+    }
+}
+
+/// The amount the arrow keys move a thumb by.
+fn keyboard_step(min: f32, max: f32, step: Option<f32>) -> f32 {
+    step.unwrap_or((max - min) / 100.)
+}
+
+/// The amount PageUp and PageDown move a thumb by, always a whole number of steps.
+fn page_step(min: f32, max: f32, step: Option<f32>) -> f32 {
+    let page = (max - min) / 10.;
+
+    match step {
+        Some(step) => (page / step).round().max(1.) * step,
+        None => page,
+    }
+}
+
+/// Returns the positions of each step as fractions of the track.
+fn tick_fractions(min: f32, max: f32, step: Option<f32>) -> Vec<f32> {
+    let Some(step) = step.filter(|_| max > min) else {
+        return Vec::new();
+    };
+
+    let count = ((max - min) / step).floor() as usize;
+    if count > MAX_TICKS {
+        return Vec::new();
+    }
+
+    (0..=count)
+        .map(|idx| value_fraction(min + idx as f32 * step, min, max))
+        .collect()
+}
+
+/// Formats a value with as many decimals as the step needs, or two for continuous sliders.
+fn format_slider_value(value: f32, step: Option<f32>) -> SharedString {
+    let decimals = step.map(step_decimals).unwrap_or(2);
+    format!("{value:.decimals$}").into()
+}
+
+fn step_decimals(step: f32) -> usize {
+    let mut decimals = 0;
+    let mut scaled = step;
+
+    while decimals < 4 && (scaled - scaled.round()).abs() > 1e-4 {
+        scaled *= 10.;
+        decimals += 1;
+    }
+
+    decimals
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::{AppContext, TestAppContext, VisualTestContext};
+
+    #[gpui::test]
+    fn test_slider_creation(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let slider = Slider::new("test-slider");
+            assert_eq!(slider.value, SliderValue::Single(0.));
+            assert_eq!((slider.min, slider.max), (0., 1.));
+            assert!(slider.step.is_none(), "Slider should start continuous");
+            assert!(!slider.disabled, "Slider should start enabled");
+            assert!(!slider.ticks && !slider.value_tooltip);
+        });
+    }
+
+    #[gpui::test]
+    fn test_slider_builder_chain(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let slider = Slider::new("test-slider")
+                .value((20., 80.))
+                .min(0.)
+                .max(100.)
+                .step(5.)
+                .ticks(true)
+                .value_tooltip(true)
+                .disabled(true)
+                .layer(ThemeLayerKind::Secondary);
+
+            assert_eq!(slider.value, SliderValue::Range(20., 80.));
+            assert_eq!((slider.min, slider.max), (0., 100.));
+            assert_eq!(slider.step, Some(5.));
+            assert!(slider.ticks && slider.value_tooltip && slider.disabled);
+            assert!(matches!(slider.layer, Some(ThemeLayerKind::Secondary)));
+        });
+    }
+
+    #[gpui::test]
+    fn test_slider_non_positive_step_is_continuous(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            assert!(Slider::new("test-slider").step(0.).step.is_none());
+            assert!(Slider::new("test-slider").step(-1.).step.is_none());
+        });
+    }
+
+    #[test]
+    fn test_snap_value() {
+        assert_eq!(snap_value(0.42, 0., 1., None), 0.42);
+        assert_eq!(snap_value(-5., 0., 1., None), 0.);
+        assert_eq!(snap_value(5., 0., 1., None), 1.);
+        assert_eq!(snap_value(23., 0., 100., Some(5.)), 25.);
+        assert_eq!(snap_value(22., 0., 100., Some(5.)), 20.);
+        // Values past the last whole step snap back to it.
+        assert_eq!(snap_value(99., 0., 10., Some(3.)), 9.);
+        assert_eq!(snap_value(12., 10., 20., Some(5.)), 10.);
+    }
+
+    #[test]
+    fn test_value_from_position() {
+        assert_eq!(value_from_position(100., 100., 200., 0., 10., None), 0.);
+        assert_eq!(value_from_position(200., 100., 200., 0., 10., None), 5.);
+        assert_eq!(value_from_position(300., 100., 200., 0., 10., None), 10.);
+        assert_eq!(value_from_position(0., 100., 200., 0., 10., None), 0.);
+        assert_eq!(value_from_position(500., 100., 200., 0., 10., None), 10.);
+        assert_eq!(value_from_position(162., 100., 200., 0., 10., Some(1.)), 3.);
+        assert_eq!(value_from_position(150., 100., 0., 0., 10., None), 0.);
+    }
+
+    #[test]
+    fn test_value_fraction() {
+        assert_eq!(value_fraction(5., 0., 10.), 0.5);
+        assert_eq!(value_fraction(-1., 0., 10.), 0.);
+        assert_eq!(value_fraction(11., 0., 10.), 1.);
+        assert_eq!(value_fraction(5., 5., 5.), 0.);
+    }
+
+    #[test]
+    fn test_range_thumbs_do_not_cross() {
+        let value = SliderValue::Range(20., 60.);
+        assert_eq!(
+            value.with_thumb(SliderThumb::Start, 80.),
+            SliderValue::Range(60., 60.)
+        );
+        assert_eq!(
+            value.with_thumb(SliderThumb::End, 10.),
+            SliderValue::Range(20., 20.)
+        );
+        assert_eq!(
+            SliderValue::Single(1.).with_thumb(SliderThumb::End, 3.),
+            SliderValue::Single(3.)
+        );
+    }
+
+    #[test]
+    fn test_nearest_thumb() {
+        let value = SliderValue::Range(20., 60.);
+        assert_eq!(nearest_thumb(&value, 10.), SliderThumb::Start);
+        assert_eq!(nearest_thumb(&value, 30.), SliderThumb::Start);
+        assert_eq!(nearest_thumb(&value, 50.), SliderThumb::End);
+        assert_eq!(nearest_thumb(&value, 90.), SliderThumb::End);
+
+        let overlapping = SliderValue::Range(40., 40.);
+        assert_eq!(nearest_thumb(&overlapping, 10.), SliderThumb::Start);
+        assert_eq!(nearest_thumb(&overlapping, 70.), SliderThumb::End);
+
+        assert_eq!(
+            nearest_thumb(&SliderValue::Single(0.), 90.),
+            SliderThumb::Start
+        );
+    }
+
+    #[test]
+    fn test_keyboard_steps() {
+        assert_eq!(keyboard_step(0., 100., Some(5.)), 5.);
+        assert_eq!(keyboard_step(0., 100., None), 1.);
+        assert_eq!(page_step(0., 100., None), 10.);
+        assert_eq!(page_step(0., 100., Some(3.)), 9.);
+        // A page is never smaller than a single step.
+        assert_eq!(page_step(0., 100., Some(25.)), 25.);
+    }
+
+    #[test]
+    fn test_tick_fractions() {
+        assert!(tick_fractions(0., 1., None).is_empty());
+        assert_eq!(tick_fractions(0., 10., Some(5.)), vec![0., 0.5, 1.]);
+        assert_eq!(tick_fractions(0., 1000., Some(1.)), Vec::<f32>::new());
+    }
+
+    #[test]
+    fn test_format_slider_value() {
+        assert_eq!(format_slider_value(0.5, None).to_string(), "0.50");
+        assert_eq!(format_slider_value(40., Some(5.)).to_string(), "40");
+        assert_eq!(format_slider_value(0.3, Some(0.1)).to_string(), "0.3");
+        assert_eq!(format_slider_value(0.25, Some(0.05)).to_string(), "0.25");
+    }
+
+    #[gpui::test]
+    fn test_slider_click_and_drag_update_value(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+        use gpui::{Modifiers, MouseButton, point};
+
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |_window, cx| {
+                cx.new(|_cx| SliderTestView {
+                    value: SliderValue::Range(0.2, 0.8),
+                })
+            })
+            .unwrap()
+        });
+        let view = window.root(cx).unwrap();
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+        cx.run_until_parked();
+
+        // The thumb centers travel between half a thumb from either end of the slider.
+        let thumb_size = cx.update(|_window, cx| cx.get_theme().layout.size.md);
+        let y = thumb_size / 2.;
+        let rail_x = |fraction: f32| thumb_size / 2. + (px(240.) - thumb_size) * fraction;
+        let assert_value = |cx: &mut VisualTestContext, expected: (f32, f32)| {
+            let value = cx.update(|_window, cx| view.read(cx).value);
+            let SliderValue::Range(start, end) = value else {
+                panic!("Expected a range, got {value:?}");
+            };
+            assert!(
+                (start - expected.0).abs() < 1e-4 && (end - expected.1).abs() < 1e-4,
+                "Expected {expected:?}, got {value:?}"
+            );
+        };
+
+        // Pressing on the rail moves the nearest thumb there, snapped to a step.
+        cx.simulate_mouse_down(point(rail_x(0.68), y), MouseButton::Left, Modifiers::none());
+        cx.run_until_parked();
+        assert_value(&mut cx, (0.2, 0.7));
+
+        cx.simulate_mouse_move(point(rail_x(0.93), y), MouseButton::Left, Modifiers::none());
+        cx.run_until_parked();
+        assert_value(&mut cx, (0.2, 0.9));
+
+        // Dragging past the end of the slider clamps to the maximum.
+        cx.simulate_mouse_move(point(px(400.), y), MouseButton::Left, Modifiers::none());
+        cx.run_until_parked();
+        cx.simulate_mouse_up(point(px(400.), y), MouseButton::Left, Modifiers::none());
+        cx.run_until_parked();
+        assert_value(&mut cx, (0.2, 1.));
+
+        // Clicking before the rail clamps the nearest thumb to the minimum.
+        cx.simulate_click(point(px(1.), y), Modifiers::none());
+        cx.run_until_parked();
+        assert_value(&mut cx, (0., 1.));
+    }
+
+    #[gpui::test]
+    fn test_slider_keyboard_control(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        let (view, cx) = cx.add_window_view(|window, cx| {
+            cx.set_theme(Theme::DEFAULT);
+            init(cx);
+
+            let focus_handle = cx.focus_handle();
+            focus_handle.focus(window, cx);
+
+            SliderKeyboardTestView {
+                value: 0.5,
+                focus_handle,
+            }
+        });
+
+        cx.simulate_keystrokes("right right pageup");
+        let value = cx.update(|_window, cx| view.read(cx).value);
+        assert!((value - 0.7).abs() < 1e-4, "Expected 0.7, got {value}");
+
+        cx.simulate_keystrokes("end");
+        let value = cx.update(|_window, cx| view.read(cx).value);
+        assert_eq!(value, 1.);
+
+        cx.simulate_keystrokes("home");
+        let value = cx.update(|_window, cx| view.read(cx).value);
+        assert_eq!(value, 0.);
+    }
+
+    struct SliderTestView {
+        value: SliderValue,
+    }
+
+    impl gpui::Render for SliderTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            let entity = cx.entity();
+
+            div().w(px(240.)).child(
+                Slider::new("test-slider")
+                    .value(self.value)
+                    .step(0.1)
+                    .ticks(true)
+                    .value_tooltip(true)
+                    .on_change(move |value, _window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.value = *value;
+                            cx.notify();
+                        });
+                    }),
+            )
+        }
+    }
+
+    struct SliderKeyboardTestView {
+        value: f32,
+        focus_handle: FocusHandle,
+    }
+
+    impl gpui::Render for SliderKeyboardTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            let entity = cx.entity();
+
+            div().w(px(240.)).child(
+                Slider::new("test-slider")
+                    .value(self.value)
+                    .step(0.05)
+                    .focus_handle(self.focus_handle.clone())
+                    .on_change(move |value, _window, cx| {
+                        entity.update(cx, |this, cx| {
+                            this.value = value.start();
+                            cx.notify();
+                        });
+                    }),
+            )
+        }
+    }
+}
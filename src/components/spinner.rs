@@ -0,0 +1,154 @@
+use std::{f32::consts::TAU, time::Duration};
+
+use gpui::{
+    Animation, AnimationExt, App, Edges, ElementId, Hsla, IntoElement, Length, RenderOnce,
+    SizeRefinement, Window, prelude::FluentBuilder, radians,
+};
+
+use crate::{
+    TesseraeIconKind,
+    components::Icon,
+    theme::{ThemeExt, ThemeLayoutSizeKind},
+};
+
+/// A circular indicator that spins while something is loading.
+#[derive(IntoElement)]
+pub struct Spinner {
+    id: ElementId,
+    size: ThemeLayoutSizeKind,
+    pub(crate) icon_size: Option<SizeRefinement<Length>>,
+    color: Option<Hsla>,
+    margin: Edges<Option<Length>>,
+}
+
+impl Spinner {
+    /// Creates a new spinner with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            size: ThemeLayoutSizeKind::Md,
+            icon_size: None,
+            color: None,
+            margin: Edges::default(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the size from the theme's component sizes. Defaults to medium.
+    pub fn size(mut self, size: ThemeLayoutSizeKind) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets a custom color, overriding the theme's secondary text color.
+    pub fn color(mut self, color: impl Into<Hsla>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl RenderOnce for Spinner {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
+        let size = self.size.resolve(cx);
+
+        Icon::new(TesseraeIconKind::Spinner)
+            .color(self.color.unwrap_or(secondary_text_color.into()))
+            .map(|mut this| {
+                this.size = self.icon_size.unwrap_or(SizeRefinement {
+                    width: Some(size.into()),
+                    height: Some(size.into()),
+                });
+                this
+            })
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .with_animation(
+                self.id,
+                Animation::new(Duration::from_millis(800)).repeat(),
+                |this, delta| this.rotate(radians(delta * TAU)),
+            )
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use crate::theme::{Theme, ThemeExt};
+    use gpui::{
+        AppContext, ParentElement, Render, Styled, TestAppContext, VisualTestContext, div, hsla,
+    };
+
+    #[gpui::test]
+    fn test_spinner_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let spinner = Spinner::new("test-spinner")
+                .size(ThemeLayoutSizeKind::Sm)
+                .color(hsla(0., 0., 1., 1.));
+
+            assert!(matches!(spinner.size, ThemeLayoutSizeKind::Sm));
+            assert!(spinner.color.is_some(), "Spinner should have a color");
+            assert!(spinner.icon_size.is_none());
+        });
+    }
+
+    #[gpui::test]
+    fn test_spinner_renders_in_window(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |_window, cx| {
+                cx.new(|_cx| SpinnerTestView)
+            })
+            .unwrap()
+        });
+
+        let _cx = VisualTestContext::from_window(window.into(), cx);
+    }
+
+    struct SpinnerTestView;
+
+    impl Render for SpinnerTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            _cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            div()
+                .size_full()
+                .child(Spinner::new("test-spinner").size(ThemeLayoutSizeKind::Lg))
+        }
+    }
+}
@@ -0,0 +1,842 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    rc::Rc,
+    time::Duration,
+};
+
+use gpui::{
+    AnyElement, App, Axis, CursorStyle, ElementId, Entity, InteractiveElement, IntoElement,
+    KeyBinding, Length, MouseButton, ParentElement, Pixels, RenderOnce, SharedString,
+    StatefulInteractiveElement, Styled, Window, actions, canvas, div, ease_out_quint,
+    prelude::FluentBuilder, px, relative,
+};
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+use crate::{
+    ElementIdExt, conitional_transition,
+    theme::{ThemeExt, ThemeLayerKind},
+    views::RootWindowExt,
+};
+
+actions!(split_pane, [ResizeBackward, ResizeForward, ToggleCollapse]);
+
+/// Registers key bindings for resizing panes from a focused handle.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("left", ResizeBackward, Some("SplitPaneHandle")),
+        KeyBinding::new("up", ResizeBackward, Some("SplitPaneHandle")),
+        KeyBinding::new("right", ResizeForward, Some("SplitPaneHandle")),
+        KeyBinding::new("down", ResizeForward, Some("SplitPaneHandle")),
+        KeyBinding::new("enter", ToggleCollapse, Some("SplitPaneHandle")),
+    ]);
+}
+
+/// Thickness of a handle, including the grabbable area around its divider line.
+const HANDLE_SIZE: f32 = 5.;
+
+/// Distance a focused handle moves per arrow key press.
+const KEYBOARD_STEP: f32 = 10.;
+
+/// Type alias for the resize callback.
+pub type OnSplitPaneResizeFn = Rc<dyn Fn(&SplitPaneState, &mut Window, &mut App)>;
+
+/// Sizes of the panes in a [`SplitPane`], keyed by pane value.
+///
+/// Serialize this to restore a layout between sessions. Panes that were never
+/// resized keep their initial size.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SplitPaneState {
+    #[serde(default)]
+    sizes: BTreeMap<SharedString, f32>,
+    #[serde(default)]
+    collapsed: BTreeSet<SharedString>,
+}
+
+impl SplitPaneState {
+    /// Creates an empty state where every pane uses its initial size.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the size a pane was resized to, if any.
+    pub fn size(&self, pane: &str) -> Option<Pixels> {
+        self.sizes.get(pane).map(|size| px(*size))
+    }
+
+    /// Sets the size of a pane.
+    pub fn set_size(&mut self, pane: impl Into<SharedString>, size: Pixels) {
+        self.sizes.insert(pane.into(), size.to_f64() as f32);
+    }
+
+    /// Returns whether a pane is collapsed.
+    pub fn is_collapsed(&self, pane: &str) -> bool {
+        self.collapsed.contains(pane)
+    }
+
+    /// Collapses or restores a pane. A restored pane returns to its previous size.
+    pub fn set_collapsed(&mut self, pane: impl Into<SharedString>, collapsed: bool) {
+        let pane = pane.into();
+
+        if collapsed {
+            self.collapsed.insert(pane);
+        } else {
+            self.collapsed.remove(&pane);
+        }
+    }
+}
+
+/// A single pane within a [`SplitPane`].
+pub struct Pane {
+    value: SharedString,
+    size: Option<Pixels>,
+    min_size: Pixels,
+    max_size: Option<Pixels>,
+    collapsible: bool,
+    children: SmallVec<[AnyElement; 2]>,
+}
+
+impl Pane {
+    /// Creates a new pane identified by `value`.
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            size: None,
+            min_size: Pixels::ZERO,
+            max_size: None,
+            collapsible: false,
+            children: SmallVec::new(),
+        }
+    }
+
+    /// Sets the initial size along the split axis. Panes without a size
+    /// share whatever space the sized panes leave.
+    pub fn size(mut self, size: impl Into<Pixels>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+
+    /// Sets the smallest size the pane can be resized to.
+    pub fn min_size(mut self, min_size: impl Into<Pixels>) -> Self {
+        self.min_size = min_size.into();
+        self
+    }
+
+    /// Sets the largest size the pane can be resized to.
+    pub fn max_size(mut self, max_size: impl Into<Pixels>) -> Self {
+        self.max_size = Some(max_size.into());
+        self
+    }
+
+    /// Allows a sized pane to be collapsed by double-clicking an adjacent handle.
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+}
+
+impl ParentElement for Pane {
+    fn extend(&mut self, elements: impl IntoIterator<Item = AnyElement>) {
+        self.children.extend(elements);
+    }
+}
+
+/// Panes laid out along an axis with draggable handles between them.
+#[derive(IntoElement)]
+pub struct SplitPane {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    axis: Axis,
+    panes: Vec<Pane>,
+    state: Option<Entity<SplitPaneState>>,
+    on_resize: Option<OnSplitPaneResizeFn>,
+    width: Length,
+    height: Length,
+}
+
+impl SplitPane {
+    /// Creates a new split pane with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            axis: Axis::Horizontal,
+            panes: Vec::new(),
+            state: None,
+            on_resize: None,
+            width: relative(1.).into(),
+            height: relative(1.).into(),
+        }
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height. Defaults to the full height of the parent.
+    pub fn h(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets the axis panes are laid out along. Defaults to horizontal, side by side.
+    pub fn axis(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Adds a pane.
+    pub fn pane(mut self, pane: Pane) -> Self {
+        self.panes.push(pane);
+        self
+    }
+
+    /// Adds multiple panes.
+    pub fn panes(mut self, panes: impl IntoIterator<Item = Pane>) -> Self {
+        self.panes.extend(panes);
+        self
+    }
+
+    /// Sets the state holding pane sizes, such as one restored from disk.
+    /// When unset, sizes are kept in window-keyed storage.
+    pub fn state(mut self, state: Entity<SplitPaneState>) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// Sets a callback invoked with the updated state whenever a pane is resized or collapsed.
+    pub fn on_resize(
+        mut self,
+        on_resize: impl Fn(&SplitPaneState, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_resize = Some(Rc::new(on_resize));
+        self
+    }
+}
+
+/// An in-progress handle drag, measured from where it started.
+#[derive(Clone)]
+struct HandleDrag {
+    handle: usize,
+    origin: f32,
+    start: Rc<[f32]>,
+}
+
+impl RenderOnce for SplitPane {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let divider_color = layer.next().resolve(cx);
+
+        let axis = self.axis;
+
+        let state = self.state.clone().unwrap_or_else(|| {
+            window.use_keyed_state(self.id.with_suffix("state:sizes"), cx, |_cx, _window| {
+                SplitPaneState::default()
+            })
+        });
+
+        // Length of the split along its axis, captured during prepaint.
+        let container_size_state = window.use_keyed_state(
+            self.id.with_suffix("state:container_size"),
+            cx,
+            |_cx, _window| 0_f32,
+        );
+        let container_size = *container_size_state.read(cx);
+
+        let dragging_state =
+            window.use_keyed_state(self.id.with_suffix("state:dragging"), cx, |_cx, _window| {
+                None::<HandleDrag>
+            });
+        let dragging = dragging_state.read(cx).clone();
+
+        let layout = Rc::new(PaneLayout {
+            panes: self
+                .panes
+                .iter()
+                .map(|pane| {
+                    let min = pane.min_size.to_f64() as f32;
+                    let max = pane
+                        .max_size
+                        .map_or(f32::INFINITY, |max| max.to_f64() as f32)
+                        .max(min);
+
+                    PaneSpec {
+                        value: pane.value.clone(),
+                        size: pane.size.map(|size| size.to_f64() as f32),
+                        limits: PaneLimits { min, max },
+                        collapsible: pane.collapsible,
+                    }
+                })
+                .collect(),
+        });
+
+        let pane_sizes = layout.pane_sizes(state.read(cx));
+
+        let commit: Rc<dyn Fn(SplitPaneState, &mut Window, &mut App)> = {
+            let state = state.clone();
+            let on_resize = self.on_resize.clone();
+
+            Rc::new(move |next, window, cx| {
+                if *state.read(cx) == next {
+                    return;
+                }
+
+                state.update(cx, |this, cx| {
+                    *this = next;
+                    cx.notify();
+                });
+
+                if let Some(on_resize) = on_resize.as_ref() {
+                    (on_resize)(state.read(cx), window, cx);
+                }
+            })
+        };
+
+        // Keep tracking the pointer after it leaves the handle, until it is released.
+        if let Some(drag) = dragging.clone() {
+            let layout = layout.clone();
+            let state = state.clone();
+            let commit = commit.clone();
+
+            window.on_root_mouse_move(cx, move |event, window, cx| {
+                let delta = event.position.along(axis).to_f64() as f32 - drag.origin;
+                let next = layout.resized(state.read(cx), &drag.start, drag.handle, delta);
+                (commit)(next, window, cx);
+            });
+
+            let dragging_state = dragging_state.clone();
+            window.on_root_mouse_up(cx, move |_event, _window, cx| {
+                dragging_state.update(cx, |this, cx| {
+                    *this = None;
+                    cx.notify();
+                });
+            });
+        }
+
+        let pane_count = self.panes.len();
+        let mut children: Vec<AnyElement> = Vec::with_capacity(pane_count * 2);
+
+        for (idx, pane) in self.panes.into_iter().enumerate() {
+            let is_collapsed = state.read(cx).is_collapsed(&pane.value);
+
+            children.push(
+                div()
+                    .relative()
+                    .overflow_hidden()
+                    .map(|this| match (pane_sizes[idx], axis) {
+                        (Some(size), Axis::Horizontal) => this.flex_none().w(px(size)).h_full(),
+                        (Some(size), Axis::Vertical) => this.flex_none().h(px(size)).w_full(),
+                        (None, Axis::Horizontal) => this.flex_1().min_w_0().h_full(),
+                        (None, Axis::Vertical) => this.flex_1().min_h_0().w_full(),
+                    })
+                    .when(!is_collapsed, |this| this.children(pane.children))
+                    .into_any_element(),
+            );
+
+            if idx + 1 == pane_count {
+                break;
+            }
+
+            let handle_id = self.id.with_suffix(format!("handle:{idx}"));
+
+            let focus_handle = window
+                .use_keyed_state(
+                    handle_id.with_suffix("state:focus_handle"),
+                    cx,
+                    |_window, cx| cx.focus_handle().tab_stop(true),
+                )
+                .read(cx)
+                .clone();
+            let is_focus = focus_handle.is_focused(window);
+
+            let is_hover_state =
+                window.use_keyed_state(handle_id.with_suffix("state:hover"), cx, |_cx, _window| {
+                    false
+                });
+            let is_hover = *is_hover_state.read(cx);
+
+            let is_dragging = dragging.as_ref().is_some_and(|drag| drag.handle == idx);
+
+            let line_color = *conitional_transition!(
+                handle_id.with_suffix("state:transition:line_color"),
+                window,
+                cx,
+                Duration::from_millis(365),
+                {
+                    is_dragging || is_focus || is_hover => primary_accent_color,
+                    _ => divider_color
+                }
+            )
+            .with_easing(ease_out_quint())
+            .evaluate(window, cx);
+
+            let resize_by = {
+                let layout = layout.clone();
+                let state = state.clone();
+                let commit = commit.clone();
+
+                Rc::new(move |delta: f32, window: &mut Window, cx: &mut App| {
+                    let state = state.read(cx);
+                    let start = layout.actual_sizes(state, container_size);
+                    let next = layout.resized(state, &start, idx, delta);
+                    (commit)(next, window, cx);
+                })
+            };
+            let resize_backward = resize_by.clone();
+            let resize_forward = resize_by;
+
+            let toggle_collapse = {
+                let layout = layout.clone();
+                let state = state.clone();
+                let commit = commit.clone();
+
+                Rc::new(move |window: &mut Window, cx: &mut App| {
+                    if let Some(next) = layout.toggled_collapse(state.read(cx), idx) {
+                        (commit)(next, window, cx);
+                    }
+                })
+            };
+            let toggle_collapse_on_action = toggle_collapse.clone();
+
+            let start_drag = {
+                let layout = layout.clone();
+                let state = state.clone();
+                let dragging_state = dragging_state.clone();
+                let focus_handle = focus_handle.clone();
+
+                move |origin: f32, window: &mut Window, cx: &mut App| {
+                    let start = layout.actual_sizes(state.read(cx), container_size);
+
+                    dragging_state.update(cx, |this, cx| {
+                        *this = Some(HandleDrag {
+                            handle: idx,
+                            origin,
+                            start: start.into(),
+                        });
+                        cx.notify();
+                    });
+
+                    focus_handle.focus(window, cx);
+                }
+            };
+
+            children.push(
+                div()
+                    .id(handle_id)
+                    .key_context("SplitPaneHandle")
+                    .track_focus(&focus_handle)
+                    .flex_none()
+                    .flex()
+                    .justify_center()
+                    .items_center()
+                    .map(|this| match axis {
+                        Axis::Horizontal => this
+                            .w(px(HANDLE_SIZE))
+                            .h_full()
+                            .cursor(CursorStyle::ResizeLeftRight),
+                        Axis::Vertical => this
+                            .h(px(HANDLE_SIZE))
+                            .w_full()
+                            .cursor(CursorStyle::ResizeUpDown),
+                    })
+                    .child(
+                        div()
+                            .map(|this| match axis {
+                                Axis::Horizontal => this.w(px(1.)).h_full(),
+                                Axis::Vertical => this.h(px(1.)).w_full(),
+                            })
+                            .bg(line_color),
+                    )
+                    .on_hover(move |hover, _window, cx| {
+                        is_hover_state.update(cx, |this, cx| {
+                            *this = *hover;
+                            cx.notify();
+                        });
+                    })
+                    .on_mouse_down(MouseButton::Left, move |event, window, cx| {
+                        cx.stop_propagation();
+
+                        if event.click_count == 2 {
+                            (toggle_collapse)(window, cx);
+                        } else {
+                            start_drag(event.position.along(axis).to_f64() as f32, window, cx);
+                        }
+                    })
+                    .on_action(move |_: &ResizeBackward, window, cx| {
+                        (resize_backward)(-KEYBOARD_STEP, window, cx)
+                    })
+                    .on_action(move |_: &ResizeForward, window, cx| {
+                        (resize_forward)(KEYBOARD_STEP, window, cx)
+                    })
+                    .on_action(move |_: &ToggleCollapse, window, cx| {
+                        (toggle_collapse_on_action)(window, cx)
+                    })
+                    .into_any_element(),
+            );
+        }
+
+        div()
+            .id(self.id.clone())
+            .relative()
+            .flex()
+            .map(|this| match axis {
+                Axis::Horizontal => this.flex_row(),
+                Axis::Vertical => this.flex_col(),
+            })
+            .w(self.width)
+            .h(self.height)
+            .child(
+                canvas(
+                    move |bounds, _window, cx| {
+                        let size = bounds.size.along(axis).to_f64() as f32;
+
+                        container_size_state.update(cx, |this, cx| {
+                            if *this != size {
+                                *this = size;
+                                cx.notify();
+                            }
+                        });
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .children(children)
+    }
+}
+
+/// The smallest and largest size of a pane.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct PaneLimits {
+    min: f32,
+    max: f32,
+}
+
+/// The sizing rules of a single pane, independent of its content.
+#[derive(Clone, Debug)]
+struct PaneSpec {
+    value: SharedString,
+    size: Option<f32>,
+    limits: PaneLimits,
+    collapsible: bool,
+}
+
+/// The sizing rules of every pane in a split, in order.
+struct PaneLayout {
+    panes: Vec<PaneSpec>,
+}
+
+impl PaneLayout {
+    /// Returns the size of each pane, or `None` for panes that fill the remaining space.
+    fn pane_sizes(&self, state: &SplitPaneState) -> Vec<Option<f32>> {
+        self.panes
+            .iter()
+            .map(|pane| {
+                let size = pane.size?;
+
+                if state.is_collapsed(&pane.value) {
+                    return Some(0.);
+                }
+
+                let size = state.sizes.get(&pane.value).copied().unwrap_or(size);
+                Some(size.clamp(pane.limits.min, pane.limits.max))
+            })
+            .collect()
+    }
+
+    /// Returns the laid out size of each pane, splitting the space left in
+    /// `container_size` evenly between the panes that fill it.
+    fn actual_sizes(&self, state: &SplitPaneState, container_size: f32) -> Vec<f32> {
+        let sizes = self.pane_sizes(state);
+        let fill_count = sizes.iter().filter(|size| size.is_none()).count();
+        let handles = self.panes.len().saturating_sub(1) as f32 * HANDLE_SIZE;
+        let sized: f32 = sizes.iter().flatten().sum();
+        let fill = if fill_count == 0 {
+            0.
+        } else {
+            ((container_size - handles - sized) / fill_count as f32).max(0.)
+        };
+
+        sizes.into_iter().map(|size| size.unwrap_or(fill)).collect()
+    }
+
+    /// Returns the state after moving the handle after pane `handle` by `delta`,
+    /// starting from the laid out sizes in `start`.
+    fn resized(
+        &self,
+        state: &SplitPaneState,
+        start: &[f32],
+        handle: usize,
+        delta: f32,
+    ) -> SplitPaneState {
+        let mut next = state.clone();
+        let (before, after) = (handle, handle + 1);
+
+        let (Some(before_pane), Some(after_pane)) = (self.panes.get(before), self.panes.get(after))
+        else {
+            return next;
+        };
+        let (Some(&before_size), Some(&after_size)) = (start.get(before), start.get(after)) else {
+            return next;
+        };
+
+        let delta = clamped_delta(
+            delta,
+            before_size,
+            after_size,
+            before_pane.limits,
+            after_pane.limits,
+        );
+
+        for (pane, size) in [
+            (before_pane, before_size + delta),
+            (after_pane, after_size - delta),
+        ] {
+            if pane.size.is_some() {
+                next.sizes.insert(pane.value.clone(), size);
+                next.collapsed.remove(&pane.value);
+            }
+        }
+
+        next
+    }
+
+    /// Returns the state after collapsing or restoring the pane next to the
+    /// handle after pane `handle`, or `None` when neither side can collapse.
+    fn toggled_collapse(&self, state: &SplitPaneState, handle: usize) -> Option<SplitPaneState> {
+        let pane = [handle, handle + 1]
+            .into_iter()
+            .filter_map(|idx| self.panes.get(idx))
+            .find(|pane| pane.collapsible && pane.size.is_some())?;
+
+        let mut next = state.clone();
+        let is_collapsed = next.is_collapsed(&pane.value);
+        next.set_collapsed(pane.value.clone(), !is_collapsed);
+        Some(next)
+    }
+}
+
+/// Clamps how far a handle moves so that neither neighbouring pane leaves its limits.
+fn clamped_delta(
+    delta: f32,
+    before: f32,
+    after: f32,
+    before_limits: PaneLimits,
+    after_limits: PaneLimits,
+) -> f32 {
+    let lower = (before_limits.min - before).max(after - after_limits.max);
+    let upper = (before_limits.max - before).min(after - after_limits.min);
+
+    if lower > upper {
+        return 0.;
+    }
+
+    delta.clamp(lower, upper)
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use crate::theme::Theme;
+    use gpui::{AppContext, Render, TestAppContext, VisualTestContext};
+
+    fn spec(value: &str, size: Option<f32>, min: f32, max: f32, collapsible: bool) -> PaneSpec {
+        PaneSpec {
+            value: value.into(),
+            size,
+            limits: PaneLimits { min, max },
+            collapsible,
+        }
+    }
+
+    /// A sidebar, a chat filling the rest, and an inspector.
+    fn layout() -> PaneLayout {
+        PaneLayout {
+            panes: vec![
+                spec("sidebar", Some(200.), 100., 300., true),
+                spec("chat", None, 200., f32::INFINITY, false),
+                spec("inspector", Some(250.), 150., f32::INFINITY, true),
+            ],
+        }
+    }
+
+    #[gpui::test]
+    fn test_split_pane_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let split_pane = SplitPane::new("test-split")
+                .axis(Axis::Vertical)
+                .pane(
+                    Pane::new("top")
+                        .size(px(120.))
+                        .min_size(px(40.))
+                        .max_size(px(400.))
+                        .collapsible(true),
+                )
+                .pane(Pane::new("bottom").child("Content"));
+
+            assert_eq!(split_pane.axis, Axis::Vertical);
+            assert_eq!(split_pane.panes.len(), 2);
+            assert_eq!(split_pane.panes[0].size, Some(px(120.)));
+            assert_eq!(split_pane.panes[0].min_size, px(40.));
+            assert!(split_pane.panes[0].collapsible, "Top pane should collapse");
+            assert_eq!(split_pane.panes[1].size, None);
+            assert_eq!(split_pane.panes[1].children.len(), 1);
+        });
+    }
+
+    #[test]
+    fn test_split_pane_state_round_trip() {
+        let mut state = SplitPaneState::new();
+        state.set_size("sidebar", px(240.));
+        state.set_collapsed("inspector", true);
+
+        let json = serde_json::to_string(&state).unwrap();
+        let restored: SplitPaneState = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored, state);
+        assert_eq!(restored.size("sidebar"), Some(px(240.)));
+        assert!(restored.is_collapsed("inspector"));
+        assert_eq!(
+            serde_json::from_str::<SplitPaneState>("{}").unwrap(),
+            SplitPaneState::default()
+        );
+    }
+
+    #[test]
+    fn test_pane_sizes() {
+        let layout = layout();
+        let mut state = SplitPaneState::new();
+        assert_eq!(
+            layout.pane_sizes(&state),
+            vec![Some(200.), None, Some(250.)]
+        );
+
+        state.set_size("sidebar", px(500.));
+        state.set_collapsed("inspector", true);
+        assert_eq!(layout.pane_sizes(&state), vec![Some(300.), None, Some(0.)]);
+    }
+
+    #[test]
+    fn test_actual_sizes() {
+        let layout = layout();
+        let state = SplitPaneState::new();
+        let handles = 2. * HANDLE_SIZE;
+
+        assert_eq!(
+            layout.actual_sizes(&state, 1000. + handles),
+            vec![200., 550., 250.]
+        );
+        assert_eq!(layout.actual_sizes(&state, 100.), vec![200., 0., 250.]);
+    }
+
+    #[test]
+    fn test_resized() {
+        let layout = layout();
+        let state = SplitPaneState::new();
+        let start = [200., 550., 250.];
+
+        let next = layout.resized(&state, &start, 0, 50.);
+        assert_eq!(next.size("sidebar"), Some(px(250.)));
+        assert_eq!(next.size("chat"), None, "Filling panes are never stored");
+
+        // The sidebar can't grow past its maximum.
+        let next = layout.resized(&state, &start, 0, 500.);
+        assert_eq!(next.size("sidebar"), Some(px(300.)));
+
+        // The chat can't shrink below its minimum.
+        let next = layout.resized(&state, &start, 1, -500.);
+        assert_eq!(next.size("inspector"), Some(px(600.)));
+
+        // Dragging a collapsed pane open restores it.
+        let mut collapsed = SplitPaneState::new();
+        collapsed.set_collapsed("sidebar", true);
+        let next = layout.resized(&collapsed, &[0., 750., 250.], 0, 10.);
+        assert!(!next.is_collapsed("sidebar"));
+        assert_eq!(next.size("sidebar"), Some(px(100.)));
+    }
+
+    #[test]
+    fn test_toggled_collapse() {
+        let layout = layout();
+        let state = SplitPaneState::new();
+
+        let next = layout.toggled_collapse(&state, 0).unwrap();
+        assert!(next.is_collapsed("sidebar"));
+        let next = layout.toggled_collapse(&next, 0).unwrap();
+        assert!(!next.is_collapsed("sidebar"));
+
+        let next = layout.toggled_collapse(&state, 1).unwrap();
+        assert!(next.is_collapsed("inspector"));
+
+        let layout = PaneLayout {
+            panes: vec![
+                spec("a", None, 0., f32::INFINITY, true),
+                spec("b", Some(100.), 0., f32::INFINITY, false),
+            ],
+        };
+        assert!(layout.toggled_collapse(&state, 0).is_none());
+    }
+
+    #[test]
+    fn test_clamped_delta() {
+        let limits = PaneLimits {
+            min: 50.,
+            max: 150.,
+        };
+
+        assert_eq!(clamped_delta(20., 100., 100., limits, limits), 20.);
+        assert_eq!(clamped_delta(80., 100., 100., limits, limits), 50.);
+        assert_eq!(clamped_delta(-80., 100., 100., limits, limits), -50.);
+        // A pane already below its minimum can only be moved back into range.
+        assert_eq!(clamped_delta(10., 100., 10., limits, limits), -40.);
+
+        let tight = PaneLimits { min: 50., max: 60. };
+        assert_eq!(clamped_delta(10., 100., 100., tight, tight), 0.);
+    }
+
+    #[gpui::test]
+    fn test_split_pane_renders_in_window(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            cx.open_window(Default::default(), |_window, cx| {
+                let state = cx.new(|_cx| SplitPaneState::new());
+                cx.new(|_cx| SplitPaneTestView { state })
+            })
+            .unwrap()
+        });
+
+        let _cx = VisualTestContext::from_window(window.into(), cx);
+    }
+
+    struct SplitPaneTestView {
+        state: Entity<SplitPaneState>,
+    }
+
+    impl Render for SplitPaneTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            _cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            SplitPane::new("test-split")
+                .state(self.state.clone())
+                .pane(Pane::new("sidebar").size(px(200.)).collapsible(true))
+                .pane(Pane::new("chat").min_size(px(200.)).child("Chat"))
+                .pane(Pane::new("inspector").size(px(250.)))
+        }
+    }
+}
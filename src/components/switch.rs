@@ -17,13 +17,14 @@ use crate::{
     primitives::FocusRing,
     theme::{ThemeExt, ThemeLayerKind},
     utils::{RgbaExt, SquircleExt, checked_transition, disabled_transition},
+    views::RootWindowExt,
 };
 
 /// A toggle switch component with animated sliding indicator.
 #[derive(IntoElement)]
 pub struct Switch {
     id: ElementId,
-    layer: ThemeLayerKind,
+    layer: Option<ThemeLayerKind>,
     checked: bool,
     disabled: bool,
     force_hover: bool,
@@ -39,7 +40,7 @@ impl Switch {
     pub fn new(id: impl Into<ElementId>) -> Self {
         Self {
             id: id.into(),
-            layer: ThemeLayerKind::Tertiary,
+            layer: None,
             checked: false,
             disabled: false,
             force_hover: false,
@@ -89,8 +90,9 @@ impl Switch {
     }
 
     /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
     pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
-        self.layer = layer;
+        self.layer = Some(layer);
         self
     }
 
@@ -149,8 +151,9 @@ impl RenderOnce for Switch {
         );
         let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
         let primary_text_color = cx.get_theme().variants.active(cx).colors.text.primary;
-        let background_color = self.layer.resolve(cx);
-        let border_color = self.layer.next().resolve(cx);
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
         let border_hover_color = border_color.lerp(&primary_text_color, 0.07);
         let border_click_down_color = border_color.lerp(&primary_text_color, 0.16);
 
@@ -476,29 +479,21 @@ fn register_drag_handler(
     threshold: f32,
     dragged_checked_state: Entity<Option<bool>>,
 ) {
-    let root = window
-        .root::<crate::views::Root>()
-        .flatten()
-        .expect("expected gpui_tesserae::Root");
-
-    root.update(cx, |root, cx| {
-        root.on_mouse_move(move |event, _window, cx| {
-            let current_dragged = *dragged_checked_state.read(cx);
-            let new_dragged = calculate_dragged_checked(
-                event.position.x.to_f64() as f32,
-                start_x,
-                threshold,
-                current_dragged,
-            );
+    window.on_root_mouse_move(cx, move |event, _window, cx| {
+        let current_dragged = *dragged_checked_state.read(cx);
+        let new_dragged = calculate_dragged_checked(
+            event.position.x.to_f64() as f32,
+            start_x,
+            threshold,
+            current_dragged,
+        );
 
-            if new_dragged != current_dragged {
-                dragged_checked_state.update(cx, |this, cx| {
-                    *this = new_dragged;
-                    cx.notify();
-                });
-            }
-        });
-        cx.notify();
+        if new_dragged != current_dragged {
+            dragged_checked_state.update(cx, |this, cx| {
+                *this = new_dragged;
+                cx.notify();
+            });
+        }
     });
 }
 
@@ -564,13 +559,13 @@ mod tests {
         cx.update(|_cx| {
             let switch = Switch::new("test-switch").layer(ThemeLayerKind::Primary);
             assert!(
-                matches!(switch.layer, ThemeLayerKind::Primary),
+                matches!(switch.layer, Some(ThemeLayerKind::Primary)),
                 "Switch should have primary layer"
             );
 
             let switch = Switch::new("test-switch").layer(ThemeLayerKind::Secondary);
             assert!(
-                matches!(switch.layer, ThemeLayerKind::Secondary),
+                matches!(switch.layer, Some(ThemeLayerKind::Secondary)),
                 "Switch should have secondary layer"
             );
         });
@@ -587,7 +582,7 @@ mod tests {
             assert!(switch.checked, "Switch should be checked");
             assert!(switch.disabled, "Switch should be disabled");
             assert!(
-                matches!(switch.layer, ThemeLayerKind::Secondary),
+                matches!(switch.layer, Some(ThemeLayerKind::Secondary)),
                 "Switch should have secondary layer"
             );
         });
@@ -0,0 +1,935 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::{f32::consts::PI, rc::Rc, time::Duration};
+
+use gpui::{
+    AnyElement, App, AppContext, Bounds, Edges, ElementId, FocusHandle, InteractiveElement,
+    IntoElement, KeyBinding, Length, ParentElement, Pixels, Render, RenderOnce, ScrollHandle,
+    SharedString, StatefulInteractiveElement, Styled, Window, actions, canvas, div, ease_out_quint,
+    point, prelude::FluentBuilder, px, radians, relative,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    ElementIdExt, PositionalParentElement, TesseraeIconKind,
+    components::{Button, ButtonVariant, GranularToggleVariant, Toggle},
+    conitional_transition,
+    extensions::{
+        mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+        mouse_handleable::MouseHandleable,
+    },
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{RgbaExt, RovingNavigation, SquircleExt, navigation_target},
+};
+
+actions!(tabs, [SelectPrev, SelectNext, SelectFirst, SelectLast]);
+
+/// Registers key bindings for moving between tabs.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("left", SelectPrev, Some("TabList")),
+        KeyBinding::new("right", SelectNext, Some("TabList")),
+        KeyBinding::new("home", SelectFirst, Some("TabList")),
+        KeyBinding::new("end", SelectLast, Some("TabList")),
+    ]);
+}
+
+/// Builds the content of a tab's panel. Only called while the tab is active.
+pub type TabPanelFn = Box<dyn FnOnce(&mut Window, &mut App) -> AnyElement>;
+
+/// How the active tab is highlighted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabIndicator {
+    /// A line under the active tab.
+    #[default]
+    Underline,
+    /// A filled background behind the active tab.
+    Pill,
+}
+
+/// A single tab within [`Tabs`].
+pub struct Tab {
+    value: SharedString,
+    label: Option<SharedString>,
+    icon: Option<SharedString>,
+    closable: bool,
+    disabled: bool,
+    focus_handle: Option<FocusHandle>,
+    panel: Option<TabPanelFn>,
+}
+
+impl Tab {
+    /// Creates a new tab identified by `value`.
+    pub fn new(value: impl Into<SharedString>) -> Self {
+        Self {
+            value: value.into(),
+            label: None,
+            icon: None,
+            closable: false,
+            disabled: false,
+            focus_handle: None,
+            panel: None,
+        }
+    }
+
+    /// Sets the tab's text label.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets an icon to display in the tab.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Shows a close button that invokes [`Tabs::on_close`].
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Sets the disabled state, preventing this tab from being activated.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the focus handle for keyboard navigation.
+    pub fn focus_handle(mut self, focus_handle: FocusHandle) -> Self {
+        self.focus_handle = Some(focus_handle);
+        self
+    }
+
+    /// Sets the content shown below the tab list while this tab is active.
+    pub fn panel<E: IntoElement>(
+        mut self,
+        panel: impl FnOnce(&mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        self.panel = Some(Box::new(move |window, cx| {
+            panel(window, cx).into_any_element()
+        }));
+        self
+    }
+}
+
+/// The payload carried while a tab is being dragged to a new position.
+#[derive(Clone)]
+struct DraggedTab {
+    tabs_id: ElementId,
+    index: usize,
+    label: SharedString,
+}
+
+impl Render for DraggedTab {
+    fn render(&mut self, _window: &mut Window, cx: &mut gpui::Context<Self>) -> impl IntoElement {
+        let corner_radius = cx.get_theme().layout.corner_radii.md;
+        let padding = cx.get_theme().layout.padding.md;
+        let text_size = cx.get_theme().layout.text.default_font.sizes.body.clone();
+        let primary_text_color = cx.get_theme().variants.active(cx).colors.text.primary;
+        let background_color = ThemeLayerKind::Secondary.resolve(cx);
+
+        div()
+            .relative()
+            .px(padding * 3.)
+            .py(padding * 1.5)
+            .text_size(text_size)
+            .text_color(primary_text_color)
+            .opacity(0.85)
+            .child(
+                squircle()
+                    .absolute_expand()
+                    .rounded(corner_radius)
+                    .bg(background_color)
+                    .border(px(1.))
+                    .border_inside()
+                    .border_highlight(0.15),
+            )
+            .child(div().relative().child(self.label.clone()))
+    }
+}
+
+/// A tab list with an animated indicator and lazily rendered panels.
+#[derive(IntoElement)]
+pub struct Tabs {
+    id: ElementId,
+    layer: Option<ThemeLayerKind>,
+    tabs: Vec<Tab>,
+    selected: Option<SharedString>,
+    indicator: TabIndicator,
+    reorderable: bool,
+    on_change: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    on_close: Option<Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>>,
+    on_reorder: Option<Rc<dyn Fn(usize, usize, &mut Window, &mut App) + 'static>>,
+    mouse_behavior: MouseBehavior,
+    margin: Edges<Option<Length>>,
+    width: Length,
+}
+
+impl Tabs {
+    /// Creates a new tabs component with the given element ID.
+    pub fn new(id: impl Into<ElementId>) -> Self {
+        Self {
+            id: id.into(),
+            layer: None,
+            tabs: Vec::new(),
+            selected: None,
+            indicator: TabIndicator::default(),
+            reorderable: false,
+            on_change: None,
+            on_close: None,
+            on_reorder: None,
+            mouse_behavior: MouseBehavior::default(),
+            margin: Edges::default(),
+            width: relative(1.).into(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the background layer for theming depth.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Adds a tab.
+    pub fn tab(mut self, tab: Tab) -> Self {
+        self.tabs.push(tab);
+        self
+    }
+
+    /// Adds multiple tabs.
+    pub fn tabs(mut self, tabs: impl IntoIterator<Item = Tab>) -> Self {
+        self.tabs.extend(tabs);
+        self
+    }
+
+    /// Sets the value of the active tab. Defaults to the first enabled tab.
+    pub fn selected(mut self, selected: impl Into<SharedString>) -> Self {
+        self.selected = Some(selected.into());
+        self
+    }
+
+    /// Sets how the active tab is highlighted.
+    pub fn indicator(mut self, indicator: TabIndicator) -> Self {
+        self.indicator = indicator;
+        self
+    }
+
+    /// Allows tabs to be reordered by dragging. See [`Self::on_reorder`].
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Sets a callback invoked with the value of a newly activated tab.
+    pub fn on_change(
+        mut self,
+        on_change: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(on_change));
+        self
+    }
+
+    /// Sets a callback invoked when a closable tab's close button is clicked.
+    pub fn on_close(
+        mut self,
+        on_close: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_close = Some(Rc::new(on_close));
+        self
+    }
+
+    /// Sets a callback invoked with the `from` and `to` indices when a tab is dropped onto another.
+    pub fn on_reorder(
+        mut self,
+        on_reorder: impl Fn(usize, usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_reorder = Some(Rc::new(on_reorder));
+        self
+    }
+}
+
+impl MouseBehaviorExt for Tabs {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl RenderOnce for Tabs {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        const LIST_INSET: Pixels = px(3.);
+        const UNDERLINE_HEIGHT: Pixels = px(2.);
+
+        let corner_radius = cx.get_theme().layout.corner_radii.md;
+        let padding = cx.get_theme().layout.padding.md;
+        let primary_accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let active_variant = ButtonVariant::SecondaryGhost.as_granular_on(layer, cx);
+        let inactive_variant = ButtonVariant::TertiaryGhost.as_granular_on(layer, cx);
+        let tab_corner_radius = corner_radius - LIST_INSET;
+
+        let mut tabs = self.tabs;
+        let tab_count = tabs.len();
+
+        let values: Rc<[SharedString]> = tabs.iter().map(|tab| tab.value.clone()).collect();
+        let disabled: Rc<[bool]> = tabs.iter().map(|tab| tab.disabled).collect();
+        let tab_ids: Vec<ElementId> = values
+            .iter()
+            .map(|value| self.id.with_suffix(format!("tab:{value}")))
+            .collect();
+
+        let active_index = active_tab_index(self.selected.as_ref(), &values, &disabled);
+
+        let focus_handles: Rc<[FocusHandle]> = tabs
+            .iter()
+            .zip(&tab_ids)
+            .map(|(tab, tab_id)| {
+                tab.focus_handle
+                    .as_ref()
+                    .unwrap_or_else(|| {
+                        window
+                            .use_keyed_state(
+                                tab_id.with_suffix("state:focus_handle"),
+                                cx,
+                                |_window, cx| cx.focus_handle(),
+                            )
+                            .read(cx)
+                    })
+                    .clone()
+            })
+            .collect();
+
+        let scroll_handle = window
+            .use_keyed_state(self.id.with_suffix("state:scroll_handle"), cx, |_, _| {
+                ScrollHandle::new()
+            })
+            .read(cx)
+            .clone();
+
+        // Bounds of every tab, captured during prepaint to position the indicator.
+        let tab_bounds_state = window.use_keyed_state(
+            self.id.with_suffix("state:tab_bounds"),
+            cx,
+            |_window, _cx| Vec::<Bounds<Pixels>>::new(),
+        );
+        tab_bounds_state.update(cx, |this, _cx| this.truncate(tab_count));
+
+        // The transitions are only created once the tabs have been measured,
+        // so the indicator starts under the active tab rather than sliding in.
+        let indicator = active_index
+            .and_then(|idx| {
+                let bounds = tab_bounds_state.read(cx);
+                let first = bounds.first()?;
+                let tab = bounds.get(idx)?;
+
+                (tab.size.width > px(0.)).then(|| (tab.origin.x - first.origin.x, tab.size.width))
+            })
+            .map(|(left, width)| {
+                let mut transition = |suffix: &str, value: Pixels| {
+                    *conitional_transition!(
+                        self.id
+                            .with_suffix("state:transition:indicator")
+                            .with_suffix(suffix),
+                        window,
+                        cx,
+                        Duration::from_millis(285),
+                        value
+                    )
+                    .with_easing(ease_out_quint())
+                    .evaluate(window, cx)
+                };
+
+                (transition("left", left), transition("width", width))
+            });
+
+        // Only the active panel is built, inactive panels are never rendered.
+        let panel = active_index
+            .and_then(|idx| tabs[idx].panel.take())
+            .map(|panel| panel(window, cx));
+
+        let is_overflowing = scroll_handle.max_offset().width > px(0.);
+        let scroll_offset = scroll_handle.offset().x;
+        let max_scroll_offset = scroll_handle.max_offset().width;
+
+        let activate: Rc<dyn Fn(usize, &mut Window, &mut App)> = {
+            let values = values.clone();
+            let on_change = self.on_change.clone();
+
+            Rc::new(move |idx, window, cx| {
+                if Some(idx) == active_index {
+                    return;
+                }
+
+                if let (Some(on_change), Some(value)) = (on_change.as_ref(), values.get(idx)) {
+                    (on_change)(value, window, cx);
+                }
+            })
+        };
+
+        let navigate = {
+            let focus_handles = focus_handles.clone();
+            let disabled = disabled.clone();
+            let scroll_handle = scroll_handle.clone();
+            let tab_bounds_state = tab_bounds_state.clone();
+            let activate = activate.clone();
+
+            Rc::new(
+                move |navigation: RovingNavigation, window: &mut Window, cx: &mut App| {
+                    let focused = focus_handles
+                        .iter()
+                        .position(|focus_handle| focus_handle.is_focused(window));
+
+                    let Some(target) = navigation_target(navigation, focused, &disabled) else {
+                        return;
+                    };
+
+                    focus_handles[target].focus(window, cx);
+
+                    // Scrolls by the measured tab rather than by child index, as the
+                    // scroll container also holds the indicator.
+                    if let Some(tab) = tab_bounds_state.read(cx).get(target) {
+                        let offset = scroll_handle.offset();
+                        let visible = scroll_handle.bounds();
+                        let new_x = revealing_offset(
+                            offset.x.to_f64() as f32,
+                            (tab.left().to_f64() as f32, tab.right().to_f64() as f32),
+                            (
+                                visible.left().to_f64() as f32,
+                                visible.right().to_f64() as f32,
+                            ),
+                            scroll_handle.max_offset().width.to_f64() as f32,
+                        );
+
+                        scroll_handle.set_offset(point(px(new_x), offset.y));
+                        window.refresh();
+                    }
+
+                    (activate)(target, window, cx);
+                },
+            )
+        };
+
+        let scroll_button = |forward: bool| {
+            let scroll_handle = scroll_handle.clone();
+            let can_scroll = if forward {
+                -scroll_offset < max_scroll_offset
+            } else {
+                scroll_offset < px(0.)
+            };
+
+            Button::new(self.id.with_suffix(if forward {
+                "scroll_right"
+            } else {
+                "scroll_left"
+            }))
+            .variant(ButtonVariant::TertiaryGhost)
+            .icon(TesseraeIconKind::ArrowDown)
+            .icon_size(px(11.))
+            .icon_rotate(radians(if forward { -PI / 2. } else { PI / 2. }))
+            .p(padding)
+            .rounded(tab_corner_radius)
+            .disabled(!can_scroll)
+            .on_click(move |_event, window, _cx| {
+                let offset = scroll_handle.offset();
+                let new_x = scrolled_offset(
+                    offset.x.to_f64() as f32,
+                    scroll_handle.bounds().size.width.to_f64() as f32,
+                    scroll_handle.max_offset().width.to_f64() as f32,
+                    forward,
+                );
+
+                scroll_handle.set_offset(point(px(new_x), offset.y));
+                window.refresh();
+            })
+        };
+
+        let tabs_id = self.id.clone();
+        let behavior = self.mouse_behavior;
+
+        div()
+            .id(self.id.clone())
+            .flex()
+            .flex_col()
+            .gap(padding)
+            .w(self.width)
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .child(
+                div()
+                    .id(self.id.with_suffix("tab_list"))
+                    .key_context("TabList")
+                    .relative()
+                    .flex()
+                    .items_center()
+                    .w_full()
+                    .p(LIST_INSET)
+                    .child(
+                        squircle()
+                            .absolute_expand()
+                            .rounded(corner_radius)
+                            .bg(background_color)
+                            .border(px(1.))
+                            .border_inside()
+                            .border_color(border_color),
+                    )
+                    .when(is_overflowing, |this| this.child(scroll_button(false)))
+                    .child(
+                        div()
+                            .id(self.id.with_suffix("tab_scroll"))
+                            .relative()
+                            .flex()
+                            .flex_1()
+                            .min_w_0()
+                            .overflow_x_scroll()
+                            .track_scroll(&scroll_handle)
+                            .map(|this| {
+                                let Some((left, width)) = indicator else {
+                                    return this.child(div().absolute());
+                                };
+
+                                this.child(match self.indicator {
+                                    TabIndicator::Underline => div()
+                                        .absolute()
+                                        .bottom_0()
+                                        .left(left)
+                                        .w(width)
+                                        .h(UNDERLINE_HEIGHT)
+                                        .rounded(px(100.))
+                                        .bg(primary_accent_color),
+                                    TabIndicator::Pill => div()
+                                        .absolute()
+                                        .top_0()
+                                        .bottom_0()
+                                        .left(left)
+                                        .w(width)
+                                        .child(
+                                            squircle()
+                                                .absolute_expand()
+                                                .rounded(tab_corner_radius)
+                                                .bg(border_color)
+                                                .border(px(1.))
+                                                .border_inside()
+                                                .border_highlight(0.15),
+                                        ),
+                                })
+                            })
+                            .children(tabs.into_iter().zip(tab_ids).enumerate().map(
+                                |(idx, (tab, tab_id))| {
+                                    let is_active = active_index == Some(idx);
+                                    let label = tab.label.clone().unwrap_or(tab.value.clone());
+
+                                    let mut toggle = Toggle::new(tab_id.clone())
+                                        .variant(GranularToggleVariant {
+                                            truthy: active_variant.clone(),
+                                            falsey: inactive_variant.clone(),
+                                        })
+                                        .checked(is_active)
+                                        .disabled(tab.disabled)
+                                        .focus_handle(
+                                            focus_handles[idx].clone().tab_stop(is_active),
+                                        )
+                                        .rounded(tab_corner_radius)
+                                        .gap(padding * 1.5)
+                                        .pl(padding * 3.)
+                                        .pr(if tab.closable { padding } else { padding * 3. })
+                                        // Lets the mouse down reach the wrapper, which starts drags.
+                                        .allow_mouse_propagation();
+
+                                    if let Some(icon) = tab.icon {
+                                        toggle = toggle.icon(icon);
+                                    }
+
+                                    if let Some(text) = tab.label {
+                                        toggle = toggle.text(text);
+                                    }
+
+                                    if tab.closable {
+                                        let on_close = self.on_close.clone();
+                                        let value = tab.value.clone();
+
+                                        toggle = toggle.child_right(
+                                            Button::new(tab_id.with_suffix("close"))
+                                                .variant(ButtonVariant::TertiaryGhost)
+                                                .icon(TesseraeIconKind::Close)
+                                                .icon_size(px(8.))
+                                                .p(px(4.))
+                                                .rounded(px(100.))
+                                                .disabled(tab.disabled)
+                                                .on_click(move |_event, window, cx| {
+                                                    if let Some(on_close) = on_close.as_ref() {
+                                                        (on_close)(&value, window, cx);
+                                                    }
+                                                }),
+                                        );
+                                    }
+
+                                    let activate = activate.clone();
+                                    let toggle = toggle.on_click(move |_event, window, cx| {
+                                        behavior.apply(window, cx);
+                                        (activate)(idx, window, cx);
+                                    });
+
+                                    let tab_bounds_state = tab_bounds_state.clone();
+
+                                    div()
+                                        .id(tab_id.with_suffix("drag"))
+                                        .relative()
+                                        .flex_shrink_0()
+                                        .rounded(tab_corner_radius)
+                                        .child(
+                                            canvas(
+                                                move |bounds, _window, cx| {
+                                                    tab_bounds_state.update(cx, |this, cx| {
+                                                        if this.len() <= idx {
+                                                            this.resize(idx + 1, Bounds::default());
+                                                        }
+
+                                                        if this[idx] != bounds {
+                                                            this[idx] = bounds;
+                                                            cx.notify();
+                                                        }
+                                                    });
+                                                },
+                                                |_, _, _, _| {},
+                                            )
+                                            .absolute()
+                                            .size_full(),
+                                        )
+                                        .child(toggle)
+                                        .when(self.reorderable && !tab.disabled, |this| {
+                                            let on_reorder = self.on_reorder.clone();
+                                            let tabs_id_on_drop = tabs_id.clone();
+
+                                            this.on_drag(
+                                                DraggedTab {
+                                                    tabs_id: tabs_id.clone(),
+                                                    index: idx,
+                                                    label,
+                                                },
+                                                |dragged, _offset, _window, cx| {
+                                                    cx.new(|_cx| dragged.clone())
+                                                },
+                                            )
+                                            .drag_over::<DraggedTab>(move |style, _, _, _| {
+                                                style.bg(border_color.alpha(0.5))
+                                            })
+                                            .on_drop(
+                                                move |dragged: &DraggedTab, window, cx| {
+                                                    if dragged.tabs_id != tabs_id_on_drop
+                                                        || dragged.index == idx
+                                                    {
+                                                        return;
+                                                    }
+
+                                                    if let Some(on_reorder) = on_reorder.as_ref() {
+                                                        (on_reorder)(
+                                                            dragged.index,
+                                                            idx,
+                                                            window,
+                                                            cx,
+                                                        );
+                                                    }
+                                                },
+                                            )
+                                        })
+                                },
+                            )),
+                    )
+                    .when(is_overflowing, |this| this.child(scroll_button(true)))
+                    .map(|this| {
+                        let navigate_prev = navigate.clone();
+                        let navigate_next = navigate.clone();
+                        let navigate_first = navigate.clone();
+                        let navigate_last = navigate;
+
+                        this.on_action(move |_: &SelectPrev, window, cx| {
+                            (navigate_prev)(RovingNavigation::Prev, window, cx)
+                        })
+                        .on_action(move |_: &SelectNext, window, cx| {
+                            (navigate_next)(RovingNavigation::Next, window, cx)
+                        })
+                        .on_action(move |_: &SelectFirst, window, cx| {
+                            (navigate_first)(RovingNavigation::First, window, cx)
+                        })
+                        .on_action(move |_: &SelectLast, window, cx| {
+                            (navigate_last)(RovingNavigation::Last, window, cx)
+                        })
+                    }),
+            )
+            .when_some(panel, |this, panel| {
+                this.child(div().id(self.id.with_suffix("panel")).w_full().child(panel))
+            })
+    }
+}
+
+/// Returns the selected tab if it exists and is enabled, otherwise the first enabled tab.
+fn active_tab_index(
+    selected: Option<&SharedString>,
+    values: &[SharedString],
+    disabled: &[bool],
+) -> Option<usize> {
+    selected
+        .and_then(|selected| values.iter().position(|value| value == selected))
+        .filter(|idx| !disabled[*idx])
+        .or_else(|| disabled.iter().position(|disabled| !disabled))
+}
+
+/// Returns the scroll offset after paging the tab list by most of its visible width.
+/// Offsets are negative, running from zero to `-max_offset`.
+fn scrolled_offset(offset: f32, visible_width: f32, max_offset: f32, forward: bool) -> f32 {
+    let page = visible_width * 0.8;
+    let offset = if forward {
+        offset - page
+    } else {
+        offset + page
+    };
+
+    offset.clamp(-max_offset.max(0.), 0.)
+}
+
+/// Returns the scroll offset that brings a tab spanning `tab` into the visible
+/// span, scrolling the minimum distance. Spans are the left and right edges in
+/// window coordinates, as laid out at `offset`.
+fn revealing_offset(offset: f32, tab: (f32, f32), visible: (f32, f32), max_offset: f32) -> f32 {
+    let (tab_left, tab_right) = tab;
+    let (visible_left, visible_right) = visible;
+
+    let offset = if tab_left < visible_left {
+        offset + (visible_left - tab_left)
+    } else if tab_right > visible_right {
+        offset - (tab_right - visible_right)
+    } else {
+        offset
+    };
+
+    offset.clamp(-max_offset.max(0.), 0.)
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use gpui::TestAppContext;
+
+    fn values(values: &[&'static str]) -> Vec<SharedString> {
+        values
+            .iter()
+            .map(|value| SharedString::from(*value))
+            .collect()
+    }
+
+    #[gpui::test]
+    fn test_tabs_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let tabs = Tabs::new("test-tabs")
+                .tab(Tab::new("general").label("General"))
+                .tabs([
+                    Tab::new("advanced").closable(true),
+                    Tab::new("about").disabled(true),
+                ])
+                .selected("advanced")
+                .indicator(TabIndicator::Pill)
+                .reorderable(true);
+
+            assert_eq!(tabs.tabs.len(), 3);
+            assert!(tabs.tabs[1].closable, "Tab should be closable");
+            assert!(tabs.tabs[2].disabled, "Tab should be disabled");
+            assert_eq!(tabs.selected, Some("advanced".into()));
+            assert_eq!(tabs.indicator, TabIndicator::Pill);
+            assert!(tabs.reorderable, "Tabs should be reorderable");
+        });
+    }
+
+    #[test]
+    fn test_active_tab_index() {
+        let all = values(&["a", "b", "c"]);
+        assert_eq!(
+            active_tab_index(Some(&"b".into()), &all, &[false; 3]),
+            Some(1)
+        );
+        assert_eq!(active_tab_index(None, &all, &[false; 3]), Some(0));
+        assert_eq!(
+            active_tab_index(Some(&"x".into()), &all, &[true, false, false]),
+            Some(1)
+        );
+        assert_eq!(
+            active_tab_index(Some(&"a".into()), &all, &[true, false, false]),
+            Some(1)
+        );
+        assert_eq!(active_tab_index(None, &[], &[]), None);
+    }
+
+    #[test]
+    fn test_revealing_offset() {
+        // Already visible.
+        assert_eq!(revealing_offset(-50., (20., 60.), (0., 100.), 200.), -50.);
+        // Past the right edge.
+        assert_eq!(revealing_offset(0., (80., 130.), (0., 100.), 200.), -30.);
+        // Before the left edge.
+        assert_eq!(revealing_offset(-50., (-20., 10.), (0., 100.), 200.), -30.);
+        // Clamped to the scrollable range.
+        assert_eq!(revealing_offset(-10., (-40., 0.), (0., 100.), 200.), 0.);
+    }
+
+    #[test]
+    fn test_scrolled_offset() {
+        assert_eq!(scrolled_offset(0., 100., 200., true), -80.);
+        assert_eq!(scrolled_offset(-80., 100., 200., false), 0.);
+        assert_eq!(scrolled_offset(-180., 100., 200., true), -200.);
+        assert_eq!(scrolled_offset(-20., 100., 200., false), 0.);
+        assert_eq!(scrolled_offset(0., 100., 0., true), 0.);
+    }
+
+    #[gpui::test]
+    fn test_tabs_only_render_active_panel(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        let rendered = Rc::new([Cell::new(false), Cell::new(false)]);
+        let rendered_in_view = rendered.clone();
+
+        let (_view, _cx) = cx.add_window_view(|_window, cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            TabsTestView {
+                selected: "a".into(),
+                focus_handle: cx.focus_handle(),
+                changes: Vec::new(),
+                rendered: Some(rendered_in_view),
+            }
+        });
+
+        assert!(rendered[0].get(), "Active panel should be rendered");
+        assert!(!rendered[1].get(), "Inactive panel should not be rendered");
+    }
+
+    #[gpui::test]
+    fn test_tabs_keyboard_navigation(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        let (view, cx) = cx.add_window_view(|window, cx| {
+            cx.set_theme(Theme::DEFAULT);
+            init(cx);
+
+            let focus_handle = cx.focus_handle();
+            focus_handle.focus(window, cx);
+
+            TabsTestView {
+                selected: "a".into(),
+                focus_handle,
+                changes: Vec::new(),
+                rendered: None,
+            }
+        });
+
+        cx.simulate_keystrokes("right");
+        cx.simulate_keystrokes("home");
+        cx.simulate_keystrokes("end");
+
+        cx.update(|_window, cx| {
+            let changes: Vec<String> = view
+                .read(cx)
+                .changes
+                .iter()
+                .map(|change| change.to_string())
+                .collect();
+
+            // "b" is disabled, so navigation skips over it.
+            assert_eq!(changes, vec!["c", "a", "c"]);
+        });
+    }
+
+    struct TabsTestView {
+        selected: SharedString,
+        focus_handle: FocusHandle,
+        changes: Vec<SharedString>,
+        rendered: Option<Rc<[Cell<bool>; 2]>>,
+    }
+
+    impl Render for TabsTestView {
+        fn render(
+            &mut self,
+            _window: &mut gpui::Window,
+            cx: &mut gpui::Context<Self>,
+        ) -> impl IntoElement {
+            let entity = cx.entity();
+
+            let panel = |idx: usize, rendered: Option<Rc<[Cell<bool>; 2]>>| {
+                move |_window: &mut Window, _cx: &mut App| {
+                    if let Some(rendered) = rendered {
+                        rendered[idx].set(true);
+                    }
+
+                    div()
+                }
+            };
+
+            Tabs::new("test-tabs")
+                .tab(
+                    Tab::new("a")
+                        .label("A")
+                        .focus_handle(self.focus_handle.clone())
+                        .panel(panel(0, self.rendered.clone())),
+                )
+                .tab(Tab::new("b").label("B").disabled(true))
+                .tab(
+                    Tab::new("c")
+                        .label("C")
+                        .closable(true)
+                        .panel(panel(1, self.rendered.clone())),
+                )
+                .selected(self.selected.clone())
+                .on_change(move |value, _window, cx| {
+                    let value = value.clone();
+                    entity.update(cx, |this, cx| {
+                        this.selected = value.clone();
+                        this.changes.push(value);
+                        cx.notify();
+                    });
+                })
+        }
+    }
+}
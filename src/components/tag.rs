@@ -0,0 +1,157 @@
+use gpui::{
+    App, Edges, IntoElement, Length, ParentElement, RenderOnce, SharedString, Styled, Window, div,
+    prelude::FluentBuilder, px,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    components::Icon,
+    primitives::min_w0_wrapper,
+    theme::{ThemeAccentKind, ThemeExt, ThemeLayoutCornerRadiiKind},
+    utils::RgbaExt,
+};
+
+/// A compact colored label, such as the name of the model that wrote a message.
+#[derive(IntoElement)]
+pub struct Tag {
+    text: SharedString,
+    icon: Option<SharedString>,
+    accent: ThemeAccentKind,
+    corner_radius: ThemeLayoutCornerRadiiKind,
+    margin: Edges<Option<Length>>,
+}
+
+impl Tag {
+    /// Creates a new tag with the given text.
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        Self {
+            text: text.into(),
+            icon: None,
+            accent: ThemeAccentKind::Primary,
+            corner_radius: ThemeLayoutCornerRadiiKind::Sm,
+            margin: Edges::default(),
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets an icon to display before the text.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Sets the accent color the tag is tinted with.
+    pub fn accent(mut self, accent: ThemeAccentKind) -> Self {
+        self.accent = accent;
+        self
+    }
+
+    /// Sets the corner radius from the theme. Defaults to small.
+    pub fn rounded(mut self, corner_radius: ThemeLayoutCornerRadiiKind) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+}
+
+impl RenderOnce for Tag {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        const TINT_ALPHA: f32 = 0.12;
+        const BORDER_ALPHA: f32 = 0.25;
+
+        let padding = cx.get_theme().layout.padding.md;
+        let text_size = cx
+            .get_theme()
+            .layout
+            .text
+            .default_font
+            .sizes
+            .caption
+            .clone();
+        let accent_color = self.accent.resolve(cx);
+
+        div()
+            .relative()
+            .flex()
+            .flex_shrink_0()
+            .items_center()
+            .gap(padding)
+            .px(padding * 2.)
+            .py(padding * 0.5)
+            .max_w_full()
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .child(
+                squircle()
+                    .absolute_expand()
+                    .rounded(self.corner_radius.resolve(cx))
+                    .bg(accent_color.alpha(TINT_ALPHA))
+                    .border(px(1.))
+                    .border_inside()
+                    .border_color(accent_color.alpha(BORDER_ALPHA)),
+            )
+            .when_some(self.icon, |this, icon| {
+                this.child(Icon::new(icon).size(px(11.)).color(accent_color))
+            })
+            .child(
+                min_w0_wrapper()
+                    .relative()
+                    .text_ellipsis()
+                    .text_size(text_size)
+                    .text_color(accent_color)
+                    .child(self.text),
+            )
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use gpui::TestAppContext;
+
+    #[gpui::test]
+    fn test_tag_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let tag = Tag::new("gpt-4o")
+                .icon("icons/test.svg")
+                .accent(ThemeAccentKind::Constructive)
+                .rounded(ThemeLayoutCornerRadiiKind::Md);
+
+            assert_eq!(tag.text, SharedString::from("gpt-4o"));
+            assert!(tag.icon.is_some(), "Tag should have an icon");
+            assert!(matches!(tag.accent, ThemeAccentKind::Constructive));
+            assert!(matches!(tag.corner_radius, ThemeLayoutCornerRadiiKind::Md));
+        });
+    }
+}
@@ -0,0 +1,714 @@
+use std::{rc::Rc, sync::Arc};
+
+use gpui::{
+    AnyElement, App, Bounds, Edges, ElementId, Entity, InteractiveElement, IntoElement, Length,
+    MouseButton, ParentElement, Pixels, RenderOnce, SharedString, StatefulInteractiveElement,
+    Styled, Window, canvas, div, prelude::FluentBuilder, px, radians, relative,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    ElementIdExt, PositionalParentElement, TesseraeIconKind,
+    components::{
+        Icon, Input, Toggle, ToggleVariant, VirtualList,
+        select::{SelectMenuPlacement, measure_height},
+    },
+    conitional_transition_update,
+    extensions::{
+        deferrable::{Deferrable, DeferredConfig},
+        mouse_behavior::{MouseBehavior, MouseBehaviorExt},
+        mouse_handleable::MouseHandleable,
+    },
+    primitives::layer_scope,
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{PixelsExt, disabled_transition},
+};
+
+mod time;
+pub use time::*;
+
+mod state;
+pub use state::*;
+
+/// The height the list of times grows to before it scrolls.
+const DEFAULT_MAX_LIST_HEIGHT: Pixels = px(240.);
+
+struct TimePickerStyles {
+    margin: Edges<Option<Length>>,
+    width: Length,
+    min_width: Option<Length>,
+    max_width: Option<Length>,
+    menu_placement: SelectMenuPlacement,
+    placeholder: Option<SharedString>,
+}
+
+impl Default for TimePickerStyles {
+    fn default() -> Self {
+        Self {
+            margin: Edges::default(),
+            width: Length::Auto,
+            min_width: None,
+            max_width: None,
+            menu_placement: SelectMenuPlacement::default(),
+            placeholder: None,
+        }
+    }
+}
+
+/// A time input with a popover list of times to pick from.
+///
+/// Times can also be typed in either 24-hour or 12-hour form, such as
+/// "14:30" or "2:30 PM".
+#[derive(IntoElement)]
+pub struct TimePicker {
+    id: ElementId,
+    disabled: bool,
+    layer: Option<ThemeLayerKind>,
+    state: Arc<TimePickerState>,
+    mouse_behavior: MouseBehavior,
+    deferred_config: DeferredConfig,
+    style: TimePickerStyles,
+}
+
+impl TimePicker {
+    /// Creates a new time picker with the given ID and shared state.
+    pub fn new(id: impl Into<ElementId>, state: impl Into<Arc<TimePickerState>>) -> Self {
+        Self {
+            id: id.into(),
+            disabled: false,
+            layer: None,
+            state: state.into(),
+            mouse_behavior: MouseBehavior::default(),
+            deferred_config: DeferredConfig::default(),
+            style: TimePickerStyles::default(),
+        }
+    }
+
+    /// Sets the layer of the time picker.
+    /// Defaults to the layer provided by the enclosing surface.
+    pub fn layer(mut self, layer: ThemeLayerKind) -> Self {
+        self.layer = Some(layer);
+        self
+    }
+
+    /// Sets where the menu opens.
+    /// Defaults to below, flipping above when there's more room there.
+    pub fn menu_placement(mut self, placement: SelectMenuPlacement) -> Self {
+        self.style.menu_placement = placement;
+        self
+    }
+
+    /// Sets a custom placeholder text shown when no time is selected.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.style.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets a fixed width.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.style.width = width.into();
+        self
+    }
+
+    /// Sets width to auto, sizing based on content.
+    pub fn w_auto(mut self) -> Self {
+        self.style.width = Length::Auto;
+        self
+    }
+
+    /// Sets width to fill the parent container.
+    pub fn w_full(mut self) -> Self {
+        self.style.width = relative(100.).into();
+        self
+    }
+
+    /// Sets the minimum width of the element. [Docs](https://tailwindcss.com/docs/min-width)
+    pub fn min_w(mut self, width: impl Into<Length>) -> Self {
+        self.style.min_width = Some(width.into());
+        self
+    }
+
+    /// Sets the maximum width of the element. [Docs](https://tailwindcss.com/docs/max-width)
+    pub fn max_w(mut self, width: impl Into<Length>) -> Self {
+        self.style.max_width = Some(width.into());
+        self
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.style.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.style.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the disabled state, preventing interaction.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl MouseBehaviorExt for TimePicker {
+    fn mouse_behavior_mut(&mut self) -> &mut MouseBehavior {
+        &mut self.mouse_behavior
+    }
+}
+
+impl Deferrable for TimePicker {
+    const DEFAULT_PRIORITY: usize = 1;
+
+    fn deferred_config_mut(&mut self) -> &mut DeferredConfig {
+        &mut self.deferred_config
+    }
+
+    fn deferred_config(&self) -> &DeferredConfig {
+        &self.deferred_config
+    }
+}
+
+impl RenderOnce for TimePicker {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
+        let layer = self.layer.unwrap_or_else(|| ThemeLayerKind::current(cx));
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let corner_radius = cx.get_theme().layout.corner_radii.md;
+        let line_height = cx.get_theme().layout.text.default_font.line_height;
+        let text_size = cx.get_theme().layout.text.default_font.sizes.body.clone();
+        let padding = cx.get_theme().layout.padding.md;
+        let horizontal_padding = cx.get_theme().layout.padding.lg - padding;
+        let vertical_padding =
+            cx.get_theme()
+                .layout
+                .size
+                .lg
+                .padding_needed_for_height(window, text_size, line_height)
+                - padding;
+        let state = self.state.clone();
+
+        let focus_handle = window
+            .use_keyed_state(
+                self.id.with_suffix("state:focus_handle"),
+                cx,
+                |_window, cx| cx.focus_handle(),
+            )
+            .read(cx)
+            .clone();
+
+        // Register this TimePicker's focus handle with the shared state
+        state.register_focus_handle(cx, &focus_handle);
+
+        let is_focus = focus_handle.contains_focused(window, cx);
+
+        let is_disabled = self.disabled;
+        let disabled_transition = disabled_transition(self.id.clone(), window, cx, is_disabled);
+
+        if is_disabled && is_focus {
+            window.blur();
+        }
+
+        // A typed time is picked once focus leaves the picker, and a menu
+        // dismissed with Escape stays closed until then.
+        let was_focus = window.use_keyed_state(
+            self.id.with_suffix("state:was_focus"),
+            cx,
+            |_window, _cx| false,
+        );
+        if *was_focus.read(cx) != is_focus {
+            was_focus.update(cx, |this, _cx| *this = is_focus);
+
+            if !is_focus {
+                let _ = state.commit_input(cx);
+                state.hide_menu(cx);
+                state.menu.reset_dismissed(cx);
+            }
+        }
+
+        let menu_visible_transition = conitional_transition_update!(
+            cx,
+            state.menu_visible_transition.clone(),
+            {
+                is_focus && !state.is_menu_dismissed(cx) => true,
+                _ => false
+            }
+        );
+
+        let menu_visible_delta = menu_visible_transition.evaluate(window, cx).value();
+
+        // Track whether the highlight was synced to the selection for this menu open session
+        let has_synced = window.use_keyed_state(
+            self.id.with_suffix("state:has_synced"),
+            cx,
+            |_window, _cx| false,
+        );
+        if menu_visible_delta == 0. && *has_synced.read(cx) {
+            has_synced.update(cx, |synced, _cx| *synced = false);
+        }
+
+        // Highlight the time being typed when it's in the menu.
+        let input_text = state.input.read(cx).value();
+        let last_input_text = window.use_keyed_state(
+            self.id.with_suffix("state:last_input_text"),
+            cx,
+            |_window, _cx| SharedString::default(),
+        );
+        if *last_input_text.read(cx) != input_text {
+            last_input_text.update(cx, |this, _cx| *this = input_text);
+
+            if is_focus {
+                state.preview_input(cx);
+            }
+        }
+
+        // Where the picker is in the window and how tall its menu is, to open
+        // the menu where it fits.
+        let trigger_bounds = window.use_keyed_state(
+            self.id.with_suffix("state:trigger_bounds"),
+            cx,
+            |_window, _cx| None::<Bounds<Pixels>>,
+        );
+        let menu_height = window.use_keyed_state(
+            self.id.with_suffix("state:menu_height"),
+            cx,
+            |_window, _cx| px(0.),
+        );
+
+        let menu_gap = cx.get_theme().layout.padding.md;
+        let (menu_placement, menu_room) = match *trigger_bounds.read(cx) {
+            Some(bounds) => self.style.menu_placement.resolve(
+                bounds,
+                window.viewport_size().height,
+                *menu_height.read(cx),
+                menu_gap,
+            ),
+            None => (SelectMenuPlacement::Below, DEFAULT_MAX_LIST_HEIGHT),
+        };
+        let max_list_height = DEFAULT_MAX_LIST_HEIGHT
+            .min(menu_room - padding * 2.)
+            .max(px(0.));
+
+        let placeholder = self.style.placeholder.clone().unwrap_or_else(|| {
+            match state.clock_format() {
+                ClockFormat::TwentyFourHour => "HH:MM",
+                ClockFormat::TwelveHour => "H:MM AM",
+            }
+            .into()
+        });
+
+        let menu = (menu_visible_delta != 0.).then(|| {
+            if !*has_synced.read(cx) {
+                state.sync_highlight_to_selection(cx);
+                has_synced.update(cx, |synced, _cx| *synced = true);
+            }
+
+            let options = state.options();
+            let list_state = state.menu_list.read(cx).clone();
+            if list_state.item_count() != options.len() {
+                list_state.reset(options.len());
+            }
+
+            // Track which time is currently hovered by mouse
+            let hovered_time: Entity<Option<ClockTime>> = window.use_keyed_state(
+                self.id.with_suffix("state:hovered_time"),
+                cx,
+                |_window, _cx| None,
+            );
+
+            let rows = Rc::new(TimePickerRows {
+                id: self.id.clone(),
+                state: state.clone(),
+                options,
+                hovered_time,
+                padding,
+                horizontal_padding,
+                vertical_padding,
+                corner_radius,
+            });
+
+            // The deferred menu is drawn after any enclosing surface, so the
+            // layer resolved here is provided to it again.
+            self.apply_deferred(layer_scope(
+                layer,
+                div()
+                    .relative()
+                    .w_full()
+                    .opacity(menu_visible_delta)
+                    .p(padding)
+                    .child(
+                        squircle()
+                            .absolute_expand()
+                            .rounded(corner_radius)
+                            .bg(background_color)
+                            .border_color(border_color)
+                            .border(px(1.))
+                            .border_inside(),
+                    )
+                    .child(
+                        VirtualList::new(
+                            self.id.with_suffix("menu_times"),
+                            &list_state,
+                            move |index, window, cx| rows.render(index, window, cx),
+                        )
+                        .h_auto()
+                        .max_h(max_list_height),
+                    ),
+            ))
+        });
+
+        let state_on_mouse_down = state.clone();
+
+        div()
+            .id(self.id.clone())
+            .w(self.style.width)
+            .h_auto()
+            .when_some(self.style.margin.top, |this, v| this.mt(v))
+            .when_some(self.style.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.style.margin.left, |this, v| this.ml(v))
+            .when_some(self.style.margin.right, |this, v| this.mr(v))
+            .when_some(self.style.min_width, |this, v| this.min_w(v))
+            .when_some(self.style.max_width, |this, v| this.max_w(v))
+            .flex()
+            .flex_col()
+            .opacity(*disabled_transition.evaluate(window, cx))
+            .child(
+                canvas(
+                    move |bounds, _window, cx| {
+                        trigger_bounds.update(cx, |this, cx| {
+                            if *this != Some(bounds) {
+                                *this = Some(bounds);
+                                cx.notify();
+                            }
+                        });
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(
+                Input::new(self.id.with_suffix("input"), state.input.clone())
+                    .w_full()
+                    .layer(layer)
+                    .disabled(is_disabled)
+                    .placeholder(placeholder)
+                    .child_right(
+                        Icon::new(TesseraeIconKind::ArrowDown)
+                            .size(px(11.))
+                            .color(secondary_text_color)
+                            .map(|this| {
+                                let rotation = radians(
+                                    ((1. - menu_visible_delta) * 180.) * std::f32::consts::PI
+                                        / 180.0,
+                                );
+
+                                this.rotate(rotation)
+                            }),
+                    ),
+            )
+            .when_some(menu, |this, menu| {
+                this.child(
+                    div()
+                        .absolute()
+                        .left_0()
+                        .w_full()
+                        .map(|this| match menu_placement {
+                            SelectMenuPlacement::Above => this.bottom(relative(1.)).pb(menu_gap),
+                            _ => this.top_full().pt(menu_gap),
+                        })
+                        .child(measure_height(menu_height))
+                        .child(menu),
+                )
+            })
+            .when(!is_disabled, |this| {
+                let behavior = self.mouse_behavior;
+
+                let state_on_up = state.clone();
+                let state_on_down = state.clone();
+                let state_on_confirm = state.clone();
+                let state_on_dismiss = state.clone();
+
+                this.key_context("TimePicker")
+                    .on_action(move |_: &MoveUp, _window, cx| {
+                        state_on_up.show_menu_or_move_highlight(cx, -1);
+                    })
+                    .on_action(move |_: &MoveDown, _window, cx| {
+                        state_on_down.show_menu_or_move_highlight(cx, 1);
+                    })
+                    .on_action(move |_: &Confirm, _window, cx| {
+                        if state_on_confirm.confirm(cx).is_ok() {
+                            state_on_confirm.dismiss_menu(cx);
+                        }
+                    })
+                    .on_action(move |_: &Dismiss, _window, cx| {
+                        state_on_dismiss.dismiss_menu(cx);
+                    })
+                    .on_mouse_down(MouseButton::Left, move |_event, window, cx| {
+                        behavior.apply(window, cx);
+                        state_on_mouse_down.show_menu(cx);
+
+                        if !is_focus {
+                            state_on_mouse_down
+                                .input
+                                .read(cx)
+                                .focus_handle
+                                .clone()
+                                .focus(window, cx);
+                        }
+                    })
+                    .track_focus(&focus_handle)
+            })
+    }
+}
+
+/// Renders the times of a menu's virtualized list as they scroll into view.
+struct TimePickerRows {
+    id: ElementId,
+    state: Arc<TimePickerState>,
+    options: Vec<ClockTime>,
+    hovered_time: Entity<Option<ClockTime>>,
+    padding: Pixels,
+    horizontal_padding: Pixels,
+    vertical_padding: Pixels,
+    corner_radius: Pixels,
+}
+
+impl TimePickerRows {
+    fn render(&self, index: usize, _window: &mut Window, cx: &mut App) -> AnyElement {
+        let Some(&time) = self.options.get(index) else {
+            return div().into_any_element();
+        };
+
+        let selected = self.state.selected_time(cx) == Some(time);
+        let show_highlight =
+            self.hovered_time.read(cx).is_none() && self.state.highlighted_time(cx) == Some(time);
+
+        let variant = if selected {
+            ToggleVariant::Secondary
+        } else {
+            ToggleVariant::Tertiary
+        };
+
+        let state = self.state.clone();
+        let hovered_time = self.hovered_time.clone();
+
+        Toggle::new(self.id.with_suffix("time").with_suffix(time.to_string()))
+            .text(time.format(self.state.clock_format()))
+            .w_full()
+            .max_w(relative(1.))
+            .checked(selected)
+            .variant(variant)
+            .force_hover(show_highlight)
+            .justify_start()
+            .rounded(self.corner_radius - self.padding)
+            .pl(self.horizontal_padding)
+            .pr(self.horizontal_padding)
+            .pt(self.vertical_padding)
+            .pb(self.vertical_padding)
+            .on_any_mouse_down(|_event, window, _cx| {
+                window.prevent_default();
+            })
+            .on_hover(move |is_hovered, _window, cx| {
+                hovered_time.update(cx, |this, cx| {
+                    if *is_hovered {
+                        *this = Some(time);
+                    } else if *this == Some(time) {
+                        *this = None;
+                    }
+                    cx.notify();
+                });
+            })
+            .on_click(move |_event, window, cx| {
+                if state.select_time(cx, time).is_ok() {
+                    state.dismiss_menu(cx);
+                }
+
+                state.input.read(cx).focus_handle.clone().focus(window, cx);
+            })
+            .into_any_element()
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+
+    use gpui::TestAppContext;
+
+    fn time(hour: u8, minute: u8) -> ClockTime {
+        ClockTime::new(hour, minute).unwrap()
+    }
+
+    fn set_input_text(state: &TimePickerState, cx: &mut App, text: &str) {
+        state.input.update(cx, |this, _cx| {
+            this.value = Some(SharedString::from(text.to_string()));
+        });
+    }
+
+    #[gpui::test]
+    fn test_time_picker_creation(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            let state = TimePickerState::from_cx(cx);
+            let picker = TimePicker::new("test-time-picker", state);
+            assert!(!picker.disabled, "TimePicker should start enabled");
+        });
+    }
+
+    #[gpui::test]
+    fn test_select_time_emits_event(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| {
+            let mut state = TimePickerState::from_cx(cx);
+            state.set_clock_format(ClockFormat::TwelveHour);
+            state
+        });
+        let events = Rc::new(RefCell::new(Vec::new()));
+
+        cx.update(|cx| {
+            let events = events.clone();
+            cx.subscribe(
+                state.events(),
+                move |_events, event: &TimeSelectionChanged, _cx| {
+                    events.borrow_mut().push(*event);
+                },
+            )
+            .detach();
+        });
+
+        cx.update(|cx| {
+            state.select_time(cx, time(14, 30)).unwrap();
+            state.select_time(cx, time(14, 30)).unwrap();
+
+            assert_eq!(state.selected_time(cx), Some(time(14, 30)));
+            assert_eq!(state.input.read(cx).value(), "2:30 PM");
+        });
+
+        assert_eq!(
+            *events.borrow(),
+            vec![TimeSelectionChanged {
+                old: None,
+                new: Some(time(14, 30)),
+            }]
+        );
+    }
+
+    #[gpui::test]
+    fn test_select_time_outside_limits(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| {
+            let mut state = TimePickerState::from_cx(cx);
+            state.set_min_time(Some(time(9, 0)));
+            state.set_max_time(Some(time(17, 0)));
+            state
+        });
+
+        cx.update(|cx| {
+            assert_eq!(
+                state.select_time(cx, time(8, 59)),
+                Err(TimeSelectError::BeforeMin)
+            );
+            assert_eq!(
+                state.select_time(cx, time(17, 1)),
+                Err(TimeSelectError::AfterMax)
+            );
+            assert_eq!(state.selected_time(cx), None);
+        });
+    }
+
+    #[gpui::test]
+    fn test_commit_input(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| TimePickerState::from_cx(cx));
+
+        cx.update(|cx| {
+            // Typed times don't have to be offered in the menu.
+            set_input_text(&state, cx, "9:07 pm");
+            assert!(state.commit_input(cx).is_ok());
+            assert_eq!(state.selected_time(cx), Some(time(21, 7)));
+            assert_eq!(state.input.read(cx).value(), "21:07");
+
+            set_input_text(&state, cx, "25:00");
+            assert_eq!(state.commit_input(cx), Err(TimeSelectError::Invalid));
+            assert_eq!(state.selected_time(cx), Some(time(21, 7)));
+            assert_eq!(state.input.read(cx).value(), "21:07");
+
+            set_input_text(&state, cx, "");
+            assert!(state.commit_input(cx).is_ok());
+            assert_eq!(state.selected_time(cx), None);
+        });
+    }
+
+    #[gpui::test]
+    fn test_move_highlight(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| {
+            let mut state = TimePickerState::from_cx(cx);
+            state.set_step_minutes(30);
+            state.set_min_time(Some(time(9, 0)));
+            state.set_max_time(Some(time(10, 0)));
+            state
+        });
+
+        cx.update(|cx| {
+            state.move_highlight(cx, 1);
+            assert_eq!(state.highlighted_time(cx), Some(time(9, 0)));
+
+            state.move_highlight(cx, 1);
+            state.move_highlight(cx, 1);
+            state.move_highlight(cx, 1);
+            assert_eq!(state.highlighted_time(cx), Some(time(10, 0)));
+
+            state.move_highlight(cx, -1);
+            state.confirm(cx).unwrap();
+            assert_eq!(state.selected_time(cx), Some(time(9, 30)));
+
+            // Without a highlight, the arrows start from the time nearest the selection.
+            state.hide_menu(cx);
+            state.select_time(cx, time(9, 40)).unwrap();
+            state.move_highlight(cx, -1);
+            assert_eq!(state.highlighted_time(cx), Some(time(9, 30)));
+        });
+    }
+
+    #[gpui::test]
+    fn test_typed_time_replaces_highlight(cx: &mut TestAppContext) {
+        let state = cx.update(|cx| TimePickerState::from_cx(cx));
+
+        cx.update(|cx| {
+            state.move_highlight(cx, 1);
+            assert_eq!(state.highlighted_time(cx), Some(time(0, 0)));
+
+            set_input_text(&state, cx, "14:15");
+            state.preview_input(cx);
+            assert_eq!(state.highlighted_time(cx), Some(time(14, 15)));
+
+            // A time between the options isn't highlighted, so enter picks it as typed.
+            set_input_text(&state, cx, "14:20");
+            state.preview_input(cx);
+            assert_eq!(state.highlighted_time(cx), None);
+
+            state.confirm(cx).unwrap();
+            assert_eq!(state.selected_time(cx), Some(time(14, 20)));
+        });
+    }
+}
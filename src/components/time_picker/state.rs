@@ -0,0 +1,405 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use gpui::{
+    App, AppContext, ElementId, Entity, EventEmitter, FocusHandle, KeyBinding, SharedString,
+    Window, actions,
+};
+use gpui_transitions::{BoolLerp, Transition};
+use thiserror::Error;
+
+use crate::{
+    ElementIdExt,
+    components::{
+        VirtualListState,
+        time_picker::{ClockFormat, ClockTime, nearest_option_index, time_options},
+    },
+    primitives::input::InputState,
+    utils::{PopoverState, TypedSelection},
+};
+
+actions!(time_picker, [MoveUp, MoveDown, Confirm, Dismiss]);
+
+/// The minutes between the times offered when no step is set.
+const DEFAULT_STEP_MINUTES: u16 = 15;
+
+/// Emitted by [`TimePickerState::events`] when the selected time changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TimeSelectionChanged {
+    /// The time selected before the change.
+    pub old: Option<ClockTime>,
+    /// The time selected after the change.
+    pub new: Option<ClockTime>,
+}
+
+/// Emits [`TimeSelectionChanged`] events for a [`TimePickerState`].
+///
+/// Subscribe to it with `cx.subscribe(state.events(), ...)`.
+pub struct TimePickerStateEvents;
+
+impl EventEmitter<TimeSelectionChanged> for TimePickerStateEvents {}
+
+/// Shared state for a TimePicker component, managing the selected time,
+/// the times offered and menu visibility.
+pub struct TimePickerState {
+    pub(crate) selected_time: Entity<Option<ClockTime>>,
+    /// The time moved with the arrow keys, picked with enter.
+    pub(crate) highlighted_time: Entity<Option<ClockTime>>,
+    /// The text field the time can be typed into.
+    pub(crate) input: Entity<InputState>,
+    pub(crate) events: Entity<TimePickerStateEvents>,
+    pub(crate) step_minutes: u16,
+    pub(crate) min_time: Option<ClockTime>,
+    pub(crate) max_time: Option<ClockTime>,
+    pub(crate) clock_format: ClockFormat,
+    /// The virtualized list of times in the menu.
+    pub(crate) menu_list: Entity<VirtualListState>,
+    /// Animated transition for menu visibility.
+    pub menu_visible_transition: Transition<BoolLerp<f32>>,
+    /// Dismissal and focus state of the menu, shared by all TimePicker components
+    /// using this state.
+    pub(crate) menu: PopoverState,
+}
+
+impl TimePickerState {
+    /// Creates state using window-keyed storage, persisting across renders.
+    pub fn from_window(id: impl Into<ElementId>, window: &mut Window, cx: &mut App) -> Self {
+        let id = id.into();
+
+        Self {
+            selected_time: window.use_keyed_state(
+                id.with_suffix("state:selected_time"),
+                cx,
+                |_window, _cx| None,
+            ),
+            highlighted_time: window.use_keyed_state(
+                id.with_suffix("state:highlighted_time"),
+                cx,
+                |_window, _cx| None,
+            ),
+            input: window.use_keyed_state(id.with_suffix("state:input"), cx, |_window, cx| {
+                InputState::new(cx)
+            }),
+            events: window.use_keyed_state(id.with_suffix("state:events"), cx, |_window, _cx| {
+                TimePickerStateEvents
+            }),
+            step_minutes: DEFAULT_STEP_MINUTES,
+            min_time: None,
+            max_time: None,
+            clock_format: ClockFormat::default(),
+            menu_list: window.use_keyed_state(
+                id.with_suffix("state:menu_list"),
+                cx,
+                |_window, _cx| VirtualListState::new(0),
+            ),
+            menu_visible_transition: PopoverState::visible_transition_from_window(&id, window, cx),
+            menu: PopoverState::from_window(&id, window, cx),
+        }
+    }
+
+    /// Creates state from an App context.
+    pub fn from_cx(cx: &mut App) -> Self {
+        Self {
+            selected_time: cx.new(|_cx| None),
+            highlighted_time: cx.new(|_cx| None),
+            input: cx.new(|cx| InputState::new(cx)),
+            events: cx.new(|_cx| TimePickerStateEvents),
+            step_minutes: DEFAULT_STEP_MINUTES,
+            min_time: None,
+            max_time: None,
+            clock_format: ClockFormat::default(),
+            menu_list: cx.new(|_cx| VirtualListState::new(0)),
+            menu_visible_transition: PopoverState::visible_transition_from_cx(cx),
+            menu: PopoverState::from_cx(cx),
+        }
+    }
+
+    /// Sets the minutes between the times offered in the menu. Defaults to 15.
+    ///
+    /// Any time can still be typed into the input.
+    pub fn set_step_minutes(&mut self, step_minutes: u16) {
+        self.step_minutes = step_minutes;
+    }
+
+    /// Sets the earliest time that can be picked.
+    pub fn set_min_time(&mut self, min_time: Option<ClockTime>) {
+        self.min_time = min_time;
+    }
+
+    /// Sets the latest time that can be picked.
+    pub fn set_max_time(&mut self, max_time: Option<ClockTime>) {
+        self.max_time = max_time;
+    }
+
+    /// Sets how times are written in the input and menu. Defaults to 24-hour.
+    pub fn set_clock_format(&mut self, clock_format: ClockFormat) {
+        self.clock_format = clock_format;
+    }
+
+    /// Returns how times are written in the input and menu.
+    pub fn clock_format(&self) -> ClockFormat {
+        self.clock_format
+    }
+
+    /// Returns the entity that emits [`TimeSelectionChanged`] events.
+    pub fn events(&self) -> &Entity<TimePickerStateEvents> {
+        &self.events
+    }
+
+    /// Returns the text field the time can be typed into.
+    pub fn input(&self) -> &Entity<InputState> {
+        &self.input
+    }
+
+    /// Returns the times offered in the menu.
+    pub(crate) fn options(&self) -> Vec<ClockTime> {
+        time_options(self.step_minutes, self.min_time, self.max_time)
+    }
+
+    /// Checks whether `time` can be picked.
+    pub fn check_time(&self, time: ClockTime) -> Result<(), TimeSelectError> {
+        if self.min_time.is_some_and(|min_time| time < min_time) {
+            return Err(TimeSelectError::BeforeMin);
+        }
+
+        if self.max_time.is_some_and(|max_time| time > max_time) {
+            return Err(TimeSelectError::AfterMax);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the selected time.
+    pub fn selected_time(&self, cx: &App) -> Option<ClockTime> {
+        *self.selected_time.read(cx)
+    }
+
+    /// Picks a time, returning an error if it can't be picked.
+    pub fn select_time(&self, cx: &mut App, time: ClockTime) -> Result<(), TimeSelectError> {
+        self.check_time(time)?;
+        self.set_selection(cx, Some(time));
+
+        Ok(())
+    }
+
+    /// Clears the selected time.
+    pub fn clear_selection(&self, cx: &mut App) {
+        self.set_selection(cx, None);
+    }
+
+    /// Sets the selection, emitting a [`TimeSelectionChanged`] event if it changed,
+    /// and shows it in the input.
+    fn set_selection(&self, cx: &mut App, new: Option<ClockTime>) {
+        let old = self.selected_time(cx);
+
+        if old != new {
+            self.selected_time.update(cx, |this, cx| {
+                *this = new;
+                cx.notify();
+            });
+
+            self.events.update(cx, |_this, cx| {
+                cx.emit(TimeSelectionChanged { old, new });
+            });
+        }
+
+        self.sync_input_to_selection(cx);
+    }
+
+    /// Returns the text the input shows for the selected time.
+    pub fn selection_text(&self, cx: &App) -> SharedString {
+        self.selected_time(cx)
+            .map(|time| time.format(self.clock_format).into())
+            .unwrap_or_default()
+    }
+
+    /// Replaces the text of the input with the selected time.
+    pub fn sync_input_to_selection(&self, cx: &mut App) {
+        TypedSelection::sync_input_to_selection(self, cx);
+    }
+
+    /// Selects the time typed into the input, or clears the selection if it's empty.
+    ///
+    /// If the text isn't a time that can be picked, the input goes back to
+    /// showing the selection and the error is returned.
+    pub fn commit_input(&self, cx: &mut App) -> Result<(), TimeSelectError> {
+        TypedSelection::commit_input(self, cx)
+    }
+
+    /// Highlights the time being typed into the input if it's offered in the
+    /// menu, so enter picks what was typed rather than a stale highlight.
+    pub(crate) fn preview_input(&self, cx: &mut App) {
+        let time = match self.parse_input(cx) {
+            Some(Ok(time)) if self.options().contains(&time) => Some(time),
+            _ => None,
+        };
+
+        self.set_highlighted_time(cx, time);
+    }
+
+    /// Returns the highlighted time, if any.
+    pub fn highlighted_time(&self, cx: &App) -> Option<ClockTime> {
+        *self.highlighted_time.read(cx)
+    }
+
+    /// Highlights `time` and scrolls the menu to show it.
+    pub fn set_highlighted_time(&self, cx: &mut App, time: Option<ClockTime>) {
+        self.highlighted_time.update(cx, |this, cx| {
+            if *this != time {
+                *this = time;
+                cx.notify();
+            }
+        });
+
+        if let Some(index) = time.and_then(|time| nearest_option_index(&self.options(), time)) {
+            self.menu_list.read(cx).scroll_to_item(index);
+        }
+    }
+
+    /// Highlights the selected time when it's offered, and scrolls the menu to
+    /// the time nearest to it.
+    pub(crate) fn sync_highlight_to_selection(&self, cx: &mut App) {
+        let options = self.options();
+        let selected_time = self.selected_time(cx);
+
+        self.highlighted_time.update(cx, |this, cx| {
+            *this = selected_time.filter(|time| options.contains(time));
+            cx.notify();
+        });
+
+        if let Some(index) = selected_time.and_then(|time| nearest_option_index(&options, time)) {
+            self.menu_list.read(cx).jump_to_item(index);
+        }
+    }
+
+    /// Moves the highlight `delta` times down the menu, or up for negative `delta`,
+    /// stopping at the first and last times.
+    ///
+    /// Without a highlight it starts from the time nearest to the selection,
+    /// else from the first or last time.
+    pub fn move_highlight(&self, cx: &mut App, delta: isize) {
+        let options = self.options();
+        let Some(last) = options.len().checked_sub(1) else {
+            return;
+        };
+
+        let current = self
+            .highlighted_time(cx)
+            .or_else(|| self.selected_time(cx))
+            .and_then(|time| nearest_option_index(&options, time));
+
+        let index = match current {
+            Some(index) if self.highlighted_time(cx).is_some() => {
+                index.saturating_add_signed(delta).min(last)
+            }
+            Some(index) => index,
+            None if delta < 0 => last,
+            None => 0,
+        };
+
+        self.set_highlighted_time(cx, Some(options[index]));
+    }
+
+    /// Opens a hidden menu, or moves the highlight when the menu is already open.
+    ///
+    /// The menu highlights the selected time as it opens, so the first press
+    /// only shows it.
+    pub(crate) fn show_menu_or_move_highlight(&self, cx: &mut App, delta: isize) {
+        if !self.menu.is_shown(cx, &self.menu_visible_transition) {
+            self.show_menu(cx);
+        } else {
+            self.move_highlight(cx, delta);
+        }
+    }
+
+    /// Picks the highlighted time, or the time typed into the input when
+    /// nothing is highlighted.
+    pub fn confirm(&self, cx: &mut App) -> Result<(), TimeSelectError> {
+        match self.highlighted_time(cx) {
+            Some(time) => self.select_time(cx, time),
+            None => self.commit_input(cx),
+        }
+    }
+
+    /// Registers a focus handle from a TimePicker component using this state.
+    /// Called automatically when a TimePicker component renders.
+    pub(crate) fn register_focus_handle(&self, cx: &mut App, focus_handle: &FocusHandle) {
+        self.menu.register_focus_handle(cx, focus_handle);
+    }
+
+    /// Checks if any TimePicker component using this state has focus.
+    pub fn any_picker_focused(&self, window: &Window, cx: &mut App) -> bool {
+        self.menu.any_focused(window, cx)
+    }
+
+    /// Hides the menu.
+    pub fn hide_menu(&self, cx: &mut App) {
+        self.highlighted_time.update(cx, |this, _cx| *this = None);
+        self.menu.hide(cx, &self.menu_visible_transition);
+    }
+
+    /// Hides the menu and keeps it closed while the picker stays focused,
+    /// until it's opened again with [`TimePickerState::show_menu`].
+    pub fn dismiss_menu(&self, cx: &mut App) {
+        self.hide_menu(cx);
+        self.menu.dismiss(cx);
+    }
+
+    /// Returns true if the menu was dismissed while the picker kept focus.
+    pub fn is_menu_dismissed(&self, cx: &App) -> bool {
+        self.menu.is_dismissed(cx)
+    }
+
+    /// Shows the menu.
+    pub fn show_menu(&self, cx: &mut App) {
+        self.menu.show(cx, &self.menu_visible_transition);
+    }
+}
+
+impl TypedSelection for TimePickerState {
+    type Value = ClockTime;
+    type Error = TimeSelectError;
+
+    fn input(&self) -> &Entity<InputState> {
+        &self.input
+    }
+
+    fn selection_text(&self, cx: &App) -> SharedString {
+        self.selection_text(cx)
+    }
+
+    fn parse(&self, text: &str) -> Result<ClockTime, TimeSelectError> {
+        ClockTime::parse(text).ok_or(TimeSelectError::Invalid)
+    }
+
+    fn select_parsed(&self, cx: &mut App, time: ClockTime) -> Result<(), TimeSelectError> {
+        self.select_time(cx, time)
+    }
+
+    fn clear_selection(&self, cx: &mut App) {
+        self.clear_selection(cx);
+    }
+}
+
+/// Errors that can occur when picking a time.
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeSelectError {
+    /// The typed text isn't a time.
+    #[error("The text isn't a valid time.")]
+    Invalid,
+    /// The time is before the earliest time that can be picked.
+    #[error("The time is before the earliest time that can be picked.")]
+    BeforeMin,
+    /// The time is after the latest time that can be picked.
+    #[error("The time is after the latest time that can be picked.")]
+    AfterMax,
+}
+
+/// Registers key bindings for time picker keyboard navigation.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", MoveUp, Some("TimePicker > TextInput")),
+        KeyBinding::new("down", MoveDown, Some("TimePicker > TextInput")),
+        KeyBinding::new("enter", Confirm, Some("TimePicker > TextInput")),
+        KeyBinding::new("escape", Dismiss, Some("TimePicker > TextInput")),
+    ]);
+}
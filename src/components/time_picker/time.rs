@@ -0,0 +1,229 @@
+use std::fmt;
+
+const MINUTES_PER_DAY: u16 = 24 * 60;
+
+/// How a [`ClockTime`] is written.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClockFormat {
+    /// Hours from 0 to 23, such as "14:30".
+    #[default]
+    TwentyFourHour,
+    /// Hours from 1 to 12 followed by AM or PM, such as "2:30 PM".
+    TwelveHour,
+}
+
+/// A time of day with minute precision, without a date or time zone.
+///
+/// Times are ordered from midnight to the end of the day.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ClockTime {
+    hour: u8,
+    minute: u8,
+}
+
+impl ClockTime {
+    /// Midnight, the start of the day.
+    pub const MIDNIGHT: Self = Self { hour: 0, minute: 0 };
+
+    /// Creates a time, returning `None` if the hour isn't from 0 to 23 or the
+    /// minute isn't from 0 to 59.
+    pub fn new(hour: u8, minute: u8) -> Option<Self> {
+        (hour < 24 && minute < 60).then_some(Self { hour, minute })
+    }
+
+    /// Creates the time the given number of minutes after midnight, returning
+    /// `None` if that's a day or more.
+    pub fn from_minutes_since_midnight(minutes: u16) -> Option<Self> {
+        (minutes < MINUTES_PER_DAY).then(|| Self {
+            hour: (minutes / 60) as u8,
+            minute: (minutes % 60) as u8,
+        })
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+
+    /// Returns how many minutes after midnight this time is.
+    pub fn minutes_since_midnight(self) -> u16 {
+        self.hour as u16 * 60 + self.minute as u16
+    }
+
+    /// Returns the time written in the given format.
+    pub fn format(self, format: ClockFormat) -> String {
+        match format {
+            ClockFormat::TwentyFourHour => self.to_string(),
+            ClockFormat::TwelveHour => {
+                let hour = match self.hour % 12 {
+                    0 => 12,
+                    hour => hour,
+                };
+                let meridiem = if self.hour < 12 { "AM" } else { "PM" };
+
+                format!("{hour}:{:02} {meridiem}", self.minute)
+            }
+        }
+    }
+
+    /// Parses a time written in either format, such as "14:30", "1430",
+    /// "2:30 pm", "2pm" or "14".
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_ascii_lowercase().replace('.', "");
+
+        let (text, is_pm) = if let Some(text) = text.strip_suffix("am") {
+            (text, Some(false))
+        } else if let Some(text) = text.strip_suffix("pm") {
+            (text, Some(true))
+        } else if let Some(text) = text.strip_suffix('a') {
+            (text, Some(false))
+        } else if let Some(text) = text.strip_suffix('p') {
+            (text, Some(true))
+        } else {
+            (text.as_str(), None)
+        };
+        let text = text.trim_end();
+
+        let (hour, minute) = match text.split_once(':') {
+            Some((hour, minute)) if minute.len() == 2 => (hour, minute),
+            Some(_) => return None,
+            None if text.len() <= 2 => (text, "0"),
+            None if text.len() <= 4 => text.split_at(text.len() - 2),
+            None => return None,
+        };
+
+        if hour.is_empty()
+            || hour.len() > 2
+            || ![hour, minute]
+                .iter()
+                .all(|part| part.bytes().all(|byte| byte.is_ascii_digit()))
+        {
+            return None;
+        }
+
+        let hour: u8 = hour.parse().ok()?;
+        let minute: u8 = minute.parse().ok()?;
+
+        let hour = match is_pm {
+            Some(_) if !(1..=12).contains(&hour) => return None,
+            Some(is_pm) => hour % 12 + if is_pm { 12 } else { 0 },
+            None => hour,
+        };
+
+        Self::new(hour, minute)
+    }
+}
+
+impl fmt::Display for ClockTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// Returns the times offered by a time picker: every `step_minutes` from
+/// midnight, limited to those between `min` and `max`.
+pub(crate) fn time_options(
+    step_minutes: u16,
+    min: Option<ClockTime>,
+    max: Option<ClockTime>,
+) -> Vec<ClockTime> {
+    (0..MINUTES_PER_DAY)
+        .step_by(step_minutes.clamp(1, MINUTES_PER_DAY) as usize)
+        .filter_map(ClockTime::from_minutes_since_midnight)
+        .filter(|time| min.is_none_or(|min| *time >= min) && max.is_none_or(|max| *time <= max))
+        .collect()
+}
+
+/// Returns the index of the option closest to `time`, preferring the later
+/// one when `time` is halfway between two options.
+pub(crate) fn nearest_option_index(options: &[ClockTime], time: ClockTime) -> Option<usize> {
+    let minutes = time.minutes_since_midnight() as i32;
+
+    options
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, option)| {
+            let distance = option.minutes_since_midnight() as i32 - minutes;
+            (distance.abs(), distance < 0)
+        })
+        .map(|(index, _)| index)
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+
+    fn time(hour: u8, minute: u8) -> ClockTime {
+        ClockTime::new(hour, minute).unwrap()
+    }
+
+    #[test]
+    fn test_new_rejects_out_of_range_values() {
+        assert!(ClockTime::new(23, 59).is_some());
+        assert!(ClockTime::new(24, 0).is_none());
+        assert!(ClockTime::new(12, 60).is_none());
+        assert_eq!(
+            ClockTime::from_minutes_since_midnight(90),
+            Some(time(1, 30))
+        );
+        assert_eq!(ClockTime::from_minutes_since_midnight(1440), None);
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(time(9, 5).format(ClockFormat::TwentyFourHour), "09:05");
+        assert_eq!(time(0, 0).format(ClockFormat::TwelveHour), "12:00 AM");
+        assert_eq!(time(12, 30).format(ClockFormat::TwelveHour), "12:30 PM");
+        assert_eq!(time(14, 30).format(ClockFormat::TwelveHour), "2:30 PM");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(ClockTime::parse("14:30"), Some(time(14, 30)));
+        assert_eq!(ClockTime::parse("9:05"), Some(time(9, 5)));
+        assert_eq!(ClockTime::parse("1430"), Some(time(14, 30)));
+        assert_eq!(ClockTime::parse("930"), Some(time(9, 30)));
+        assert_eq!(ClockTime::parse("14"), Some(time(14, 0)));
+        assert_eq!(ClockTime::parse("2:30 PM"), Some(time(14, 30)));
+        assert_eq!(ClockTime::parse("2pm"), Some(time(14, 0)));
+        assert_eq!(ClockTime::parse("12 a.m."), Some(time(0, 0)));
+        assert_eq!(ClockTime::parse("12:15p"), Some(time(12, 15)));
+
+        assert_eq!(ClockTime::parse(""), None);
+        assert_eq!(ClockTime::parse("24:00"), None);
+        assert_eq!(ClockTime::parse("13pm"), None);
+        assert_eq!(ClockTime::parse("0am"), None);
+        assert_eq!(ClockTime::parse("9:5"), None);
+        assert_eq!(ClockTime::parse("noon"), None);
+        assert_eq!(ClockTime::parse("12345"), None);
+    }
+
+    #[test]
+    fn test_time_options() {
+        let options = time_options(15, None, None);
+        assert_eq!(options.len(), 96);
+        assert_eq!(options[1], time(0, 15));
+
+        let options = time_options(30, Some(time(9, 0)), Some(time(17, 0)));
+        assert_eq!(options.first(), Some(&time(9, 0)));
+        assert_eq!(options.last(), Some(&time(17, 0)));
+        assert_eq!(options.len(), 17);
+
+        assert_eq!(time_options(0, None, None).len(), 1440);
+    }
+
+    #[test]
+    fn test_nearest_option_index() {
+        let options = time_options(30, None, None);
+
+        assert_eq!(nearest_option_index(&options, time(14, 10)), Some(28));
+        assert_eq!(nearest_option_index(&options, time(14, 15)), Some(29));
+        assert_eq!(nearest_option_index(&options, time(23, 59)), Some(47));
+        assert_eq!(nearest_option_index(&[], time(12, 0)), None);
+    }
+}
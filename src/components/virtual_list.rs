@@ -0,0 +1,304 @@
+use std::{ops::Range, rc::Rc};
+
+use gpui::{
+    AnyElement, App, Edges, ElementId, InteractiveElement, IntoElement, Length, ListAlignment,
+    ListOffset, ListSizingBehavior, ListState, ParentElement, RenderOnce, Styled, Window, div,
+    list, prelude::FluentBuilder, px, relative,
+};
+
+/// Scroll and measurement state for a [`VirtualList`].
+///
+/// Rows are measured lazily as they scroll into view. Keep the state alive
+/// across renders and report changes to the underlying items through it.
+#[derive(Clone)]
+pub struct VirtualListState {
+    list: ListState,
+    alignment: ListAlignment,
+}
+
+impl VirtualListState {
+    /// Creates state for a list of `item_count` rows aligned to the top.
+    pub fn new(item_count: usize) -> Self {
+        Self::with_alignment(item_count, ListAlignment::Top)
+    }
+
+    /// Creates state for a list of `item_count` rows anchored to the bottom.
+    ///
+    /// The list follows new items appended to the end while scrolled to the
+    /// bottom, and stays put once the user has scrolled up.
+    pub fn bottom_anchored(item_count: usize) -> Self {
+        Self::with_alignment(item_count, ListAlignment::Bottom)
+    }
+
+    fn with_alignment(item_count: usize, alignment: ListAlignment) -> Self {
+        Self {
+            list: ListState::new(item_count, alignment, px(1000.)),
+            alignment,
+        }
+    }
+
+    /// Returns the number of rows.
+    pub fn item_count(&self) -> usize {
+        self.list.item_count()
+    }
+
+    /// Returns the index of the first visible row.
+    pub fn first_visible_item(&self) -> usize {
+        self.list.logical_scroll_top().item_ix
+    }
+
+    /// Appends `count` rows to the end of the list.
+    pub fn append(&self, count: usize) {
+        let item_count = self.item_count();
+        self.list.splice(item_count..item_count, count);
+    }
+
+    /// Inserts `count` rows at the start of the list, such as older history,
+    /// without moving the rows currently in view.
+    pub fn prepend(&self, count: usize) {
+        // A top aligned list that hasn't been scrolled has no scroll anchor,
+        // so pin it to the current first row before it shifts down.
+        let scroll_top =
+            (self.alignment == ListAlignment::Top).then(|| self.list.logical_scroll_top());
+
+        self.list.splice(0..0, count);
+
+        if let Some(scroll_top) = scroll_top {
+            self.list.scroll_to(ListOffset {
+                item_ix: scroll_top.item_ix + count,
+                offset_in_item: scroll_top.offset_in_item,
+            });
+        }
+    }
+
+    /// Replaces the rows in `range` with `count` new rows, remeasuring only those.
+    pub fn splice(&self, range: Range<usize>, count: usize) {
+        self.list.splice(range, count);
+    }
+
+    /// Marks a row for remeasurement, such as after its content changed height.
+    /// Does nothing if there's no row at `index`.
+    pub fn remeasure_item(&self, index: usize) {
+        if index < self.item_count() {
+            self.list.splice(index..index + 1, 1);
+        }
+    }
+
+    /// Discards all measurements and resets the list to `item_count` rows.
+    pub fn reset(&self, item_count: usize) {
+        self.list.reset(item_count);
+    }
+
+    /// Scrolls the minimum distance needed to bring a row into view.
+    pub fn scroll_to_item(&self, index: usize) {
+        self.list.scroll_to_reveal_item(index);
+    }
+
+    /// Scrolls so a row is at the top of the list, or the last row if `index`
+    /// is past the end. Does nothing for an empty list.
+    pub fn jump_to_item(&self, index: usize) {
+        let Some(last) = self.item_count().checked_sub(1) else {
+            return;
+        };
+
+        self.list.scroll_to(ListOffset {
+            item_ix: index.min(last),
+            offset_in_item: px(0.),
+        });
+    }
+
+    /// Scrolls to the last row.
+    pub fn scroll_to_bottom(&self) {
+        // Unlike `reset`, this keeps the rows measured so far.
+        if let Some(last) = self.item_count().checked_sub(1) {
+            self.list.scroll_to_reveal_item(last);
+        }
+    }
+}
+
+/// A virtualized list that only renders the rows currently in view.
+#[derive(IntoElement)]
+pub struct VirtualList {
+    id: ElementId,
+    state: VirtualListState,
+    render_item: Rc<dyn Fn(usize, &mut Window, &mut App) -> AnyElement + 'static>,
+    margin: Edges<Option<Length>>,
+    width: Length,
+    height: Length,
+    max_height: Option<Length>,
+}
+
+impl VirtualList {
+    /// Creates a new virtual list that builds each visible row with `render_item`.
+    pub fn new<E: IntoElement>(
+        id: impl Into<ElementId>,
+        state: &VirtualListState,
+        render_item: impl Fn(usize, &mut Window, &mut App) -> E + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            render_item: Rc::new(move |index, window, cx| {
+                render_item(index, window, cx).into_any_element()
+            }),
+            margin: Edges::default(),
+            width: relative(1.).into(),
+            height: relative(1.).into(),
+            max_height: None,
+        }
+    }
+
+    /// Sets uniform margin for all sides.
+    pub fn m(mut self, margin: impl Into<Length>) -> Self {
+        let margin = margin.into();
+        self.margin = Edges::all(Some(margin));
+        self
+    }
+
+    /// Sets top margin.
+    pub fn mt(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.top = Some(margin.into());
+        self
+    }
+
+    /// Sets bottom margin.
+    pub fn mb(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.bottom = Some(margin.into());
+        self
+    }
+
+    /// Sets left margin.
+    pub fn ml(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.left = Some(margin.into());
+        self
+    }
+
+    /// Sets right margin.
+    pub fn mr(mut self, margin: impl Into<Length>) -> Self {
+        self.margin.right = Some(margin.into());
+        self
+    }
+
+    /// Sets the width. Defaults to the full width of the parent.
+    pub fn w(mut self, width: impl Into<Length>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// Sets the height. Defaults to the full height of the parent.
+    pub fn h(mut self, height: impl Into<Length>) -> Self {
+        self.height = height.into();
+        self
+    }
+
+    /// Sizes the list to fit its rows, such as inside a popover.
+    pub fn h_auto(mut self) -> Self {
+        self.height = Length::Auto;
+        self
+    }
+
+    /// Sets the maximum height, past which the list scrolls.
+    pub fn max_h(mut self, max_height: impl Into<Length>) -> Self {
+        self.max_height = Some(max_height.into());
+        self
+    }
+}
+
+impl RenderOnce for VirtualList {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let render_item = self.render_item;
+        let fit_rows = matches!(self.height, Length::Auto);
+        let max_height = self.max_height;
+
+        div()
+            .id(self.id)
+            .w(self.width)
+            .h(self.height)
+            .when_some(max_height, |this, v| this.max_h(v))
+            .when_some(self.margin.top, |this, v| this.mt(v))
+            .when_some(self.margin.bottom, |this, v| this.mb(v))
+            .when_some(self.margin.left, |this, v| this.ml(v))
+            .when_some(self.margin.right, |this, v| this.mr(v))
+            .child(
+                list(self.state.list, move |index, window, cx| {
+                    (render_item)(index, window, cx)
+                })
+                .map(|this| {
+                    if fit_rows {
+                        // Take the height of the rows rather than of the parent.
+                        this.with_sizing_behavior(ListSizingBehavior::Infer)
+                            .w_full()
+                            .when_some(max_height, |this, v| this.max_h(v))
+                    } else {
+                        this.size_full()
+                    }
+                }),
+            )
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_virtual_list_state_counts() {
+        let state = VirtualListState::bottom_anchored(10);
+        assert_eq!(state.item_count(), 10);
+
+        state.append(5);
+        assert_eq!(state.item_count(), 15);
+
+        state.prepend(5);
+        assert_eq!(state.item_count(), 20);
+
+        state.splice(0..10, 2);
+        assert_eq!(state.item_count(), 12);
+
+        state.reset(3);
+        assert_eq!(state.item_count(), 3);
+    }
+
+    #[test]
+    fn test_virtual_list_out_of_range_indices() {
+        let state = VirtualListState::new(5);
+        state.jump_to_item(10);
+        assert_eq!(state.first_visible_item(), 4);
+
+        state.remeasure_item(5);
+        assert_eq!(state.item_count(), 5);
+
+        let state = VirtualListState::new(0);
+        state.jump_to_item(0);
+        state.remeasure_item(0);
+        assert_eq!(state.item_count(), 0);
+        assert_eq!(state.first_visible_item(), 0);
+    }
+
+    #[test]
+    fn test_virtual_list_prepend_keeps_position() {
+        let state = VirtualListState::new(10);
+        state.jump_to_item(4);
+        assert_eq!(state.first_visible_item(), 4);
+
+        state.prepend(3);
+        assert_eq!(state.first_visible_item(), 7);
+
+        // An unscrolled top aligned list keeps its first row in view too.
+        let state = VirtualListState::new(10);
+        state.prepend(3);
+        assert_eq!(state.first_visible_item(), 3);
+
+        let state = VirtualListState::bottom_anchored(10);
+        state.jump_to_item(4);
+        state.prepend(3);
+        assert_eq!(state.first_visible_item(), 7);
+    }
+
+    #[test]
+    fn test_virtual_list_remeasure_keeps_count() {
+        let state = VirtualListState::new(10);
+        state.remeasure_item(4);
+        assert_eq!(state.item_count(), 10);
+    }
+}
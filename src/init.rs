@@ -1,15 +1,62 @@
-use gpui::{App, Window};
+use gpui::{App, AppContext, Entity, Render, Window, WindowHandle, WindowOptions};
 
-use crate::{Assets, theme::ThemeExt};
+use crate::{
+    Assets,
+    components::{
+        accordion, date_picker, radio_group, select, sidebar, slider, split_pane, tabs, time_picker,
+    },
+    theme::{Theme, ThemeExt},
+    views::{Root, command_palette},
+};
 
 /// Initializes global tesserae state. Call once at application startup.
 pub fn init(cx: &mut App) {
     Assets::init_fonts(cx).expect("Could not initialize fonts!");
 
+    // Registered first so the component bindings scoped to text inputs take
+    // precedence over the inputs' own bindings.
     gpui_primitives::init(cx);
+    command_palette::init(cx);
+    accordion::init(cx);
+    date_picker::init(cx);
+    radio_group::init(cx);
+    select::init(cx);
+    sidebar::init(cx);
+    slider::init(cx);
+    split_pane::init(cx);
+    tabs::init(cx);
+    time_picker::init(cx);
 }
 
 /// Initializes per-window tesserae state. Call for each new window.
+///
+/// The rem size is taken from the current theme, so call this again after
+/// changing the theme. Windows opened with [`open_tesserae_window`] do so
+/// automatically.
 pub fn init_for_window(window: &mut Window, cx: &mut App) {
     window.set_rem_size(cx.get_theme().layout.text.base_size);
 }
+
+/// Opens a new window with the built view wrapped in a [`Root`] and
+/// [`init_for_window`] applied, again whenever the theme changes.
+pub fn open_tesserae_window<V: Render>(
+    cx: &mut App,
+    options: WindowOptions,
+    build_view: impl FnOnce(&mut Window, &mut App) -> Entity<V>,
+) -> anyhow::Result<WindowHandle<Root>> {
+    cx.open_window(options, |window, cx| {
+        init_for_window(window, cx);
+
+        let view = build_view(window, cx);
+        cx.new(|cx| {
+            // Keep the rem size in step with the theme.
+            cx.observe_global_in::<Theme>(window, |_root, window, cx| {
+                init_for_window(window, cx);
+                window.refresh();
+            })
+            .detach();
+
+            Root::new(view, window, cx)
+        })
+    })
+}
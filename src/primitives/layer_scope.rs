@@ -0,0 +1,119 @@
+use gpui::{
+    AnyElement, App, Bounds, Element, ElementId, Global, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, Window,
+};
+
+use crate::theme::ThemeLayerKind;
+
+/// Layers provided by the [`LayerScope`]s currently being drawn, innermost last.
+#[derive(Default)]
+pub(crate) struct LayerStack(Vec<ThemeLayerKind>);
+
+impl LayerStack {
+    pub(crate) fn last(&self) -> Option<ThemeLayerKind> {
+        self.0.last().copied()
+    }
+}
+
+impl Global for LayerStack {}
+
+/// Provides a layer to every component rendered inside its child.
+///
+/// Components without an explicit `layer` pick up the innermost provided layer
+/// through [`ThemeLayerKind::current`], so surfaces like `Card` only need to
+/// wrap their content once instead of every nested control passing `.layer(...)`.
+///
+/// Deferred children are drawn after the scope has ended, so components that
+/// defer their content, such as menus, resolve the layer while rendering and
+/// wrap the deferred content in a scope of their own.
+pub struct LayerScope {
+    layer: ThemeLayerKind,
+    child: AnyElement,
+}
+
+impl LayerScope {
+    /// Creates a scope that provides `layer` to the given child.
+    pub fn new(layer: ThemeLayerKind, child: impl IntoElement) -> Self {
+        Self {
+            layer,
+            child: child.into_any_element(),
+        }
+    }
+
+    fn with_layer<R>(layer: ThemeLayerKind, cx: &mut App, f: impl FnOnce(&mut App) -> R) -> R {
+        cx.default_global::<LayerStack>().0.push(layer);
+        let result = f(cx);
+        cx.global_mut::<LayerStack>().0.pop();
+        result
+    }
+}
+
+impl IntoElement for LayerScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for LayerScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let layout_id =
+            Self::with_layer(self.layer, cx, |cx| self.child.request_layout(window, cx));
+
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        // Lists render their items while prepainting, so the layer has to be
+        // provided here as well as during layout.
+        Self::with_layer(self.layer, cx, |cx| {
+            self.child.prepaint(window, cx);
+        });
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        Self::with_layer(self.layer, cx, |cx| {
+            self.child.paint(window, cx);
+        });
+    }
+}
+
+/// Creates a new [`LayerScope`] element.
+pub fn layer_scope(layer: ThemeLayerKind, child: impl IntoElement) -> LayerScope {
+    LayerScope::new(layer, child)
+}
@@ -5,5 +5,8 @@ pub use gpui_primitives::selectable_text;
 mod focus_ring;
 pub use focus_ring::*;
 
+mod layer_scope;
+pub use layer_scope::*;
+
 mod min_w0_wrapper;
 pub use min_w0_wrapper::*;
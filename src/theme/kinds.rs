@@ -3,7 +3,7 @@
 use enum_assoc::Assoc;
 use gpui::App;
 
-use crate::theme::ThemeExt;
+use crate::{primitives::LayerStack, theme::ThemeExt};
 
 /// Text size variants that resolve to theme-defined values.
 ///
@@ -34,7 +34,7 @@ pub enum ThemeTextSizeKind {
 /// Component size variants that resolve to theme-defined pixel values.
 ///
 /// Each size has a corresponding corner radius for consistent styling.
-#[derive(Assoc)]
+#[derive(Assoc, Clone, Copy)]
 #[func(pub fn resolve(&self, cx: &App) -> gpui::Pixels)]
 #[func(pub fn corner_radii(&self) -> ThemeLayoutCornerRadiiKind)]
 pub enum ThemeLayoutSizeKind {
@@ -113,32 +113,70 @@ pub enum ThemeBackgroundKind {
     Quinary,
 }
 
+/// Accent color variants from the active theme variant.
+#[derive(Assoc)]
+#[func(pub fn resolve(&self, cx: &App) -> gpui::Rgba)]
+pub enum ThemeAccentKind {
+    /// Main accent for highlights and selection.
+    #[assoc(resolve = cx.get_theme().variants.active(cx).colors.accent.primary)]
+    Primary,
+    /// Positive accent for success and confirmation.
+    #[assoc(resolve = cx.get_theme().variants.active(cx).colors.accent.constructive)]
+    Constructive,
+    /// Negative accent for errors and destructive actions.
+    #[assoc(resolve = cx.get_theme().variants.active(cx).colors.accent.destructive)]
+    Destructive,
+}
+
 /// Background layers for stacking surfaces with visual hierarchy.
 ///
 /// Similar to `ThemeBackgroundKind` but supports `next()` to get the
 /// elevated layer color for nested elements.
-#[derive(Assoc)]
+#[derive(Assoc, Clone, Copy)]
 #[func(pub fn resolve(&self, cx: &App) -> gpui::Rgba)]
 #[func(pub fn next(&self) -> ThemeBackgroundKind)]
+#[func(pub fn nested(&self) -> ThemeLayerKind)]
 pub enum ThemeLayerKind {
     /// Base layer for main surfaces.
     #[assoc(resolve = cx.get_theme().variants.active(cx).colors.background.primary)]
     #[assoc(next = ThemeBackgroundKind::Secondary)]
+    #[assoc(nested = ThemeLayerKind::Secondary)]
     Primary,
     /// Second layer for grouped content.
     #[assoc(resolve = cx.get_theme().variants.active(cx).colors.background.secondary)]
     #[assoc(next = ThemeBackgroundKind::Tertiary)]
+    #[assoc(nested = ThemeLayerKind::Tertiary)]
     Secondary,
     /// Third layer for elevated elements.
     #[assoc(resolve = cx.get_theme().variants.active(cx).colors.background.tertiary)]
     #[assoc(next = ThemeBackgroundKind::Quaternary)]
+    #[assoc(nested = ThemeLayerKind::Quaternary)]
     Tertiary,
     /// Fourth layer for high emphasis.
     #[assoc(resolve = cx.get_theme().variants.active(cx).colors.background.quaternary)]
     #[assoc(next = ThemeBackgroundKind::Quinary)]
+    #[assoc(nested = ThemeLayerKind::Quaternary)]
     Quaternary,
 }
 
+impl ThemeLayerKind {
+    /// Returns the layer provided by the closest enclosing
+    /// [`LayerScope`](crate::primitives::LayerScope), or tertiary outside of one.
+    ///
+    /// Components without an explicit layer resolve it with this while rendering.
+    pub fn current(cx: &App) -> Self {
+        Self::current_or(ThemeLayerKind::Tertiary, cx)
+    }
+
+    /// Returns the layer provided by the closest enclosing
+    /// [`LayerScope`](crate::primitives::LayerScope), or `default` outside of one.
+    pub fn current_or(default: Self, cx: &App) -> Self {
+        cx.try_global::<LayerStack>()
+            .and_then(|stack| stack.last())
+            .unwrap_or(default)
+    }
+}
+
 impl Into<ThemeBackgroundKind> for ThemeLayerKind {
     fn into(self) -> ThemeBackgroundKind {
         match self {
@@ -241,6 +279,17 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    fn test_theme_accent_kind_variants(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+
+            let _ = ThemeAccentKind::Primary.resolve(cx);
+            let _ = ThemeAccentKind::Constructive.resolve(cx);
+            let _ = ThemeAccentKind::Destructive.resolve(cx);
+        });
+    }
+
     #[gpui::test]
     fn test_theme_layer_kind_variants(cx: &mut TestAppContext) {
         cx.update(|cx| {
@@ -275,6 +324,48 @@ mod tests {
         });
     }
 
+    #[gpui::test]
+    fn test_theme_layer_kind_nested(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            assert!(matches!(
+                ThemeLayerKind::Primary.nested(),
+                ThemeLayerKind::Secondary
+            ));
+            assert!(matches!(
+                ThemeLayerKind::Secondary.nested(),
+                ThemeLayerKind::Tertiary
+            ));
+            assert!(matches!(
+                ThemeLayerKind::Tertiary.nested(),
+                ThemeLayerKind::Quaternary
+            ));
+            assert!(matches!(
+                ThemeLayerKind::Quaternary.nested(),
+                ThemeLayerKind::Quaternary
+            ));
+        });
+    }
+
+    #[gpui::test]
+    fn test_theme_layer_kind_current_outside_scope(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            assert!(matches!(
+                ThemeLayerKind::current(cx),
+                ThemeLayerKind::Tertiary
+            ));
+        });
+    }
+
+    #[gpui::test]
+    fn test_theme_layer_kind_current_or_outside_scope(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            assert!(matches!(
+                ThemeLayerKind::current_or(ThemeLayerKind::Primary, cx),
+                ThemeLayerKind::Primary
+            ));
+        });
+    }
+
     #[gpui::test]
     fn test_theme_layer_kind_into_background_kind(cx: &mut TestAppContext) {
         cx.update(|_cx| {
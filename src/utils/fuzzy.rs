@@ -0,0 +1,254 @@
+use std::{iter, ops::Range};
+
+const MATCH_SCORE: i32 = 16;
+const CONSECUTIVE_BONUS: i32 = 24;
+const BOUNDARY_BONUS: i32 = 20;
+const GAP_PENALTY: i32 = 2;
+const LEADING_GAP_PENALTY: i32 = 1;
+
+/// The result of a successful fuzzy match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// Higher scores indicate a better match.
+    pub score: i32,
+    /// Byte offsets of every matched char in the candidate.
+    pub positions: Vec<usize>,
+}
+
+impl FuzzyMatch {
+    /// Merges the matched positions into contiguous byte ranges of `candidate`,
+    /// suitable for text highlights.
+    pub fn ranges(&self, candidate: &str) -> Vec<Range<usize>> {
+        let mut ranges: Vec<Range<usize>> = Vec::new();
+
+        for &position in &self.positions {
+            let Some(ch) = candidate[position..].chars().next() else {
+                continue;
+            };
+            let end = position + ch.len_utf8();
+
+            match ranges.last_mut() {
+                Some(last) if last.end == position => last.end = end,
+                _ => ranges.push(position..end),
+            }
+        }
+
+        ranges
+    }
+}
+
+/// Matches `query` against `candidate` as a case-insensitive subsequence.
+///
+/// Returns `None` if not every char of the query appears in order in the candidate.
+/// Matches that are consecutive or land on word boundaries, including the start
+/// of the candidate, score higher, while gaps between matched chars lower the
+/// score. Of all the ways the query can be matched, the highest scoring one is
+/// returned. An empty query matches everything with a score of zero.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<FuzzyMatch> {
+    let query = query
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .collect::<Vec<_>>();
+
+    if query.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+
+    let chars = candidate.char_indices().collect::<Vec<_>>();
+    let bonuses = chars
+        .iter()
+        .enumerate()
+        .map(|(idx, &(_, ch))| {
+            let prev_char = idx.checked_sub(1).map(|prev| chars[prev].1);
+            if is_word_boundary(prev_char, ch) {
+                BOUNDARY_BONUS
+            } else {
+                0
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // `scores[i][j]` is the best score of matching the query up to its `i`th char
+    // with that char landing on the `j`th char of the candidate, and `parents[i][j]`
+    // is where the previous query char landed in that match.
+    let mut scores: Vec<Vec<Option<i32>>> = Vec::with_capacity(query.len());
+    let mut parents: Vec<Vec<usize>> = Vec::with_capacity(query.len());
+
+    for (query_idx, &query_char) in query.iter().enumerate() {
+        let prev_scores = query_idx.checked_sub(1).map(|prev| &scores[prev]);
+        let mut row = vec![None; chars.len()];
+        let mut row_parents = vec![0; chars.len()];
+        // The best previous match that leaves a gap before the current char, with
+        // the gap penalty up to that match added back so it can be compared.
+        let mut best_gapped: Option<(i32, usize)> = None;
+
+        for (idx, &(_, ch)) in chars.iter().enumerate() {
+            let gapped_score = prev_scores
+                .zip(idx.checked_sub(2))
+                .and_then(|(prev_scores, gapped_idx)| Some((prev_scores[gapped_idx]?, gapped_idx)));
+            if let Some((score, gapped_idx)) = gapped_score {
+                let score = score + gapped_idx as i32 * GAP_PENALTY;
+                if best_gapped.is_none_or(|(best, _)| score > best) {
+                    best_gapped = Some((score, gapped_idx));
+                }
+            }
+
+            if !ch.to_lowercase().eq(iter::once(query_char)) {
+                continue;
+            }
+
+            let best = match prev_scores {
+                None => Some((-(idx as i32) * LEADING_GAP_PENALTY, idx)),
+                Some(prev_scores) => {
+                    let consecutive = idx.checked_sub(1).and_then(|prev| {
+                        prev_scores[prev].map(|score| (score + CONSECUTIVE_BONUS, prev))
+                    });
+                    let gapped = best_gapped
+                        .map(|(score, prev)| (score - (idx as i32 - 1) * GAP_PENALTY, prev));
+
+                    match (consecutive, gapped) {
+                        (Some(consecutive), Some(gapped)) if gapped.0 > consecutive.0 => {
+                            Some(gapped)
+                        }
+                        (consecutive, gapped) => consecutive.or(gapped),
+                    }
+                }
+            };
+
+            if let Some((score, parent)) = best {
+                row[idx] = Some(score + MATCH_SCORE + bonuses[idx]);
+                row_parents[idx] = parent;
+            }
+        }
+
+        scores.push(row);
+        parents.push(row_parents);
+    }
+
+    // Prefer the earliest of equally scored matches.
+    let (mut idx, score) = scores
+        .last()?
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, score)| score.map(|score| (idx, score)))
+        .max_by(|a, b| a.1.cmp(&b.1).then(b.0.cmp(&a.0)))?;
+
+    let mut positions = vec![0; query.len()];
+    for query_idx in (0..query.len()).rev() {
+        positions[query_idx] = chars[idx].0;
+        idx = parents[query_idx][idx];
+    }
+
+    Some(FuzzyMatch { score, positions })
+}
+
+fn is_word_boundary(prev: Option<char>, current: char) -> bool {
+    match prev {
+        None => true,
+        Some(prev) => {
+            !prev.is_alphanumeric()
+                || (prev.is_lowercase() && current.is_uppercase())
+                || (!prev.is_numeric() && current.is_numeric())
+        }
+    }
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty_query_matches_everything() {
+        let result = fuzzy_match("", "Open File").unwrap();
+        assert_eq!(result.score, 0);
+        assert!(result.positions.is_empty());
+    }
+
+    #[test]
+    fn test_subsequence_matches() {
+        let result = fuzzy_match("opf", "Open File").unwrap();
+        assert_eq!(result.positions, vec![0, 1, 5]);
+    }
+
+    #[test]
+    fn test_non_subsequence_does_not_match() {
+        assert!(fuzzy_match("xyz", "Open File").is_none());
+        assert!(fuzzy_match("fo", "Open File").is_none());
+    }
+
+    #[test]
+    fn test_match_is_case_insensitive() {
+        assert!(fuzzy_match("OPEN", "open file").is_some());
+        assert!(fuzzy_match("open", "OPEN FILE").is_some());
+    }
+
+    #[test]
+    fn test_whitespace_in_query_is_ignored() {
+        let result = fuzzy_match("open file", "OpenFile").unwrap();
+        assert_eq!(result.positions.len(), 8);
+    }
+
+    #[test]
+    fn test_consecutive_match_scores_higher() {
+        let consecutive = fuzzy_match("file", "File").unwrap();
+        let scattered = fuzzy_match("file", "Find in lines").unwrap();
+        assert!(consecutive.score > scattered.score);
+    }
+
+    #[test]
+    fn test_word_boundary_match_scores_higher() {
+        let boundary = fuzzy_match("cp", "Command Palette").unwrap();
+        let inner = fuzzy_match("cp", "Escape").unwrap();
+        assert!(boundary.score > inner.score);
+    }
+
+    #[test]
+    fn test_word_match_ranks_above_scattered_match() {
+        let word = fuzzy_match("line", "Go to Line").unwrap();
+        let inner = fuzzy_match("line", "Toggle Inline Hints").unwrap();
+        assert!(word.score > inner.score);
+    }
+
+    #[test]
+    fn test_start_is_a_single_word_boundary() {
+        let start = fuzzy_match("f", "File").unwrap();
+        let boundary = fuzzy_match("f", "Open File").unwrap();
+        assert_eq!(start.score - boundary.score, 5 * LEADING_GAP_PENALTY);
+    }
+
+    #[test]
+    fn test_best_alignment_is_highlighted() {
+        let result = fuzzy_match("line", "Toggle Line").unwrap();
+        assert_eq!(result.positions, vec![7, 8, 9, 10]);
+
+        let result = fuzzy_match("tl", "Toggle Line").unwrap();
+        assert_eq!(result.positions, vec![0, 7]);
+
+        let result = fuzzy_match("line", "Toggle Inline Hints").unwrap();
+        assert_eq!(result.positions, vec![9, 10, 11, 12]);
+    }
+
+    #[test]
+    fn test_camel_case_is_word_boundary() {
+        assert!(is_word_boundary(Some('n'), 'F'));
+        assert!(!is_word_boundary(Some('n'), 'f'));
+        assert!(is_word_boundary(Some(' '), 'f'));
+        assert!(is_word_boundary(None, 'f'));
+    }
+
+    #[test]
+    fn test_ranges_merge_consecutive_positions() {
+        let result = fuzzy_match("opfi", "Open File").unwrap();
+        assert_eq!(result.ranges("Open File"), vec![0..2, 5..7]);
+    }
+
+    #[test]
+    fn test_ranges_handle_multibyte_chars() {
+        let result = fuzzy_match("éa", "éab").unwrap();
+        assert_eq!(result.ranges("éab"), vec![0..3]);
+    }
+}
@@ -17,3 +17,15 @@ pub use pixels::*;
 
 mod squircle;
 pub use squircle::*;
+
+mod fuzzy;
+pub use fuzzy::*;
+
+mod navigation;
+pub use navigation::*;
+
+mod popover;
+pub use popover::*;
+
+mod typed_selection;
+pub use typed_selection::*;
@@ -0,0 +1,158 @@
+use std::f32::consts::FRAC_PI_2;
+
+/// A keyboard movement between the items of a roving-focus group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum RovingNavigation {
+    Prev,
+    Next,
+    First,
+    Last,
+}
+
+/// Returns the item keyboard navigation should move to, skipping disabled items.
+pub(crate) fn navigation_target(
+    navigation: RovingNavigation,
+    focused: Option<usize>,
+    disabled: &[bool],
+) -> Option<usize> {
+    match navigation {
+        RovingNavigation::Prev => next_enabled_index(focused?, disabled, false),
+        RovingNavigation::Next => next_enabled_index(focused?, disabled, true),
+        RovingNavigation::First => disabled.iter().position(|disabled| !disabled),
+        RovingNavigation::Last => disabled.iter().rposition(|disabled| !disabled),
+    }
+}
+
+/// Returns the next enabled item after `current` in the given direction,
+/// wrapping around and skipping disabled items.
+pub(crate) fn next_enabled_index(
+    current: usize,
+    disabled: &[bool],
+    forward: bool,
+) -> Option<usize> {
+    let len = disabled.len();
+    let mut idx = current;
+
+    for _ in 0..len {
+        idx = if forward {
+            wrapping_next_index(Some(idx), len)
+        } else {
+            wrapping_prev_index(Some(idx), len)
+        };
+
+        if !disabled[idx] {
+            return Some(idx);
+        }
+    }
+
+    None
+}
+
+/// Returns the index before `current`, wrapping around to the last index.
+/// Starts from the last index when nothing is highlighted yet.
+///
+/// `len` must be non-zero.
+pub(crate) fn wrapping_prev_index(current: Option<usize>, len: usize) -> usize {
+    match current {
+        Some(idx) if idx > 0 && idx < len => idx - 1,
+        _ => len - 1,
+    }
+}
+
+/// Returns the index after `current`, wrapping around to the first index.
+/// Starts from the first index when nothing is highlighted yet.
+///
+/// `len` must be non-zero.
+pub(crate) fn wrapping_next_index(current: Option<usize>, len: usize) -> usize {
+    match current {
+        Some(idx) if idx + 1 < len => idx + 1,
+        _ => 0,
+    }
+}
+
+/// Returns the chevron rotation for an open transition delta, pointing
+/// right while collapsed and down while expanded.
+pub(crate) fn chevron_rotation(open_delta: f32) -> f32 {
+    (open_delta - 1.) * FRAC_PI_2
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_target() {
+        let disabled = [true, false, false, true];
+
+        assert_eq!(
+            navigation_target(RovingNavigation::Next, Some(1), &disabled),
+            Some(2)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::Next, Some(2), &disabled),
+            Some(1)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::Prev, Some(1), &disabled),
+            Some(2)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::First, None, &disabled),
+            Some(1)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::Last, None, &disabled),
+            Some(2)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::Next, None, &disabled),
+            None
+        );
+
+        let disabled = [true, false, true, false, false];
+        assert_eq!(
+            navigation_target(RovingNavigation::Next, Some(1), &disabled),
+            Some(3)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::Prev, Some(1), &disabled),
+            Some(4)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::First, Some(4), &disabled),
+            Some(1)
+        );
+        assert_eq!(
+            navigation_target(RovingNavigation::Last, Some(1), &disabled),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn test_next_enabled_index() {
+        let disabled = [false, true, false];
+        assert_eq!(next_enabled_index(0, &disabled, true), Some(2));
+        assert_eq!(next_enabled_index(2, &disabled, true), Some(0));
+        assert_eq!(next_enabled_index(0, &disabled, false), Some(2));
+        assert_eq!(next_enabled_index(2, &disabled, false), Some(0));
+        assert_eq!(next_enabled_index(0, &[false], true), Some(0));
+        assert_eq!(next_enabled_index(0, &[true, true], true), None);
+    }
+
+    #[test]
+    fn test_wrapping_index_helpers() {
+        assert_eq!(wrapping_next_index(None, 3), 0);
+        assert_eq!(wrapping_next_index(Some(0), 3), 1);
+        assert_eq!(wrapping_next_index(Some(2), 3), 0);
+
+        assert_eq!(wrapping_prev_index(None, 3), 2);
+        assert_eq!(wrapping_prev_index(Some(2), 3), 1);
+        assert_eq!(wrapping_prev_index(Some(0), 3), 2);
+    }
+
+    #[test]
+    fn test_chevron_rotation() {
+        assert_eq!(chevron_rotation(0.), -FRAC_PI_2);
+        assert_eq!(chevron_rotation(1.), 0.);
+    }
+}
@@ -0,0 +1,167 @@
+use std::time::Duration;
+
+use gpui::{
+    App, AppContext, ElementId, Entity, FocusHandle, WeakFocusHandle, Window, ease_out_quint,
+};
+use gpui_transitions::{BoolLerp, Transition, TransitionState};
+
+use crate::ElementIdExt;
+
+/// The dismissal and focus state of a popover opened from a trigger, such as a
+/// select menu or a picker's calendar, shared by every trigger rendered from
+/// the same state.
+///
+/// The visibility transition itself stays on the owning state, so it's passed
+/// to the methods that show or hide the popover.
+pub(crate) struct PopoverState {
+    /// Whether the popover was closed with Escape while a trigger kept focus.
+    pub(crate) dismissed: Entity<bool>,
+    /// Weak focus handles from all triggers using this state.
+    /// Stored as weak references so stale handles can be cleaned up.
+    pub(crate) focus_handles: Entity<Vec<WeakFocusHandle>>,
+}
+
+impl PopoverState {
+    /// Creates state from pre-existing focus handles.
+    pub(crate) fn new(cx: &mut App, focus_handles: Entity<Vec<WeakFocusHandle>>) -> Self {
+        Self {
+            dismissed: cx.new(|_cx| false),
+            focus_handles,
+        }
+    }
+
+    /// Creates state using window-keyed storage under `id`, persisting across renders.
+    pub(crate) fn from_window(id: &ElementId, window: &mut Window, cx: &mut App) -> Self {
+        Self {
+            dismissed: window.use_keyed_state(
+                id.with_suffix("state:menu_dismissed"),
+                cx,
+                |_window, _cx| false,
+            ),
+            focus_handles: window.use_keyed_state(
+                id.with_suffix("state:focus_handles"),
+                cx,
+                |_window, _cx| vec![],
+            ),
+        }
+    }
+
+    /// Creates state from an App context.
+    pub(crate) fn from_cx(cx: &mut App) -> Self {
+        Self {
+            dismissed: cx.new(|_cx| false),
+            focus_handles: cx.new(|_cx| vec![]),
+        }
+    }
+
+    /// Creates the animated visibility transition of a popover.
+    pub(crate) fn visible_transition(
+        state: Entity<TransitionState<BoolLerp<f32>>>,
+    ) -> Transition<BoolLerp<f32>> {
+        Transition::new(state, Duration::from_millis(275)).with_easing(ease_out_quint())
+    }
+
+    /// Creates the visibility transition using window-keyed storage under `id`.
+    pub(crate) fn visible_transition_from_window(
+        id: &ElementId,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Transition<BoolLerp<f32>> {
+        Self::visible_transition(window.use_keyed_state(
+            id.with_suffix("state:menu_visible"),
+            cx,
+            |_window, _cx| TransitionState::new(BoolLerp::falsey()),
+        ))
+    }
+
+    /// Creates the visibility transition from an App context.
+    pub(crate) fn visible_transition_from_cx(cx: &mut App) -> Transition<BoolLerp<f32>> {
+        Self::visible_transition(cx.new(|_cx| TransitionState::new(BoolLerp::falsey())))
+    }
+
+    /// Removes focus handles whose trigger has been removed.
+    pub(crate) fn cleanup_stale_focus_handles(&self, cx: &mut App) {
+        self.focus_handles.update(cx, |handles, _cx| {
+            handles.retain(|handle| handle.upgrade().is_some());
+        });
+    }
+
+    /// Registers the focus handle of a trigger using this state,
+    /// dropping handles whose trigger has been removed.
+    pub(crate) fn register_focus_handle(&self, cx: &mut App, focus_handle: &FocusHandle) {
+        self.focus_handles.update(cx, |handles, _cx| {
+            handles.retain(|handle| handle.upgrade().is_some());
+
+            let weak = focus_handle.downgrade();
+            if !handles.contains(&weak) {
+                handles.push(weak);
+            }
+        });
+    }
+
+    /// Checks if any trigger using this state has focus.
+    pub(crate) fn any_focused(&self, window: &Window, cx: &mut App) -> bool {
+        self.focus_handles
+            .read(cx)
+            .iter()
+            .filter_map(|handle| handle.upgrade())
+            .any(|handle| handle.contains_focused(window, cx))
+    }
+
+    /// Returns true if the popover is open or opening, and wasn't dismissed.
+    pub(crate) fn is_shown(&self, cx: &App, visible: &Transition<BoolLerp<f32>>) -> bool {
+        !self.is_dismissed(cx) && visible.read_goal(cx) == &true.into()
+    }
+
+    /// Shows the popover, even if it was dismissed.
+    pub(crate) fn show(&self, cx: &mut App, visible: &Transition<BoolLerp<f32>>) {
+        self.reset_dismissed(cx);
+
+        visible.update(cx, |this, cx| {
+            if this.value() == 1. {
+                return;
+            }
+
+            *this = true.into();
+            cx.notify();
+        });
+    }
+
+    /// Hides the popover.
+    pub(crate) fn hide(&self, cx: &mut App, visible: &Transition<BoolLerp<f32>>) {
+        visible.update(cx, |this, cx| {
+            if this.value() == 0. {
+                return;
+            }
+
+            *this = false.into();
+            cx.notify();
+        });
+    }
+
+    /// Marks the popover as dismissed, keeping it closed while a trigger stays
+    /// focused until it's shown again or [`Self::reset_dismissed`] is called.
+    pub(crate) fn dismiss(&self, cx: &mut App) {
+        self.dismissed.update(cx, |this, cx| {
+            if !*this {
+                *this = true;
+                cx.notify();
+            }
+        });
+    }
+
+    /// Returns true if the popover was dismissed while a trigger kept focus.
+    pub(crate) fn is_dismissed(&self, cx: &App) -> bool {
+        *self.dismissed.read(cx)
+    }
+
+    /// Lets a dismissed popover open again, once its triggers lost focus.
+    pub(crate) fn reset_dismissed(&self, cx: &mut App) {
+        self.dismissed.update(cx, |this, cx| {
+            if *this {
+                *this = false;
+                cx.notify();
+            }
+        });
+    }
+}
@@ -0,0 +1,80 @@
+use gpui::{App, Entity, SharedString};
+
+use crate::primitives::input::InputState;
+
+/// A picker whose selection can also be typed into its text input, such as
+/// the date and time pickers.
+pub(crate) trait TypedSelection {
+    /// What the typed text parses to.
+    type Value;
+    /// Why the typed text can't be picked.
+    type Error;
+
+    /// Returns the text field the selection can be typed into.
+    fn input(&self) -> &Entity<InputState>;
+
+    /// Returns the text the input shows for the current selection.
+    fn selection_text(&self, cx: &App) -> SharedString;
+
+    /// Parses text typed into the input, which is never blank.
+    fn parse(&self, text: &str) -> Result<Self::Value, Self::Error>;
+
+    /// Picks a parsed value, returning an error if it can't be picked.
+    fn select_parsed(&self, cx: &mut App, value: Self::Value) -> Result<(), Self::Error>;
+
+    /// Clears the selection.
+    fn clear_selection(&self, cx: &mut App);
+
+    /// Replaces the text of the input with the current selection.
+    fn sync_input_to_selection(&self, cx: &mut App) {
+        let text = self.selection_text(cx);
+
+        self.input().update(cx, |this, cx| {
+            if this.value() == text {
+                return;
+            }
+
+            this.clear();
+            if !text.is_empty() {
+                let len = text.len();
+                this.value = Some(text);
+                this.move_to(len, cx);
+            }
+            cx.notify();
+        });
+    }
+
+    /// Parses the text typed into the input, returning `None` when it's empty.
+    fn parse_input(&self, cx: &App) -> Option<Result<Self::Value, Self::Error>> {
+        let text = self.input().read(cx).value();
+        if text.trim().is_empty() {
+            return None;
+        }
+
+        Some(self.parse(&text))
+    }
+
+    /// Picks what was typed into the input, or clears the selection if it's empty.
+    ///
+    /// If the text can't be picked, the input goes back to showing the
+    /// selection and the error is returned.
+    fn commit_input(&self, cx: &mut App) -> Result<(), Self::Error> {
+        if self.input().read(cx).value() == self.selection_text(cx) {
+            return Ok(());
+        }
+
+        let result = match self.parse_input(cx) {
+            None => {
+                self.clear_selection(cx);
+                Ok(())
+            }
+            Some(value) => value.and_then(|value| self.select_parsed(cx, value)),
+        };
+
+        if result.is_err() {
+            self.sync_input_to_selection(cx);
+        }
+
+        result
+    }
+}
@@ -0,0 +1,778 @@
+#![allow(missing_docs, reason = "actions! macro generates undocumented structs")]
+
+use std::ops::Range;
+
+use gpui::{
+    Action, App, AppContext, ClickEvent, Context, ElementId, Entity, FocusHandle, Focusable,
+    FontWeight, Global, HighlightStyle, InteractiveElement, IntoElement, KeyBinding, ParentElement,
+    Render, ScrollHandle, SharedString, StatefulInteractiveElement, Styled, StyledText,
+    Subscription, Window, actions, div, prelude::FluentBuilder, px, relative,
+};
+use gpui_squircle::{SquircleStyled, squircle};
+
+use crate::{
+    ElementIdExt, PositionalParentElement,
+    components::{Icon, Input, Toggle, ToggleVariant},
+    extensions::mouse_handleable::MouseHandleable,
+    primitives::input::InputState,
+    theme::{ThemeExt, ThemeLayerKind},
+    utils::{PixelsExt, fuzzy_match, wrapping_next_index, wrapping_prev_index},
+    views::{Root, RootWindowExt},
+};
+
+actions!(command_palette, [SelectPrev, SelectNext, Confirm, Dismiss]);
+
+/// Registers key bindings for command palette navigation.
+///
+/// Must be called after `gpui_primitives::init` so the bindings scoped to the
+/// query input take precedence over the input's own up, down and enter bindings.
+pub fn init(cx: &mut App) {
+    cx.bind_keys([
+        KeyBinding::new("up", SelectPrev, Some("CommandPalette > TextInput")),
+        KeyBinding::new("down", SelectNext, Some("CommandPalette > TextInput")),
+        KeyBinding::new("enter", Confirm, Some("CommandPalette > TextInput")),
+        KeyBinding::new("escape", Dismiss, Some("CommandPalette")),
+    ]);
+}
+
+/// A command that can be searched for and run from the [`CommandPalette`].
+pub struct Command {
+    name: SharedString,
+    description: Option<SharedString>,
+    keybinding: Option<SharedString>,
+    icon: Option<SharedString>,
+    action: Box<dyn Action>,
+}
+
+impl Command {
+    /// Creates a new command that dispatches the given action when run.
+    pub fn new(name: impl Into<SharedString>, action: impl Action) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            keybinding: None,
+            icon: None,
+            action: Box::new(action),
+        }
+    }
+
+    /// Sets a short description displayed below the name.
+    pub fn description(mut self, description: impl Into<SharedString>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Sets the keybinding label, overriding the one looked up from the keymap.
+    pub fn keybinding(mut self, keybinding: impl Into<SharedString>) -> Self {
+        self.keybinding = Some(keybinding.into());
+        self
+    }
+
+    /// Sets an icon to display next to the name.
+    pub fn icon(mut self, icon: impl Into<SharedString>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// Returns the name the command is searched by.
+    pub fn name(&self) -> &SharedString {
+        &self.name
+    }
+
+    /// Returns the action dispatched when the command is run.
+    pub fn action(&self) -> &dyn Action {
+        self.action.as_ref()
+    }
+}
+
+impl Clone for Command {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            keybinding: self.keybinding.clone(),
+            icon: self.icon.clone(),
+            action: self.action.boxed_clone(),
+        }
+    }
+}
+
+/// Global list of commands shown by [`CommandPalette::open`].
+#[derive(Default)]
+pub struct CommandRegistry(Vec<Command>);
+
+impl Global for CommandRegistry {}
+
+impl CommandRegistry {
+    /// Registers commands to be listed in the command palette.
+    pub fn register(cx: &mut App, commands: impl IntoIterator<Item = Command>) {
+        cx.default_global::<Self>().0.extend(commands);
+    }
+
+    /// Returns all registered commands in registration order.
+    pub fn commands(cx: &App) -> &[Command] {
+        cx.try_global::<Self>()
+            .map(|registry| registry.0.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+/// A command whose name matched the current query.
+struct CommandMatch {
+    /// Index into the palette's commands.
+    index: usize,
+    /// Byte ranges of the name to highlight.
+    ranges: Vec<Range<usize>>,
+}
+
+/// A searchable list of commands, opened as a dialog in the window's [`Root`].
+///
+/// Commands are filtered by fuzzy matching their names against the query, and
+/// running one closes the palette and dispatches its action to whatever was
+/// focused before the palette opened.
+pub struct CommandPalette {
+    id: ElementId,
+    commands: Vec<Command>,
+    query: Entity<InputState>,
+    last_query: SharedString,
+    matches: Vec<CommandMatch>,
+    highlighted: Option<usize>,
+    hovered: Option<usize>,
+    scroll_handle: ScrollHandle,
+    _query_subscription: Subscription,
+}
+
+impl CommandPalette {
+    /// Creates a palette listing the given commands.
+    ///
+    /// Keybinding labels are looked up from the currently focused element, so this
+    /// should be called before focus moves into the palette.
+    pub fn new(
+        commands: impl IntoIterator<Item = Command>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let commands = commands
+            .into_iter()
+            .map(|mut command| {
+                if command.keybinding.is_none() {
+                    command.keybinding = keybinding_label(command.action(), window);
+                }
+                command
+            })
+            .collect::<Vec<_>>();
+
+        let query = cx.new(|cx| InputState::new(cx));
+        let query_subscription = cx.observe(&query, |this, _query, cx| this.update_matches(cx));
+
+        Self {
+            id: "command_palette".into(),
+            matches: match_commands("", &commands),
+            highlighted: (!commands.is_empty()).then_some(0),
+            commands,
+            query,
+            last_query: SharedString::default(),
+            hovered: None,
+            scroll_handle: ScrollHandle::new(),
+            _query_subscription: query_subscription,
+        }
+    }
+
+    /// Opens a palette listing every command in the [`CommandRegistry`].
+    pub fn open(window: &mut Window, cx: &mut App) {
+        let commands = CommandRegistry::commands(cx).to_vec();
+        Self::open_with(commands, window, cx);
+    }
+
+    /// Opens a palette listing the given commands.
+    pub fn open_with(
+        commands: impl IntoIterator<Item = Command>,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let palette = cx.new(|cx| Self::new(commands, window, cx));
+        let focus_handle = palette.focus_handle(cx);
+
+        if window.open_dialog(cx, palette) {
+            focus_handle.focus(window, cx);
+        }
+    }
+
+    /// Opens the palette with every registered command, or closes it if it's already open.
+    pub fn toggle(window: &mut Window, cx: &mut App) {
+        let is_open =
+            Root::for_window(window).is_some_and(|root| root.read(cx).is_dialog_of_view::<Self>());
+
+        if is_open {
+            Self::close(window, cx);
+        } else {
+            Self::open(window, cx);
+        }
+    }
+
+    /// Closes the palette if it's open, restoring focus to the previously focused element.
+    pub fn close(window: &mut Window, cx: &mut App) {
+        Root::update_for_window(window, cx, |root, window, cx| {
+            if root.is_dialog_of_view::<Self>() {
+                root.close_dialog(window, cx);
+            }
+        });
+    }
+
+    fn update_matches(&mut self, cx: &mut Context<Self>) {
+        let query = self.query.read(cx).value();
+        if query == self.last_query {
+            return;
+        }
+
+        self.matches = match_commands(&query, &self.commands);
+        self.last_query = query;
+        self.hovered = None;
+
+        if self.matches.is_empty() {
+            self.highlighted = None;
+        } else {
+            self.set_highlight(0, cx);
+        }
+
+        cx.notify();
+    }
+
+    fn set_highlight(&mut self, index: usize, cx: &mut Context<Self>) {
+        self.highlighted = Some(index);
+        self.scroll_handle.scroll_to_item(index);
+        cx.notify();
+    }
+
+    fn select_prev(&mut self, _: &SelectPrev, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        // Keyboard navigation takes over from the mouse.
+        self.hovered = None;
+        let index = wrapping_prev_index(self.highlighted, self.matches.len());
+        self.set_highlight(index, cx);
+    }
+
+    fn select_next(&mut self, _: &SelectNext, _window: &mut Window, cx: &mut Context<Self>) {
+        if self.matches.is_empty() {
+            return;
+        }
+
+        // Keyboard navigation takes over from the mouse.
+        self.hovered = None;
+        let index = wrapping_next_index(self.highlighted, self.matches.len());
+        self.set_highlight(index, cx);
+    }
+
+    fn confirm(&mut self, _: &Confirm, window: &mut Window, cx: &mut Context<Self>) {
+        if let Some(index) = self.highlighted {
+            self.run(index, window, cx);
+        }
+    }
+
+    fn dismiss(&mut self, _: &Dismiss, window: &mut Window, cx: &mut Context<Self>) {
+        Self::close(window, cx);
+    }
+
+    fn run(&mut self, match_index: usize, window: &mut Window, cx: &mut Context<Self>) {
+        let Some(command_match) = self.matches.get(match_index) else {
+            return;
+        };
+        let action = self.commands[command_match.index].action.boxed_clone();
+
+        // Close first so the action is dispatched to the element focused before the palette.
+        Self::close(window, cx);
+        window.dispatch_action(action, cx);
+    }
+}
+
+impl Focusable for CommandPalette {
+    fn focus_handle(&self, cx: &App) -> FocusHandle {
+        self.query.read(cx).focus_handle.clone()
+    }
+}
+
+impl Render for CommandPalette {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        // The palette floats above every surface of the window.
+        let layer = ThemeLayerKind::Tertiary;
+        let background_color = layer.resolve(cx);
+        let border_color = layer.next().resolve(cx);
+        let corner_radius = cx.get_theme().layout.corner_radii.lg;
+        let line_height = cx.get_theme().layout.text.default_font.line_height;
+        let text_size = cx.get_theme().layout.text.default_font.sizes.body.clone();
+        let caption_text_size = cx
+            .get_theme()
+            .layout
+            .text
+            .default_font
+            .sizes
+            .caption
+            .clone();
+        let padding = cx.get_theme().layout.padding.md;
+        let horizontal_padding = cx.get_theme().layout.padding.lg - padding;
+        let vertical_padding =
+            cx.get_theme()
+                .layout
+                .size
+                .lg
+                .padding_needed_for_height(window, text_size, line_height)
+                - padding;
+        let accent_color = cx.get_theme().variants.active(cx).colors.accent.primary;
+        let secondary_text_color = cx.get_theme().variants.active(cx).colors.text.secondary;
+        let text_color = ToggleVariant::Tertiary
+            .as_granular_toggle(cx)
+            .falsey
+            .text_color;
+
+        let match_highlight = HighlightStyle {
+            color: Some(accent_color.into()),
+            font_weight: Some(FontWeight::SEMIBOLD),
+            ..Default::default()
+        };
+
+        div()
+            .id(self.id.clone())
+            .key_context("CommandPalette")
+            .on_action(cx.listener(Self::select_prev))
+            .on_action(cx.listener(Self::select_next))
+            .on_action(cx.listener(Self::confirm))
+            .on_action(cx.listener(Self::dismiss))
+            .w(px(560.))
+            .max_w(relative(0.9))
+            .flex()
+            .flex_col()
+            .gap(padding)
+            .p(padding)
+            .child(
+                squircle()
+                    .absolute_expand()
+                    .rounded(corner_radius)
+                    .bg(background_color)
+                    .border_color(border_color)
+                    .border(px(1.))
+                    .border_inside(),
+            )
+            .child(
+                Input::new(self.id.with_suffix("query"), self.query.clone())
+                    .w_full()
+                    .layer(layer)
+                    .rounded(corner_radius - padding)
+                    .placeholder("Search commands..."),
+            )
+            .child(
+                div()
+                    .id(self.id.with_suffix("matches"))
+                    .w_full()
+                    .max_h(px(320.))
+                    .flex()
+                    .flex_col()
+                    .gap(px(1.))
+                    .overflow_y_scroll()
+                    .track_scroll(&self.scroll_handle)
+                    .on_scroll_wheel(|_event, _window, cx| {
+                        cx.stop_propagation();
+                    })
+                    .when(self.matches.is_empty(), |this| {
+                        this.child(
+                            div()
+                                .w_full()
+                                .pl(horizontal_padding)
+                                .pr(horizontal_padding)
+                                .pt(vertical_padding)
+                                .pb(vertical_padding)
+                                .text_size(text_size)
+                                .text_color(secondary_text_color)
+                                .child("No matching commands"),
+                        )
+                    })
+                    .children(self.matches.iter().enumerate().map(|(idx, command_match)| {
+                        let command = &self.commands[command_match.index];
+                        let show_highlight =
+                            self.hovered.is_none() && self.highlighted == Some(idx);
+
+                        let name = StyledText::new(command.name.clone()).with_highlights(
+                            command_match
+                                .ranges
+                                .iter()
+                                .map(|range| (range.clone(), match_highlight)),
+                        );
+
+                        Toggle::new(
+                            self.id
+                                .with_suffix("command")
+                                .with_suffix(command_match.index.to_string()),
+                        )
+                        .w_full()
+                        .max_w(relative(1.))
+                        .checked(false)
+                        .variant(ToggleVariant::Tertiary)
+                        .force_hover(show_highlight)
+                        .justify_start()
+                        .rounded(corner_radius - padding)
+                        .pl(horizontal_padding)
+                        .pr(horizontal_padding)
+                        .pt(vertical_padding)
+                        .pb(vertical_padding)
+                        .child_left(
+                            div()
+                                .flex_1()
+                                .min_w_0()
+                                .flex()
+                                .items_center()
+                                .gap(horizontal_padding)
+                                .when_some(command.icon.clone(), |this, icon| {
+                                    this.child(Icon::new(icon).color(text_color))
+                                })
+                                .child(
+                                    div()
+                                        .min_w_0()
+                                        .flex()
+                                        .flex_col()
+                                        .child(
+                                            div()
+                                                .text_size(text_size)
+                                                .text_color(text_color)
+                                                .text_ellipsis()
+                                                .child(name),
+                                        )
+                                        .when_some(
+                                            command.description.clone(),
+                                            |this, description| {
+                                                this.child(
+                                                    div()
+                                                        .text_size(caption_text_size)
+                                                        .text_color(secondary_text_color)
+                                                        .text_ellipsis()
+                                                        .child(description),
+                                                )
+                                            },
+                                        ),
+                                ),
+                        )
+                        .when_some(command.keybinding.clone(), |this, keybinding| {
+                            this.child_right(
+                                div()
+                                    .flex_none()
+                                    .text_size(caption_text_size)
+                                    .text_color(secondary_text_color)
+                                    .child(keybinding),
+                            )
+                        })
+                        .on_any_mouse_down(|_event, window, _cx| {
+                            // Keeps focus in the query input.
+                            window.prevent_default();
+                        })
+                        .on_hover(cx.listener(move |this, is_hovered: &bool, _window, cx| {
+                            if *is_hovered {
+                                this.hovered = Some(idx);
+                                this.highlighted = Some(idx);
+                            } else if this.hovered == Some(idx) {
+                                this.hovered = None;
+                            }
+                            cx.notify();
+                        }))
+                        .on_click(cx.listener(
+                            move |this, _event: &ClickEvent, window, cx| {
+                                this.run(idx, window, cx);
+                            },
+                        ))
+                    })),
+            )
+    }
+}
+
+/// Formats the keystrokes of the action's highest precedence binding, if it has one.
+fn keybinding_label(action: &dyn Action, window: &Window) -> Option<SharedString> {
+    let binding = window.highest_precedence_binding_for_action(action)?;
+
+    Some(
+        binding
+            .keystrokes()
+            .iter()
+            .map(|keystroke| keystroke.to_string())
+            .collect::<Vec<_>>()
+            .join(" ")
+            .into(),
+    )
+}
+
+/// Fuzzy matches every command name against the query, best matches first.
+///
+/// Commands with equal scores keep their original order.
+fn match_commands(query: &str, commands: &[Command]) -> Vec<CommandMatch> {
+    let mut matches = commands
+        .iter()
+        .enumerate()
+        .filter_map(|(index, command)| {
+            let fuzzy = fuzzy_match(query, &command.name)?;
+            let command_match = CommandMatch {
+                index,
+                ranges: fuzzy.ranges(&command.name),
+            };
+            Some((fuzzy.score, command_match))
+        })
+        .collect::<Vec<_>>();
+
+    matches.sort_by(|(a, _), (b, _)| b.cmp(a));
+    matches
+        .into_iter()
+        .map(|(_, command_match)| command_match)
+        .collect()
+}
+
+#[cfg(all(test, feature = "test-support"))]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+    use crate::theme::Theme;
+    use gpui::{TestAppContext, VisualTestContext};
+
+    actions!(command_palette_test, [FirstAction, SecondAction]);
+
+    /// A focusable view that counts how often it receives `FirstAction`.
+    struct TestView {
+        focus_handle: FocusHandle,
+        first_action_count: Rc<Cell<usize>>,
+    }
+
+    impl Render for TestView {
+        fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+            let first_action_count = self.first_action_count.clone();
+
+            div()
+                .id("test-view")
+                .size_full()
+                .track_focus(&self.focus_handle)
+                .on_action(move |_: &FirstAction, _window, _cx| {
+                    first_action_count.set(first_action_count.get() + 1);
+                })
+                .child("Test Content")
+        }
+    }
+
+    fn test_commands() -> Vec<Command> {
+        vec![
+            Command::new("Open File", FirstAction).description("Opens a file"),
+            Command::new("Close Window", SecondAction).keybinding("cmd-w"),
+        ]
+    }
+
+    fn open_test_window(
+        cx: &mut TestAppContext,
+    ) -> (Entity<Root>, Rc<Cell<usize>>, VisualTestContext) {
+        cx.update(|cx| {
+            cx.set_theme(Theme::DEFAULT);
+            gpui_primitives::init(cx);
+            init(cx);
+        });
+
+        let first_action_count = Rc::new(Cell::new(0));
+        let count = first_action_count.clone();
+
+        let window = cx
+            .update(|cx| {
+                cx.open_window(Default::default(), |window, cx| {
+                    let test_view = cx.new(|cx| TestView {
+                        focus_handle: cx.focus_handle(),
+                        first_action_count: count,
+                    });
+                    test_view.read(cx).focus_handle.focus(window, cx);
+                    cx.new(|cx| Root::new(test_view, window, cx))
+                })
+            })
+            .unwrap();
+
+        let root = window.root(cx).unwrap();
+        let cx = VisualTestContext::from_window(window.into(), cx);
+
+        (root, first_action_count, cx)
+    }
+
+    #[gpui::test]
+    fn test_command_builder(cx: &mut TestAppContext) {
+        cx.update(|_cx| {
+            let command = Command::new("Open File", FirstAction)
+                .description("Opens a file")
+                .keybinding("cmd-o")
+                .icon("icons/test.svg");
+
+            assert_eq!(command.name(), &SharedString::from("Open File"));
+            assert_eq!(command.description, Some("Opens a file".into()));
+            assert_eq!(command.keybinding, Some("cmd-o".into()));
+            assert_eq!(command.icon, Some("icons/test.svg".into()));
+            assert!(command.action().partial_eq(&FirstAction));
+
+            let cloned = command.clone();
+            assert!(
+                cloned.action().partial_eq(&FirstAction),
+                "Cloned command should keep its action"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_command_registry(cx: &mut TestAppContext) {
+        cx.update(|cx| {
+            assert!(
+                CommandRegistry::commands(cx).is_empty(),
+                "Registry should start empty"
+            );
+
+            CommandRegistry::register(cx, test_commands());
+            CommandRegistry::register(cx, [Command::new("Third", FirstAction)]);
+
+            let names = CommandRegistry::commands(cx)
+                .iter()
+                .map(|command| command.name().clone())
+                .collect::<Vec<_>>();
+            assert_eq!(
+                names,
+                vec![
+                    SharedString::from("Open File"),
+                    "Close Window".into(),
+                    "Third".into()
+                ],
+                "Commands should be listed in registration order"
+            );
+        });
+    }
+
+    #[test]
+    fn test_match_commands_empty_query_keeps_order() {
+        let matches = match_commands("", &test_commands());
+        let indices = matches.iter().map(|m| m.index).collect::<Vec<_>>();
+        assert_eq!(indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_match_commands_filters_and_highlights() {
+        let matches = match_commands("cw", &test_commands());
+        assert_eq!(matches.len(), 1, "Only 'Close Window' should match");
+        assert_eq!(matches[0].index, 1);
+        assert_eq!(matches[0].ranges, vec![0..1, 6..7]);
+    }
+
+    #[test]
+    fn test_match_commands_sorts_by_score() {
+        let commands = vec![
+            Command::new("Toggle Line Wrapping", FirstAction),
+            Command::new("Go to Line", SecondAction),
+        ];
+
+        let matches = match_commands("line", &commands);
+        assert_eq!(
+            matches.iter().map(|m| m.index).collect::<Vec<_>>(),
+            vec![1, 0],
+            "The closer match should come first"
+        );
+    }
+
+    #[gpui::test]
+    fn test_palette_opens_as_dialog_and_dismisses(cx: &mut TestAppContext) {
+        let (root, _count, mut cx) = open_test_window(cx);
+
+        cx.update(|window, cx| CommandPalette::open_with(test_commands(), window, cx));
+        cx.run_until_parked();
+
+        root.read_with(&cx, |root, _| {
+            assert!(
+                root.is_dialog_of_view::<CommandPalette>(),
+                "Palette should be open as the root dialog"
+            );
+        });
+
+        cx.update(|window, cx| {
+            window.dispatch_action(Box::new(Dismiss), cx);
+        });
+        cx.run_until_parked();
+
+        root.read_with(&cx, |root, _| {
+            assert!(root.dialog().is_none(), "Dismiss should close the palette");
+        });
+    }
+
+    #[gpui::test]
+    fn test_palette_toggle(cx: &mut TestAppContext) {
+        let (root, _count, mut cx) = open_test_window(cx);
+
+        cx.update(|_window, cx| CommandRegistry::register(cx, test_commands()));
+
+        cx.update(|window, cx| CommandPalette::toggle(window, cx));
+        root.read_with(&cx, |root, _| {
+            assert!(root.is_dialog_of_view::<CommandPalette>());
+        });
+
+        cx.update(|window, cx| CommandPalette::toggle(window, cx));
+        root.read_with(&cx, |root, _| {
+            assert!(root.dialog().is_none(), "Toggling again should close it");
+        });
+    }
+
+    #[gpui::test]
+    fn test_palette_keyboard_highlight_wraps(cx: &mut TestAppContext) {
+        let (root, _count, mut cx) = open_test_window(cx);
+
+        cx.update(|window, cx| CommandPalette::open_with(test_commands(), window, cx));
+        cx.run_until_parked();
+
+        let palette = root.read_with(&cx, |root, _| {
+            root.dialog()
+                .cloned()
+                .unwrap()
+                .downcast::<CommandPalette>()
+                .ok()
+                .unwrap()
+        });
+
+        palette.read_with(&cx, |palette, _| {
+            assert_eq!(
+                palette.highlighted,
+                Some(0),
+                "First match starts highlighted"
+            );
+        });
+
+        palette.update_in(&mut cx, |palette, window, cx| {
+            palette.select_prev(&SelectPrev, window, cx);
+            assert_eq!(
+                palette.highlighted,
+                Some(1),
+                "Up should wrap to the last match"
+            );
+
+            palette.select_next(&SelectNext, window, cx);
+            assert_eq!(
+                palette.highlighted,
+                Some(0),
+                "Down should wrap to the first match"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_palette_confirm_dispatches_action(cx: &mut TestAppContext) {
+        let (root, first_action_count, mut cx) = open_test_window(cx);
+        cx.run_until_parked();
+
+        cx.update(|window, cx| CommandPalette::open_with(test_commands(), window, cx));
+        cx.run_until_parked();
+
+        cx.update(|window, cx| {
+            window.dispatch_action(Box::new(Confirm), cx);
+        });
+        cx.run_until_parked();
+
+        root.read_with(&cx, |root, _| {
+            assert!(root.dialog().is_none(), "Confirm should close the palette");
+        });
+        assert_eq!(
+            first_action_count.get(),
+            1,
+            "The highlighted command's action should reach the previously focused view"
+        );
+    }
+}
@@ -2,3 +2,6 @@
 
 mod root;
 pub use root::*;
+
+/// Searchable command list opened as a dialog in the window's [`Root`].
+pub mod command_palette;
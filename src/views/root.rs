@@ -1,9 +1,10 @@
 use std::{any::TypeId, cell::RefCell, cmp::Ordering, collections::BTreeMap, rc::Rc};
 
 use gpui::{
-    AnyElement, AnyView, App, Bounds, ClickEvent, Context, ElementId, InteractiveElement,
-    IntoElement, Length, MouseDownEvent, MouseMoveEvent, MouseUpEvent, ParentElement, Render,
-    StatefulInteractiveElement, Styled, Window, WindowHandle, div, prelude::FluentBuilder, px,
+    AnyElement, AnyView, App, Bounds, ClickEvent, Context, ElementId, Entity, FocusHandle,
+    InteractiveElement, IntoElement, Length, MouseButton, MouseDownEvent, MouseMoveEvent,
+    MouseUpEvent, ParentElement, Render, StatefulInteractiveElement, Styled, Window, WindowHandle,
+    div, hsla, prelude::FluentBuilder, px, relative,
 };
 
 #[derive(PartialEq, Eq, Hash)]
@@ -81,37 +82,38 @@ impl OverlayEntry {
     }
 }
 
+/// A modal view rendered above the Root's child view.
+struct DialogEntry {
+    view: AnyView,
+    /// The element that had focus before the dialog opened.
+    restore_focus: Option<FocusHandle>,
+}
+
 /// Root is the top-level view component that renders a child view and any overlay elements.
 ///
 /// Overlay elements are rendered in front of everything else, positioned absolutely
-/// within the Root's bounds.
+/// within the Root's bounds. A single modal dialog can also be opened on top of the
+/// child view, see [`Root::open_dialog`].
 ///
 /// # Example
 ///
 /// ```ignore
 /// // In your window creation:
-/// cx.open_window(options, |window, cx| {
-///     cx.new(|cx| Root::new(your_main_view, window, cx))
+/// gpui_tesserae::open_tesserae_window(cx, options, |_window, cx| {
+///     cx.new(|cx| YourMainView::new(cx))
 /// });
 ///
 /// // To add an overlay from anywhere:
-/// let root = window
-///     .root::<Root>()
-///     .expect("The window's root view should be of type `gpui_tesserae::Root`")
-///     .unwrap();
-///
-/// root.update(cx, |root, cx| {
-///     root.add(
-///         Bounds::new(point(px(100.), px(100.)), size(px(200.), px(150.))),
-///         |_window, _cx| {
-///             div().size_full().bg(red())
-///         },
-///     );
-///     cx.notify();
-/// });
+/// window.add_overlay(
+///     cx,
+///     "my-overlay",
+///     Bounds::new(point(px(100.), px(100.)), size(px(200.), px(150.))),
+///     |_window, _cx| div().size_full().bg(red()),
+/// );
 /// ```
 pub struct Root {
     view: AnyView,
+    dialog: Option<DialogEntry>,
     overlays: BTreeMap<ElementIdKey, OverlayEntry>,
     mouse_events: MouseEvents,
 }
@@ -146,6 +148,7 @@ impl Root {
     pub fn new(view: impl Into<AnyView>, _window: &mut Window, _cx: &mut Context<Self>) -> Self {
         Self {
             view: view.into(),
+            dialog: None,
             overlays: BTreeMap::new(),
             mouse_events: MouseEvents::new(),
         }
@@ -176,6 +179,53 @@ impl Root {
         self.overlays.clear();
     }
 
+    /// Opens a modal dialog centered above the child view, replacing any open dialog.
+    ///
+    /// Clicking the backdrop closes the dialog. Focus returns to the element that was
+    /// focused before the first dialog opened once it closes.
+    pub fn open_dialog(
+        &mut self,
+        view: impl Into<AnyView>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let restore_focus = match self.dialog.take() {
+            Some(dialog) => dialog.restore_focus,
+            None => window.focused(cx),
+        };
+
+        self.dialog = Some(DialogEntry {
+            view: view.into(),
+            restore_focus,
+        });
+        cx.notify();
+    }
+
+    /// Closes the open dialog and restores focus. Returns true if a dialog was open.
+    pub fn close_dialog(&mut self, window: &mut Window, cx: &mut Context<Self>) -> bool {
+        let Some(dialog) = self.dialog.take() else {
+            return false;
+        };
+
+        if let Some(restore_focus) = dialog.restore_focus {
+            restore_focus.focus(window, cx);
+        }
+
+        cx.notify();
+        true
+    }
+
+    /// Returns the view of the open dialog, if any.
+    pub fn dialog(&self) -> Option<&AnyView> {
+        self.dialog.as_ref().map(|dialog| &dialog.view)
+    }
+
+    /// Checks if the open dialog is of the specific view type.
+    pub fn is_dialog_of_view<V: Render>(&self) -> bool {
+        self.dialog()
+            .is_some_and(|view| TypeId::of::<V>() == view.entity_type())
+    }
+
     /// Registers a click handler that fires for any click on the root overlay.
     pub fn on_click(
         &mut self,
@@ -225,6 +275,28 @@ impl Root {
         !self.mouse_events.is_empty()
     }
 
+    /// Returns the Root of the given window, or `None` if its root view isn't a Root.
+    pub fn for_window(window: &Window) -> Option<Entity<Root>> {
+        window.root::<Root>().flatten()
+    }
+
+    /// Updates the Root of the given window and notifies it so the changes are rendered.
+    ///
+    /// Returns `None` without running `update` if the window's root view isn't a Root.
+    pub fn update_for_window<R>(
+        window: &mut Window,
+        cx: &mut App,
+        update: impl FnOnce(&mut Root, &mut Window, &mut Context<Root>) -> R,
+    ) -> Option<R> {
+        let root = Self::for_window(window)?;
+
+        Some(root.update(cx, |root, cx| {
+            let result = update(root, window, cx);
+            cx.notify();
+            result
+        }))
+    }
+
     /// Finds a Root window with a specific child view.
     pub fn find_window<V: Render>(cx: &App) -> Option<WindowHandle<Root>> {
         cx.windows().iter().find_map(|window| {
@@ -250,6 +322,131 @@ impl Root {
     }
 }
 
+/// Extension trait for adding overlays, dialogs and mouse handlers to a window's [`Root`]
+/// without looking it up first.
+///
+/// Each method returns `false` and does nothing if the window's root view isn't a [`Root`].
+pub trait RootWindowExt {
+    /// Adds an overlay to the window's Root. Overlays are cleared every frame,
+    /// so this should be called during render.
+    fn add_overlay<E: IntoElement>(
+        &mut self,
+        cx: &mut App,
+        id: impl Into<ElementId>,
+        bounds: Bounds<Length>,
+        element: impl FnOnce(&mut Window, &mut App) -> E + 'static,
+    ) -> bool;
+
+    /// Removes an overlay from the window's Root by its ID.
+    /// Returns true if the overlay was found and removed.
+    fn remove_overlay(&mut self, cx: &mut App, id: impl Into<ElementId>) -> bool;
+
+    /// Opens a modal dialog in the window's Root, replacing any open dialog.
+    fn open_dialog(&mut self, cx: &mut App, view: impl Into<AnyView>) -> bool;
+
+    /// Closes the window's open dialog and restores focus.
+    /// Returns true if a dialog was open.
+    fn close_dialog(&mut self, cx: &mut App) -> bool;
+
+    /// Registers a click handler on the window's Root overlay for the next frame.
+    fn on_root_click(
+        &mut self,
+        cx: &mut App,
+        on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> bool;
+
+    /// Registers a mouse down handler on the window's Root overlay for the next frame.
+    fn on_root_mouse_down(
+        &mut self,
+        cx: &mut App,
+        on_mouse_down: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> bool;
+
+    /// Registers a mouse up handler on the window's Root overlay for the next frame.
+    fn on_root_mouse_up(
+        &mut self,
+        cx: &mut App,
+        on_mouse_up: impl Fn(&MouseUpEvent, &mut Window, &mut App) + 'static,
+    ) -> bool;
+
+    /// Registers a mouse move handler on the window's Root overlay for the next frame.
+    fn on_root_mouse_move(
+        &mut self,
+        cx: &mut App,
+        on_mouse_move: impl Fn(&MouseMoveEvent, &mut Window, &mut App) + 'static,
+    ) -> bool;
+}
+
+impl RootWindowExt for Window {
+    fn add_overlay<E: IntoElement>(
+        &mut self,
+        cx: &mut App,
+        id: impl Into<ElementId>,
+        bounds: Bounds<Length>,
+        element: impl FnOnce(&mut Window, &mut App) -> E + 'static,
+    ) -> bool {
+        Root::update_for_window(self, cx, |root, _window, _cx| root.add(id, bounds, element))
+            .is_some()
+    }
+
+    fn remove_overlay(&mut self, cx: &mut App, id: impl Into<ElementId>) -> bool {
+        Root::update_for_window(self, cx, |root, _window, _cx| root.remove(id)).unwrap_or(false)
+    }
+
+    fn open_dialog(&mut self, cx: &mut App, view: impl Into<AnyView>) -> bool {
+        Root::update_for_window(self, cx, |root, window, cx| {
+            root.open_dialog(view, window, cx)
+        })
+        .is_some()
+    }
+
+    fn close_dialog(&mut self, cx: &mut App) -> bool {
+        Root::update_for_window(self, cx, |root, window, cx| root.close_dialog(window, cx))
+            .unwrap_or(false)
+    }
+
+    fn on_root_click(
+        &mut self,
+        cx: &mut App,
+        on_click: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> bool {
+        Root::update_for_window(self, cx, |root, _window, _cx| root.on_click(on_click)).is_some()
+    }
+
+    fn on_root_mouse_down(
+        &mut self,
+        cx: &mut App,
+        on_mouse_down: impl Fn(&MouseDownEvent, &mut Window, &mut App) + 'static,
+    ) -> bool {
+        Root::update_for_window(self, cx, |root, _window, _cx| {
+            root.on_any_mouse_down(on_mouse_down)
+        })
+        .is_some()
+    }
+
+    fn on_root_mouse_up(
+        &mut self,
+        cx: &mut App,
+        on_mouse_up: impl Fn(&MouseUpEvent, &mut Window, &mut App) + 'static,
+    ) -> bool {
+        Root::update_for_window(self, cx, |root, _window, _cx| {
+            root.on_any_mouse_up(on_mouse_up)
+        })
+        .is_some()
+    }
+
+    fn on_root_mouse_move(
+        &mut self,
+        cx: &mut App,
+        on_mouse_move: impl Fn(&MouseMoveEvent, &mut Window, &mut App) + 'static,
+    ) -> bool {
+        Root::update_for_window(self, cx, |root, _window, _cx| {
+            root.on_mouse_move(on_mouse_move)
+        })
+        .is_some()
+    }
+}
+
 impl Render for Root {
     fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let overlays_is_empty = self.overlays.is_empty();
@@ -260,6 +457,37 @@ impl Render for Root {
             .size_full()
             .relative()
             .child(self.view.clone())
+            .when_some(self.dialog(), |this, view| {
+                this.child(
+                    div()
+                        .id("root-dialog-backdrop")
+                        .absolute()
+                        .top(px(0.))
+                        .left(px(0.))
+                        .size_full()
+                        .occlude()
+                        .bg(hsla(0., 0., 0., 0.3))
+                        .flex()
+                        .flex_col()
+                        .items_center()
+                        .pt(relative(0.15))
+                        .on_mouse_down(
+                            MouseButton::Left,
+                            cx.listener(|this, _event, window, cx| {
+                                this.close_dialog(window, cx);
+                            }),
+                        )
+                        .child(
+                            div()
+                                .id("root-dialog")
+                                // Keeps clicks inside the dialog from reaching the backdrop.
+                                .on_mouse_down(MouseButton::Left, |_event, _window, cx| {
+                                    cx.stop_propagation();
+                                })
+                                .child(view.clone()),
+                        ),
+                )
+            })
             .when(!(overlays_is_empty && mouse_events_is_empty), |this| {
                 let overlays = self.take_overlays();
 
@@ -641,4 +869,236 @@ mod tests {
 
         // The window creation itself validates rendering works
     }
+
+    #[gpui::test]
+    fn test_root_open_and_close_dialog(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.open_window(Default::default(), |window, cx| {
+                let test_view = cx.new(|_cx| TestView);
+                cx.new(|cx| Root::new(test_view, window, cx))
+            })
+            .unwrap()
+        });
+
+        let root = window.root(cx).unwrap();
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+
+        root.update_in(&mut cx, |root, window, cx| {
+            assert!(root.dialog().is_none(), "Root should start with no dialog");
+
+            let dialog_view = cx.new(|_cx| TestView);
+            root.open_dialog(dialog_view, window, cx);
+
+            assert!(root.dialog().is_some(), "Dialog should be open");
+            assert!(
+                root.is_dialog_of_view::<TestView>(),
+                "Dialog should be of the opened view type"
+            );
+        });
+
+        // Render with the dialog open.
+        cx.run_until_parked();
+
+        root.update_in(&mut cx, |root, window, cx| {
+            assert!(
+                root.close_dialog(window, cx),
+                "Close should report an open dialog"
+            );
+            assert!(root.dialog().is_none(), "Dialog should be closed");
+            assert!(
+                !root.close_dialog(window, cx),
+                "Close should return false when no dialog is open"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_root_close_dialog_restores_focus(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.open_window(Default::default(), |window, cx| {
+                let test_view = cx.new(|_cx| TestView);
+                cx.new(|cx| Root::new(test_view, window, cx))
+            })
+            .unwrap()
+        });
+
+        let root = window.root(cx).unwrap();
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+
+        let previous_focus = cx.update(|window, cx| {
+            let focus_handle = cx.focus_handle();
+            focus_handle.focus(window, cx);
+            focus_handle
+        });
+
+        root.update_in(&mut cx, |root, window, cx| {
+            let dialog_view = cx.new(|_cx| TestView);
+            root.open_dialog(dialog_view, window, cx);
+
+            // Opening a second dialog should keep the original focus to restore.
+            let replacement_view = cx.new(|_cx| TestView);
+            root.open_dialog(replacement_view, window, cx);
+
+            window.blur();
+            root.close_dialog(window, cx);
+        });
+
+        cx.update(|window, cx| {
+            assert_eq!(
+                window.focused(cx),
+                Some(previous_focus.clone()),
+                "Focus should return to the element focused before the dialog opened"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_root_for_window(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.open_window(Default::default(), |window, cx| {
+                let test_view = cx.new(|_cx| TestView);
+                cx.new(|cx| Root::new(test_view, window, cx))
+            })
+            .unwrap()
+        });
+
+        let root = window.root(cx).unwrap();
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+
+        cx.update(|window, _cx| {
+            assert_eq!(
+                Root::for_window(window),
+                Some(root.clone()),
+                "Should find the window's Root"
+            );
+        });
+    }
+
+    #[gpui::test]
+    fn test_root_helpers_without_root(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.open_window(Default::default(), |_window, cx| cx.new(|_cx| TestView))
+                .unwrap()
+        });
+
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+
+        cx.update(|window, cx| {
+            assert!(
+                Root::for_window(window).is_none(),
+                "A window without a Root should have no Root"
+            );
+            assert!(
+                Root::update_for_window(window, cx, |_root, _window, _cx| ()).is_none(),
+                "Updating should be skipped when there's no Root"
+            );
+            assert!(
+                !window.add_overlay(
+                    cx,
+                    "overlay",
+                    Bounds::new(
+                        point(px(0.).into(), px(0.).into()),
+                        size(px(10.).into(), px(10.).into()),
+                    ),
+                    |_window, _cx| div(),
+                ),
+                "Adding an overlay should report failure instead of panicking"
+            );
+            assert!(!window.on_root_mouse_down(cx, |_event, _window, _cx| {}));
+            assert!(!window.close_dialog(cx));
+        });
+    }
+
+    #[gpui::test]
+    fn test_root_window_ext(cx: &mut TestAppContext) {
+        let window = cx.update(|cx| {
+            cx.open_window(Default::default(), |window, cx| {
+                let test_view = cx.new(|_cx| TestView);
+                cx.new(|cx| Root::new(test_view, window, cx))
+            })
+            .unwrap()
+        });
+
+        let root = window.root(cx).unwrap();
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+
+        cx.update(|window, cx| {
+            assert!(window.add_overlay(
+                cx,
+                "overlay",
+                Bounds::new(
+                    point(px(0.).into(), px(0.).into()),
+                    size(px(10.).into(), px(10.).into()),
+                ),
+                |_window, _cx| div().child("Overlay"),
+            ));
+            assert!(window.on_root_mouse_move(cx, |_event, _window, _cx| {}));
+        });
+
+        root.read_with(&cx, |root, _| {
+            assert_eq!(
+                root.overlays.len(),
+                1,
+                "Overlay should be added to the Root"
+            );
+            assert!(
+                root.click_overlay_visible(),
+                "Mouse handler should be registered on the Root"
+            );
+        });
+
+        cx.update(|window, cx| {
+            assert!(window.remove_overlay(cx, "overlay"));
+            assert!(!window.remove_overlay(cx, "overlay"));
+
+            let dialog_view = cx.new(|_cx| TestView);
+            assert!(window.open_dialog(cx, dialog_view));
+            assert!(window.close_dialog(cx));
+            assert!(!window.close_dialog(cx));
+        });
+    }
+
+    #[gpui::test]
+    fn test_open_tesserae_window(cx: &mut TestAppContext) {
+        use crate::theme::{Theme, ThemeExt};
+
+        cx.update(|cx| cx.set_theme(Theme::DEFAULT));
+
+        let window = cx.update(|cx| {
+            crate::open_tesserae_window(cx, Default::default(), |_window, cx| {
+                cx.new(|_cx| TestView)
+            })
+            .unwrap()
+        });
+
+        let root = window.root(cx).unwrap();
+        root.read_with(cx, |root, _| {
+            assert!(
+                root.is_of_view::<TestView>(),
+                "The view should be wrapped in a Root"
+            );
+        });
+
+        let mut cx = VisualTestContext::from_window(window.into(), cx);
+        cx.update(|window, cx| {
+            assert_eq!(
+                window.rem_size(),
+                cx.get_theme().layout.text.base_size,
+                "Rem size should be set from the theme"
+            );
+        });
+
+        cx.update(|_window, cx| {
+            let mut theme = cx.get_theme().clone();
+            theme.layout.text.base_size = px(20.);
+            cx.set_theme(theme);
+        });
+        cx.update(|window, _cx| {
+            assert_eq!(
+                window.rem_size(),
+                px(20.),
+                "Rem size should follow theme changes"
+            );
+        });
+    }
 }